@@ -14,6 +14,7 @@ use scripts::blueprints_catalog::{
     blueprints_catalog_refresh_localization, blueprints_catalog_revalidate,
     blueprints_catalog_supplement_ids,
 };
+use scripts::blueprints_import::{merge_blueprints_import, preview_blueprints_import};
 use scripts::blueprints_wiki_extended::{
     blueprints_catalog_filters, blueprints_mission_detail, blueprints_unlock_index_status,
     ingredient_locations, wiki_items_filters,
@@ -21,34 +22,61 @@ use scripts::blueprints_wiki_extended::{
 use scripts::cache_functions::{
     clear_cache, delete_folder, get_cache_informations, open_cache_folder,
 };
+use scripts::disk_usage::get_disk_usage_report;
 use scripts::game_config_backup::{export_game_config_backup, list_game_config_backup_targets};
 use scripts::game_log::{
-    get_cached_game_stats, get_game_stats, get_game_stats_scan_status, sync_game_stats,
-    GameStatsScanState,
+    get_cached_game_stats, get_game_stats, get_game_stats_scan_status, get_location_catalog,
+    refresh_location_catalog, sync_game_stats, GameStatsScanState,
+};
+use scripts::game_process::{
+    get_game_process_status, save_game_process_config, start_game_process_monitor, GameProcessState,
 };
 use scripts::gamelog_archive::{
-    get_gamelog_archive_status, load_gamelog_archive_config, save_gamelog_archive_config,
-    sync_gamelog_archive,
+    apply_gamelog_archive_retention, get_gamelog_archive_status, list_gamelog_archive_entries,
+    load_gamelog_archive_config, preview_gamelog_archive_retention, relocate_gamelog_archive,
+    save_gamelog_archive_config, set_gamelog_archive_pinned, sync_gamelog_archive,
+};
+use scripts::gamelog_archive_bundle::{
+    export_gamelog_archive_bundle, import_gamelog_archive_bundle,
+};
+use scripts::gamelog_archive_verify::{repair_gamelog_archive, verify_gamelog_archive};
+use scripts::gamelog_channels::{get_gamelog_channels, set_gamelog_channel_enabled};
+use scripts::gamelog_crash_index::get_gamelog_crash_index;
+use scripts::gamelog_replay::{
+    get_gamelog_replay_status, load_gamelog_replay_results, start_gamelog_replay,
+    stop_gamelog_replay, GamelogReplayState,
 };
+use scripts::gamelog_search::{rebuild_gamelog_search_index, search_gamelogs};
 use scripts::gamelog_watcher::{
-    export_gamelog_blueprints, get_gamelog_watcher_status, import_blueprints_from_logbackups,
-    load_gamelog_blueprints, load_gamelog_watcher_config, load_gamelog_watcher_config_sync,
-    save_gamelog_blueprint_catalog_matches, save_gamelog_watcher_config, start_gamelog_watcher,
-    start_gamelog_watcher_internal, stop_gamelog_watcher, GamelogWatcherState,
+    export_gamelog_blueprints, get_gamelog_watcher_status, get_mission_blueprint_drops,
+    import_blueprints_from_logbackups, load_gamelog_blueprints, load_gamelog_watcher_config,
+    load_gamelog_watcher_config_sync, save_gamelog_blueprint_catalog_matches,
+    save_gamelog_watcher_config, start_gamelog_watcher, start_gamelog_watcher_internal,
+    stop_gamelog_watcher, GamelogWatcherState,
 };
 use scripts::gamepath::{get_live_game_log_path, get_star_citizen_versions};
+use scripts::gamepath_sources::{
+    add_manual_install_path, list_manual_install_paths, remove_manual_install_path,
+};
 use scripts::hangar_exec::{
     hangar_exec_fetch_status, hangar_exec_get_timers, hangar_exec_start_timer,
 };
 use scripts::home_dashboard::{get_home_dashboard, save_home_dashboard};
+use scripts::launcher_log::query_launcher_events;
 use scripts::local_characters_functions::{
     delete_character, download_character, duplicate_character, get_character_informations,
     open_characters_folder,
 };
+use scripts::notification_rules::{
+    get_notification_rules, save_notification_rules, start_notification_rules_engine,
+    NotificationRulesState,
+};
 use scripts::onboarding::{
     complete_onboarding, get_onboarding_state, record_onboarding_attempt, reset_onboarding,
 };
+use scripts::p4k::{extract_p4k_file, get_p4k_entry, list_p4k_entries};
 use scripts::paints_catalog::paints_catalog_list;
+use scripts::patch_hooks::{get_patch_history, get_patch_hooks_config, save_patch_hooks_config};
 use scripts::patchnote::get_latest_commits;
 use scripts::presets_list_functions::get_characters;
 use scripts::recent_routes::{get_top_routes, record_page_visit};
@@ -60,9 +88,15 @@ use scripts::startup_manager::{
 use scripts::system_metrics::get_system_metrics;
 use scripts::system_tray::setup_system_tray;
 use scripts::theme_preferences::{load_theme_selected, save_theme_selected};
+use scripts::translation_diff::{get_translation_changelog, preview_translation_update};
 use scripts::translation_functions::{
-    init_translation_files, is_game_translated, is_translation_up_to_date, uninstall_translation,
-    update_translation,
+    get_installed_translation_language, init_translation_files, is_game_translated,
+    is_translation_up_to_date, uninstall_translation, update_translation,
+};
+use scripts::translation_languages::list_translation_languages;
+use scripts::translation_overrides::{
+    export_translation_overrides, import_translation_overrides, list_translation_overrides,
+    remove_translation_override, set_translation_override,
 };
 use scripts::translation_preferences::{load_translations_selected, save_translations_selected};
 use scripts::translations_links::{get_translation_by_setting, get_translations};
@@ -108,6 +142,11 @@ pub fn run() {
                 .get_webview_window("main")
                 .expect("impossible de récupérer la fenêtre principale");
 
+            // Avant tout watcher : la détection des installations lit ces chemins.
+            scripts::gamepath_sources::init_manual_install_paths(app.handle());
+            scripts::patch_hooks::init_patch_watch(app.handle());
+            scripts::translation_overrides::init_translation_overrides(app.handle());
+
             let background_state = BackgroundServiceState::default();
             match load_background_service_config(app.handle().clone()) {
                 Ok(config) => {
@@ -136,6 +175,16 @@ pub fn run() {
 
             app.manage(background_state);
 
+            if let Err(e) = scripts::game_log::locations::reload_location_catalog_sync(app.handle())
+            {
+                eprintln!("Échec du chargement du catalogue de lieux: {e}");
+            }
+
+            // Abonné avant le démarrage automatique du watcher : aucun événement manqué.
+            let notification_rules_state = NotificationRulesState::default();
+            start_notification_rules_engine(app.handle(), &notification_rules_state);
+            app.manage(notification_rules_state);
+
             let gamelog_watcher_state = GamelogWatcherState::default();
             match load_gamelog_watcher_config_sync(app.handle()) {
                 Ok(config) => {
@@ -154,10 +203,21 @@ pub fn run() {
                 }
             }
             app.manage(gamelog_watcher_state);
+            app.manage(GamelogReplayState::default());
             app.manage(GameStatsScanState::default());
 
+            // Lancement / fermeture du jeu : démarrage du watcher, sync archive et stats.
+            let game_process_state = GameProcessState::default();
+            if let Err(e) = start_game_process_monitor(app.handle(), &game_process_state) {
+                eprintln!("Échec du démarrage de la détection du jeu: {e}");
+            }
+            app.manage(game_process_state);
+
             // Sync initiale des logbackups vers l'archive locale.
             let app_for_archive = app.handle().clone();
+            let app_for_archive_migration = app.handle().clone();
+            let app_for_archive_fingerprints = app.handle().clone();
+            let app_for_archive_retention = app.handle().clone();
             tauri::async_runtime::spawn(async move {
                 let app_clone = app_for_archive;
                 match tokio::task::spawn_blocking(move || {
@@ -174,6 +234,72 @@ pub fn run() {
                     Ok(Err(e)) => eprintln!("Échec sync archive gamelog: {e}"),
                     Err(e) => eprintln!("Échec tâche sync archive gamelog: {e}"),
                 }
+
+                // Migration des copies brutes des versions précédentes vers le stockage compressé.
+                let app_clone = app_for_archive_migration;
+                match tokio::task::spawn_blocking(move || {
+                    let scan_state = app_clone.state::<GameStatsScanState>().inner().clone();
+                    scripts::gamelog_archive::migrate_uncompressed_archives_sync(
+                        &app_clone,
+                        &scan_state,
+                    )
+                })
+                .await
+                {
+                    Ok(Ok(migration)) if migration.migrated > 0 || migration.failed > 0 => {
+                        eprintln!(
+                            "[gamelog_archive] compression: {} migrés, {} échecs, {} octets gagnés",
+                            migration.migrated, migration.failed, migration.bytes_saved
+                        );
+                    }
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => eprintln!("Échec compression archive gamelog: {e}"),
+                    Err(e) => eprintln!("Échec tâche compression archive gamelog: {e}"),
+                }
+
+                // Empreintes des copies archivées avant la déduplication par contenu.
+                let app_clone = app_for_archive_fingerprints;
+                match tokio::task::spawn_blocking(move || {
+                    scripts::gamelog_archive::backfill_archive_fingerprints_sync(&app_clone)
+                })
+                .await
+                {
+                    Ok(Ok(filled)) if filled > 0 => {
+                        eprintln!("[gamelog_archive] empreintes: {filled} copies complétées");
+                    }
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => eprintln!("Échec empreintes archive gamelog: {e}"),
+                    Err(e) => eprintln!("Échec tâche empreintes archive gamelog: {e}"),
+                }
+
+                // Rétention : sans limite configurée, rien n'est supprimé.
+                let app_clone = app_for_archive_retention;
+                match tokio::task::spawn_blocking(move || {
+                    let scan_state = app_clone.state::<GameStatsScanState>().inner().clone();
+                    scripts::gamelog_archive::apply_archive_retention_sync(&app_clone, &scan_state)
+                })
+                .await
+                {
+                    Ok(Ok(retention)) if retention.pruned > 0 || retention.failed > 0 => {
+                        eprintln!(
+                            "[gamelog_archive] rétention: {} supprimés, {} échecs, {} octets libérés",
+                            retention.pruned, retention.failed, retention.bytes_freed
+                        );
+                    }
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => eprintln!("Échec rétention archive gamelog: {e}"),
+                    Err(e) => eprintln!("Échec tâche rétention archive gamelog: {e}"),
+                }
+            });
+
+            // Un patch appliqué pendant que l'application était fermée est détecté dès le démarrage.
+            tauri::async_runtime::spawn(async {
+                if let Err(e) =
+                    tokio::task::spawn_blocking(scripts::gamepath::get_star_citizen_versions_sync)
+                        .await
+                {
+                    eprintln!("Échec de la détection des patchs: {e}");
+                }
             });
 
             if let Err(e) = setup_system_tray(app.handle()) {
@@ -202,16 +328,36 @@ pub fn run() {
             load_theme_selected,
             get_latest_commits,
             get_star_citizen_versions,
+            list_manual_install_paths,
+            add_manual_install_path,
+            remove_manual_install_path,
+            list_p4k_entries,
+            get_p4k_entry,
+            extract_p4k_file,
+            query_launcher_events,
+            get_patch_hooks_config,
+            save_patch_hooks_config,
+            get_patch_history,
             is_game_translated,
             init_translation_files,
             is_translation_up_to_date,
             update_translation,
             uninstall_translation,
+            list_translation_languages,
+            get_installed_translation_language,
+            preview_translation_update,
+            get_translation_changelog,
+            list_translation_overrides,
+            set_translation_override,
+            remove_translation_override,
+            import_translation_overrides,
+            export_translation_overrides,
             save_translations_selected,
             load_translations_selected,
             get_translations,
             get_translation_by_setting,
             get_cache_informations,
+            get_disk_usage_report,
             delete_folder,
             get_character_informations,
             delete_character,
@@ -241,17 +387,42 @@ pub fn run() {
             get_live_game_log_path,
             load_gamelog_blueprints,
             save_gamelog_blueprint_catalog_matches,
+            get_mission_blueprint_drops,
             load_gamelog_watcher_config,
             save_gamelog_watcher_config,
             get_gamelog_watcher_status,
             start_gamelog_watcher,
             stop_gamelog_watcher,
+            start_gamelog_replay,
+            get_game_process_status,
+            get_notification_rules,
+            save_notification_rules,
+            save_game_process_config,
+            stop_gamelog_replay,
+            get_gamelog_replay_status,
+            load_gamelog_replay_results,
             export_gamelog_blueprints,
             import_blueprints_from_logbackups,
             load_gamelog_archive_config,
             save_gamelog_archive_config,
             sync_gamelog_archive,
             get_gamelog_archive_status,
+            list_gamelog_archive_entries,
+            set_gamelog_archive_pinned,
+            preview_gamelog_archive_retention,
+            apply_gamelog_archive_retention,
+            verify_gamelog_archive,
+            repair_gamelog_archive,
+            relocate_gamelog_archive,
+            export_gamelog_archive_bundle,
+            import_gamelog_archive_bundle,
+            search_gamelogs,
+            rebuild_gamelog_search_index,
+            get_gamelog_crash_index,
+            get_gamelog_channels,
+            preview_blueprints_import,
+            merge_blueprints_import,
+            set_gamelog_channel_enabled,
             record_page_visit,
             get_top_routes,
             get_home_dashboard,
@@ -260,6 +431,8 @@ pub fn run() {
             get_game_stats_scan_status,
             get_game_stats,
             sync_game_stats,
+            get_location_catalog,
+            refresh_location_catalog,
             get_system_metrics,
             list_game_config_backup_targets,
             export_game_config_backup,
@@ -16,6 +16,7 @@ use crate::scripts::translation_preferences::load_translations_selected;
 pub struct BackgroundServiceConfig {
     pub enabled: bool,
     pub check_interval_minutes: u64,
+    /// Langue par défaut ; un canal peut en choisir une autre (`lang` de sa préférence).
     pub language: String,
 }
 
@@ -180,7 +181,7 @@ async fn run_background_service(
     println!("[Background Service] Service arrêté");
 }
 
-async fn check_and_update_translations(app: &AppHandle, lang: &str) -> Result<(), String> {
+async fn check_and_update_translations(app: &AppHandle, default_lang: &str) -> Result<(), String> {
     println!("[Background Service] Vérification des mises à jour de traduction...");
     let version_paths = get_star_citizen_versions()
         .await
@@ -198,6 +199,8 @@ async fn check_and_update_translations(app: &AppHandle, lang: &str) -> Result<()
     let mut updates_count = 0;
     for (version_name, version_info) in version_paths.versions {
         let version_path = version_info.path.clone();
+        let lang = translations_selected.channel_language(&version_name, default_lang);
+        let lang = lang.as_str();
         if let Some(translation_setting) = translations_obj.get(&version_name) {
             if let Some(link) = translation_setting.get("link").and_then(|v| v.as_str()) {
                 if is_game_translated_sync(version_path.clone(), lang.to_string()) {
@@ -210,6 +213,7 @@ async fn check_and_update_translations(app: &AppHandle, lang: &str) -> Result<()
                     {
                         println!("[Background Service] Mise à jour disponible pour {version_name}");
                         match update_translation_async(
+                            app,
                             version_path.clone(),
                             lang.to_string(),
                             link.to_string(),
@@ -224,7 +228,7 @@ async fn check_and_update_translations(app: &AppHandle, lang: &str) -> Result<()
                                 match app.notification()
                                     .builder()
                                     .title("Traduction mise à jour")
-                                    .body(format!("La traduction {version_name} a été mise à jour avec succès"))
+                                    .body(format!("La traduction {version_name} ({lang}) a été mise à jour avec succès"))
                                     .show() {
                                     Ok(_) => println!("[Background Service] Notification envoyée avec succès"),
                                     Err(e) => eprintln!("[Background Service] Erreur lors de l'envoi de la notification: {e}"),
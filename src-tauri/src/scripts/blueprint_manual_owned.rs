@@ -45,6 +45,20 @@ fn save_store<R: Runtime>(
     fs::write(path, bytes).map_err(|e| e.to_string())
 }
 
+/// IDs marqués possédés (minuscules), pour les règles de notification.
+pub(crate) fn manual_owned_blueprint_ids<R: Runtime>(
+    resolver: &PathResolver<R>,
+) -> HashSet<String> {
+    load_store(resolver)
+        .map(|f| {
+            f.entries
+                .into_iter()
+                .map(|e| e.blueprint_id.to_ascii_lowercase())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 #[command]
 pub fn manual_owned_blueprints_get(app: AppHandle) -> Result<Vec<String>, String> {
     let file = load_store(app.path())?;
@@ -38,6 +38,13 @@ fn save_wishlist<R: Runtime>(
     fs::write(path, bytes).map_err(|e| e.to_string())
 }
 
+/// IDs suivis (minuscules), pour les règles de notification.
+pub(crate) fn wishlist_blueprint_ids<R: Runtime>(resolver: &PathResolver<R>) -> HashSet<String> {
+    load_wishlist(resolver)
+        .map(|f| f.blueprint_ids.into_iter().collect())
+        .unwrap_or_default()
+}
+
 #[command]
 pub fn blueprint_wishlist_get(app: AppHandle) -> Result<Vec<String>, String> {
     let file = load_wishlist(app.path())?;
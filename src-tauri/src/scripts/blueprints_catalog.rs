@@ -6,6 +6,7 @@ use std::sync::Mutex;
 use tauri::command;
 
 use crate::scripts::gamepath::get_star_citizen_versions_sync;
+use crate::scripts::p4k::{read_p4k_text, P4K_GLOBAL_INI_EN};
 
 pub(crate) const WIKI_API_BASE: &str = "https://api.star-citizen.wiki";
 /// Official CIG global.ini files hosted by PolyTool (auto-synced from game data).
@@ -1227,6 +1228,20 @@ fn match_product_to_blueprint(product_name: &str) -> Option<String> {
     match_via_en_bridge(&target, index)
 }
 
+/// Clé de comparaison d'un nom de produit (même normalisation que le matching catalogue).
+pub(crate) fn product_match_key(product_name: &str) -> String {
+    normalized_match_target(product_name)
+}
+
+/// `bp_craft_*` d'un nom de produit du Game.log. Le catalogue est relu depuis le cache Wiki
+/// sur disque s'il n'est pas en mémoire (watcher seul, application dans la zone de notification).
+pub(crate) fn resolve_product_blueprint_id(product_name: &str) -> Option<String> {
+    ensure_loc_cache().ok()?;
+    ensure_catalog_summaries_loaded();
+    ensure_match_index_built().ok()?;
+    match_product_to_blueprint(product_name)
+}
+
 fn pick_live_install_path() -> Option<PathBuf> {
     let versions = get_star_citizen_versions_sync();
     let preferred = ["LIVE", "PTU", "EPTU", "TECH-PREVIEW"];
@@ -1258,8 +1273,62 @@ fn locale_file(install: &std::path::Path, locale_folder: &str) -> PathBuf {
 
 fn parse_global_ini(path: &PathBuf) -> Option<HashMap<String, String>> {
     let raw = fs::read_to_string(path).ok()?;
+    Some(parse_global_ini_text(&raw))
+}
+
+/// `global.ini` anglais embarqué dans `Data.p4k` : présent même sans traduction installée.
+/// Le retrouver impose de parcourir plus d'un million d'entrées : la copie extraite est gardée
+/// sur disque et resservie tant que l'archive (taille, date) n'a pas changé.
+fn load_p4k_english_global(install: &std::path::Path) -> Option<HashMap<String, String>> {
+    let stamp = p4k_stamp(install)?;
+    let (ini_path, stamp_path) = p4k_english_cache_paths()?;
+    if fs::read_to_string(&stamp_path).ok().as_deref() == Some(stamp.as_str()) {
+        if let Ok(raw) = fs::read_to_string(&ini_path) {
+            return Some(parse_global_ini_text(&raw));
+        }
+    }
+    match read_p4k_text(install, P4K_GLOBAL_INI_EN) {
+        Ok(text) => text.map(|raw| {
+            if fs::write(&ini_path, &raw).is_ok() {
+                let _ = fs::write(&stamp_path, &stamp);
+            }
+            parse_global_ini_text(&raw)
+        }),
+        Err(e) => {
+            eprintln!("[blueprints] global.ini anglais de Data.p4k: {e}");
+            None
+        }
+    }
+}
+
+/// Identité de `Data.p4k` (chemin, taille et date) : change à chaque mise à jour du jeu.
+fn p4k_stamp(install: &std::path::Path) -> Option<String> {
+    let meta = fs::metadata(install.join("Data.p4k")).ok()?;
+    let mtime = meta
+        .modified()
+        .ok()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?;
+    Some(format!(
+        "{}|{}|{}",
+        install.display(),
+        meta.len(),
+        mtime.as_millis()
+    ))
+}
+
+fn p4k_english_cache_paths() -> Option<(PathBuf, PathBuf)> {
+    let dir = dirs::data_local_dir()?.join("multitool").join("blueprints");
+    fs::create_dir_all(&dir).ok()?;
+    Some((
+        dir.join("p4k_global_en.ini"),
+        dir.join("p4k_global_en.stamp"),
+    ))
+}
+
+fn parse_global_ini_text(raw: &str) -> HashMap<String, String> {
     let mut map = HashMap::with_capacity(60_000);
-    for line in raw.lines() {
+    for line in raw.trim_start_matches('\u{feff}').lines() {
         if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
             continue;
         }
@@ -1279,7 +1348,7 @@ fn parse_global_ini(path: &PathBuf) -> Option<HashMap<String, String>> {
         }
         map.entry(key).or_insert_with(|| value.to_string());
     }
-    Some(map)
+    map
 }
 
 /// Extracts a `item_internal_name → class_code` map from a parsed global.ini.
@@ -1360,6 +1429,9 @@ fn ensure_loc_cache() -> Result<(), String> {
     if let Some(install) = pick_live_install_path() {
         let local_fr = parse_global_ini(&locale_file(&install, "french_(france)"));
         fr_map = merge_loc_maps(fr_map, local_fr);
+        // Anglais d'origine du build installé (un `english/global.ini` déployé peut être une
+        // traduction) : prime sur PolyTool.
+        en_map = merge_loc_maps(en_map, load_p4k_english_global(&install));
         if en_map.is_none() {
             en_map = parse_global_ini(&locale_file(&install, "english"));
         }
@@ -1483,7 +1555,7 @@ pub(crate) fn http_client() -> Result<reqwest::Client, String> {
         .map_err(|e| format!("Impossible d'initialiser le client HTTP: {e}"))
 }
 
-fn wiki_catalog_cache_path() -> Option<PathBuf> {
+pub(crate) fn wiki_catalog_cache_path() -> Option<PathBuf> {
     let dir = dirs::data_local_dir()?.join("multitool").join("blueprints");
     fs::create_dir_all(&dir).ok()?;
     Some(dir.join("wiki_blueprints_list.json"))
@@ -2394,13 +2466,19 @@ pub struct MatchProductsResult {
     pub requested_count: usize,
 }
 
+/// Charge le catalogue depuis le cache Wiki sur disque s'il n'est pas encore en mémoire.
+fn ensure_catalog_summaries_loaded() {
+    if CATALOG_SUMMARIES.lock().unwrap().is_some() {
+        return;
+    }
+    if let Ok(blueprints) = load_wiki_catalog_from_disk() {
+        let _ = build_summaries_from_wiki(&blueprints);
+    }
+}
+
 fn match_products_sync(product_names: Vec<String>) -> Result<MatchProductsResult, String> {
     ensure_loc_cache()?;
-    if CATALOG_SUMMARIES.lock().unwrap().is_none() {
-        if let Ok(blueprints) = load_wiki_catalog_from_disk() {
-            let _ = build_summaries_from_wiki(&blueprints);
-        }
-    }
+    ensure_catalog_summaries_loaded();
     ensure_match_index_built()?;
     let mut matches = HashMap::new();
     let mut ambiguous = Vec::new();
@@ -2435,6 +2513,16 @@ pub async fn blueprints_catalog_match_products(
         .map_err(|e| e.to_string())?
 }
 
+/// Retélécharge le catalogue Wiki sans tenir compte de l'âge du cache (après un patch).
+/// Renvoie le nombre de blueprints.
+pub(crate) async fn force_refresh_wiki_catalog() -> Result<usize, String> {
+    let blueprints = fetch_wiki_catalog_all().await?;
+    let game_version = blueprints.first().and_then(|b| b.game_version.clone());
+    persist_wiki_catalog(&blueprints, game_version);
+    blueprints_catalog_refresh_localization().await?;
+    Ok(blueprints.len())
+}
+
 #[command]
 pub async fn blueprints_catalog_refresh_localization() -> Result<(), String> {
     if let Some(p) = polytool_global_cache_path("fr") {
@@ -0,0 +1,467 @@
+//! Import de blueprints depuis des sources externes : fichiers `.log`, dossiers ou archives zip
+//! (sauvegarde d'un ami, logs d'un ancien PC…).
+//!
+//! Deux temps : [`preview_blueprints_import`] scanne les sources et renvoie les entrées
+//! nouvelles sans rien écrire, puis [`merge_blueprints_import`] fusionne la sélection validée.
+
+use crate::scripts::gamelog_watcher::{
+    append_blueprints, blueprint_has_owner, emit_import_progress, file_basename,
+    import_scan_percent, load_blueprint_store_sync, merge_blueprint_entries,
+    scan_log_reader_for_blueprints, BlueprintEntry, BlueprintsImportProgress,
+};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle};
+use tauri_plugin_dialog::DialogExt;
+use zip::ZipArchive;
+
+/// Profondeur max de parcours d'un dossier choisi (évite de balayer un disque entier).
+const MAX_FOLDER_DEPTH: usize = 6;
+/// Taille décompressée max d'une entrée zip : la taille annoncée par l'en-tête n'est pas fiable.
+const MAX_ZIP_ENTRY_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Un log à scanner : fichier sur disque ou entrée d'archive zip.
+#[derive(Debug, Clone)]
+enum ImportUnit {
+    File(PathBuf),
+    ZipEntry { archive: PathBuf, name: String },
+}
+
+impl ImportUnit {
+    fn label(&self) -> String {
+        match self {
+            ImportUnit::File(path) => path.display().to_string(),
+            ImportUnit::ZipEntry { archive, name } => format!("{}!{name}", archive.display()),
+        }
+    }
+
+    fn short_name(&self) -> Option<String> {
+        match self {
+            ImportUnit::File(path) => file_basename(path),
+            ImportUnit::ZipEntry { name, .. } => name.rsplit('/').next().map(|s| s.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlueprintImportFileReport {
+    /// Chemin du fichier, ou `archive.zip!chemin/interne.log`.
+    pub source: String,
+    /// Handle RSI détecté dans ce log ; les entrées sans handle ne sont pas importables.
+    pub owner: Option<String>,
+    pub matches_found: usize,
+    pub new_entries: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlueprintImportPreview {
+    pub sources: Vec<String>,
+    pub files: Vec<BlueprintImportFileReport>,
+    /// Entrées absentes du store, dédoublonnées par `(owner, product_name)` — à renvoyer
+    /// telles quelles (ou filtrées) à [`merge_blueprints_import`].
+    pub new_entries: Vec<BlueprintEntry>,
+    pub matches_found: usize,
+    /// Occurrences déjà présentes dans le store ou en double dans la sélection.
+    pub already_known: usize,
+    pub without_owner: usize,
+    pub files_failed: usize,
+}
+
+fn is_extension(path: &Path, ext: &str) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.eq_ignore_ascii_case(ext))
+        .unwrap_or(false)
+}
+
+fn is_log_name(name: &str) -> bool {
+    name.to_ascii_lowercase().ends_with(".log")
+}
+
+fn zip_log_entries(archive_path: &Path) -> Result<Vec<ImportUnit>, String> {
+    let file = File::open(archive_path).map_err(|e| e.to_string())?;
+    let archive = ZipArchive::new(file).map_err(|e| e.to_string())?;
+    let mut names: Vec<String> = archive
+        .file_names()
+        .filter(|name| !name.ends_with('/') && is_log_name(name))
+        .map(|name| name.to_string())
+        .collect();
+    names.sort();
+    Ok(names
+        .into_iter()
+        .map(|name| ImportUnit::ZipEntry {
+            archive: archive_path.to_path_buf(),
+            name,
+        })
+        .collect())
+}
+
+fn collect_folder_units(
+    dir: &Path,
+    depth: usize,
+    units: &mut Vec<ImportUnit>,
+    errors: &mut Vec<BlueprintImportFileReport>,
+) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut paths: Vec<PathBuf> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            if depth < MAX_FOLDER_DEPTH {
+                collect_folder_units(&path, depth + 1, units, errors);
+            }
+        } else if is_extension(&path, "zip") {
+            collect_zip_units(&path, units, errors);
+        } else if is_extension(&path, "log") {
+            units.push(ImportUnit::File(path));
+        }
+    }
+}
+
+fn collect_zip_units(
+    path: &Path,
+    units: &mut Vec<ImportUnit>,
+    errors: &mut Vec<BlueprintImportFileReport>,
+) {
+    match zip_log_entries(path) {
+        Ok(entries) => units.extend(entries),
+        Err(e) => errors.push(BlueprintImportFileReport {
+            source: path.display().to_string(),
+            owner: None,
+            matches_found: 0,
+            new_entries: 0,
+            error: Some(format!("Archive illisible : {e}")),
+        }),
+    }
+}
+
+/// Développe les sources choisies : un fichier explicitement choisi est scanné quelle que soit
+/// son extension, un dossier n'apporte que ses `.log` et `.zip`.
+fn collect_import_units(sources: &[PathBuf]) -> (Vec<ImportUnit>, Vec<BlueprintImportFileReport>) {
+    let mut units = Vec::new();
+    let mut errors = Vec::new();
+    for source in sources {
+        if source.is_dir() {
+            collect_folder_units(source, 0, &mut units, &mut errors);
+        } else if is_extension(source, "zip") {
+            collect_zip_units(source, &mut units, &mut errors);
+        } else if source.is_file() {
+            units.push(ImportUnit::File(source.clone()));
+        } else {
+            errors.push(BlueprintImportFileReport {
+                source: source.display().to_string(),
+                owner: None,
+                matches_found: 0,
+                new_entries: 0,
+                error: Some("Introuvable".to_string()),
+            });
+        }
+    }
+    let mut seen = HashSet::new();
+    units.retain(|u| seen.insert(u.label().to_ascii_lowercase()));
+    (units, errors)
+}
+
+/// Scanne au plus `max_bytes` décompressés ; au-delà, l'entrée est refusée.
+fn scan_capped(reader: impl Read, max_bytes: u64) -> Result<Vec<BlueprintEntry>, String> {
+    let mut limited = BufReader::new(reader.take(max_bytes + 1));
+    let found = scan_log_reader_for_blueprints(&mut limited).map_err(|e| e.to_string())?;
+    if limited.into_inner().limit() == 0 {
+        return Err(format!(
+            "Entrée trop volumineuse (plus de {} Mo décompressés)",
+            max_bytes / (1024 * 1024)
+        ));
+    }
+    Ok(found)
+}
+
+/// Blueprints d'une unité, lue en flux.
+fn scan_unit(
+    unit: &ImportUnit,
+    archives: &mut HashMap<PathBuf, ZipArchive<File>>,
+) -> Result<Vec<BlueprintEntry>, String> {
+    match unit {
+        ImportUnit::File(path) => {
+            let file = File::open(path).map_err(|e| e.to_string())?;
+            scan_log_reader_for_blueprints(BufReader::new(file)).map_err(|e| e.to_string())
+        }
+        ImportUnit::ZipEntry { archive, name } => {
+            if !archives.contains_key(archive) {
+                let file = File::open(archive).map_err(|e| e.to_string())?;
+                let zip = ZipArchive::new(file).map_err(|e| e.to_string())?;
+                archives.insert(archive.clone(), zip);
+            }
+            let zip = archives.get_mut(archive).expect("archive ouverte");
+            let entry = zip.by_name(name).map_err(|e| e.to_string())?;
+            scan_capped(entry, MAX_ZIP_ENTRY_BYTES)
+        }
+    }
+}
+
+fn progress(
+    app: &AppHandle,
+    phase: &str,
+    files_done: u32,
+    files_total: u32,
+    current_file: Option<String>,
+    percent: u8,
+) {
+    emit_import_progress(
+        app,
+        BlueprintsImportProgress {
+            phase: phase.to_string(),
+            files_done,
+            files_total,
+            current_file,
+            percent,
+        },
+    );
+}
+
+/// Entrées d'`incoming` absentes de `existing`, dédoublonnées (première réception conservée).
+fn new_entries_against(
+    existing: &[BlueprintEntry],
+    incoming: &[BlueprintEntry],
+) -> Vec<BlueprintEntry> {
+    let known: HashSet<(&str, &str)> = existing
+        .iter()
+        .map(|e| (e.owner.as_str(), e.product_name.as_str()))
+        .collect();
+    let (deduped, _) = merge_blueprint_entries(&[], incoming);
+    deduped
+        .into_iter()
+        .filter(|e| !known.contains(&(e.owner.as_str(), e.product_name.as_str())))
+        .collect()
+}
+
+fn preview_blueprints_import_sync(
+    app: &AppHandle,
+    sources: Vec<PathBuf>,
+) -> Result<BlueprintImportPreview, String> {
+    progress(app, "discovering_files", 0, 0, None, 2);
+
+    let (units, mut files) = collect_import_units(&sources);
+    if units.is_empty() && files.is_empty() {
+        return Err("Aucun fichier .log trouvé dans la sélection.".to_string());
+    }
+
+    let store = load_blueprint_store_sync(app)?;
+    let files_total = units.len() as u32;
+    let mut archives: HashMap<PathBuf, ZipArchive<File>> = HashMap::new();
+    let mut incoming: Vec<BlueprintEntry> = Vec::new();
+    let mut without_owner = 0usize;
+    let mut files_failed = files.len();
+
+    for (index, unit) in units.iter().enumerate() {
+        let files_done = index as u32;
+        progress(
+            app,
+            "scanning_logs",
+            files_done,
+            files_total,
+            unit.short_name(),
+            import_scan_percent(files_done, files_total),
+        );
+
+        let mut report = BlueprintImportFileReport {
+            source: unit.label(),
+            owner: None,
+            matches_found: 0,
+            new_entries: 0,
+            error: None,
+        };
+        match scan_unit(unit, &mut archives) {
+            Ok(found) => {
+                report.matches_found = found.len();
+                report.owner = found
+                    .first()
+                    .map(|e| e.owner.clone())
+                    .filter(|o| !o.trim().is_empty());
+                let (owned, orphaned): (Vec<_>, Vec<_>) =
+                    found.into_iter().partition(blueprint_has_owner);
+                without_owner += orphaned.len();
+                report.new_entries = new_entries_against(&store.blueprints, &owned).len();
+                incoming.extend(owned);
+            }
+            Err(e) => {
+                files_failed += 1;
+                report.error = Some(e);
+            }
+        }
+        files.push(report);
+    }
+
+    progress(app, "merging", files_total, files_total, None, 95);
+
+    let matches_found = files.iter().map(|f| f.matches_found).sum();
+    let new_entries = new_entries_against(&store.blueprints, &incoming);
+    let already_known = matches_found - without_owner - new_entries.len();
+
+    progress(app, "done", files_total, files_total, None, 100);
+
+    Ok(BlueprintImportPreview {
+        sources: sources.iter().map(|p| p.display().to_string()).collect(),
+        files,
+        new_entries,
+        matches_found,
+        already_known,
+        without_owner,
+        files_failed,
+    })
+}
+
+fn pick_import_sources(app: &AppHandle, folders: bool) -> Result<Vec<PathBuf>, String> {
+    let dialog = app.dialog().file().set_title("Importer des blueprints");
+    let picked = if folders {
+        dialog.blocking_pick_folders()
+    } else {
+        dialog
+            .add_filter("Logs Star Citizen", &["log", "zip"])
+            .blocking_pick_files()
+    };
+    picked
+        .unwrap_or_default()
+        .into_iter()
+        .map(|p| p.into_path().map_err(|e| e.to_string()))
+        .collect()
+}
+
+/// Scanne les sources (ou celles choisies dans le dialogue si `sources` est absent) sans
+/// modifier le store. `None` si le dialogue a été annulé.
+#[command]
+pub async fn preview_blueprints_import(
+    app: AppHandle,
+    sources: Option<Vec<String>>,
+    pick_folders: Option<bool>,
+) -> Result<Option<BlueprintImportPreview>, String> {
+    tokio::task::spawn_blocking(move || {
+        let sources: Vec<PathBuf> = match sources {
+            Some(list) => list.into_iter().map(PathBuf::from).collect(),
+            None => pick_import_sources(&app, pick_folders.unwrap_or(false))?,
+        };
+        if sources.is_empty() {
+            return Ok(None);
+        }
+        preview_blueprints_import_sync(&app, sources).map(Some)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Fusionne les entrées validées depuis l'aperçu ; retourne le nombre d'entrées ajoutées.
+#[command]
+pub async fn merge_blueprints_import(
+    app: AppHandle,
+    entries: Vec<BlueprintEntry>,
+) -> Result<usize, String> {
+    tokio::task::spawn_blocking(move || {
+        let entries: Vec<BlueprintEntry> =
+            entries.into_iter().filter(blueprint_has_owner).collect();
+        let total = entries.len() as u32;
+        progress(&app, "saving", total, total, None, 97);
+        let added = append_blueprints(&app, &entries)?;
+        progress(&app, "done", total, total, None, 100);
+        Ok(added)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    fn entry(owner: &str, product: &str, ts: f64) -> BlueprintEntry {
+        BlueprintEntry {
+            owner: owner.to_string(),
+            product_name: product.to_string(),
+            ts,
+            mission_guid: None,
+            mission_debug_name: None,
+            mission_trigger: None,
+            catalog_blueprint_id: None,
+            correlation_confidence: None,
+            correlation_alternatives: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn new_entries_skip_known_and_keep_earliest_duplicate() {
+        let existing = vec![entry("Onivoid", "Known", 1.0)];
+        let incoming = vec![
+            entry("Onivoid", "Known", 5.0),
+            entry("Onivoid", "Fresh", 9.0),
+            entry("Onivoid", "Fresh", 3.0),
+            entry("Friend", "Known", 4.0),
+        ];
+        let mut fresh = new_entries_against(&existing, &incoming);
+        fresh.sort_by(|a, b| a.owner.cmp(&b.owner));
+        assert_eq!(fresh.len(), 2);
+        assert_eq!(fresh[0].owner, "Friend");
+        assert_eq!(fresh[1].product_name, "Fresh");
+        assert_eq!(fresh[1].ts, 3.0);
+    }
+
+    #[test]
+    fn collect_units_expands_folders_and_zip_logs() {
+        let dir = std::env::temp_dir().join(format!("multitool_bp_import_{}", std::process::id()));
+        let nested = dir.join("old_pc").join("logbackups");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(nested.join("Game Build 1.log"), "x").unwrap();
+        fs::write(nested.join("notes.txt"), "x").unwrap();
+
+        let zip_path = dir.join("friend.zip");
+        let mut zip = ZipWriter::new(File::create(&zip_path).unwrap());
+        zip.start_file("LIVE/Game.log", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(
+            concat!(
+                "<2026-05-16T11:50:00.000Z> Added notification \"Received Blueprint: Morozov Legs: \" [41] to queue\n",
+                "<2026-05-16T11:51:00.000Z> [Notice] <Legacy login response> [CIG-net] User Login Success - Handle[Friend] - Time[1]\n",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        zip.start_file("LIVE/readme.md", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"doc").unwrap();
+        zip.finish().unwrap();
+
+        let (units, errors) = collect_import_units(std::slice::from_ref(&dir));
+        assert!(errors.is_empty());
+        let labels: Vec<String> = units.iter().map(|u| u.label()).collect();
+        assert_eq!(labels.len(), 2, "{labels:?}");
+        assert!(labels
+            .iter()
+            .any(|l| l.ends_with("friend.zip!LIVE/Game.log")));
+        assert!(labels.iter().any(|l| l.ends_with("Game Build 1.log")));
+
+        let mut archives = HashMap::new();
+        let zipped = units
+            .iter()
+            .find(|u| matches!(u, ImportUnit::ZipEntry { .. }))
+            .unwrap();
+        let found = scan_unit(zipped, &mut archives).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].product_name, "Morozov Legs");
+        assert_eq!(found[0].owner, "Friend");
+        let _ = fs::remove_dir_all(&dir);
+
+        // Entrée plus grande que la limite : refusée sans tout charger.
+        let big = vec![b'x'; 4096];
+        assert!(scan_capped(big.as_slice(), 1024).is_err());
+        assert!(scan_capped(big.as_slice(), 4096).is_ok());
+    }
+}
@@ -4,18 +4,22 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 use super::blueprints_catalog::{
     fetch_wiki_blueprint_by_uuid, http_client, is_mission_released_in_game,
-    load_wiki_catalog_from_disk, normalize_bp_id_key, summary_from_wiki, BlueprintDetail,
-    BlueprintSummary, IngredientEnrichment, IngredientLocationPreview, IngredientOption,
-    MissionBlueprintReward, MissionDetailResult, MissionInfo, WIKI_API_BASE,
+    load_wiki_catalog_from_disk, normalize_bp_id_key, product_match_key, summary_from_wiki,
+    wiki_catalog_cache_path, BlueprintDetail, BlueprintSummary, IngredientEnrichment,
+    IngredientLocationPreview, IngredientOption, MissionBlueprintReward, MissionDetailResult,
+    MissionInfo, WikiBlueprint, WIKI_API_BASE,
 };
+use super::game_log::locations::{is_known_star_system, wiki_slug_for_system};
 use tauri::command;
 
 const USER_AGENT: &str = "MultitoolV2-Blueprints/2.0";
 
-fn wiki_data_dir() -> Option<PathBuf> {
+pub(crate) fn wiki_data_dir() -> Option<PathBuf> {
     let dir = dirs::data_local_dir()?.join("multitool").join("blueprints");
     fs::create_dir_all(&dir).ok()?;
     Some(dir)
@@ -316,25 +320,28 @@ fn normalize_system_name(s: &str) -> String {
 
 fn system_to_location_slug(system: &str) -> String {
     let base = normalize_system_name(system);
-    let lower = base.to_ascii_lowercase();
-    match lower.as_str() {
-        "stanton" => "stanton-2".to_string(),
-        "pyro" => "pyro-2".to_string(),
-        "nyx" => "nyx-3".to_string(),
-        _ => format!("{}-2", lower.replace(' ', "-")),
+    if let Some(slug) = wiki_slug_for_system(&base) {
+        return slug;
     }
+    format!("{}-2", base.to_ascii_lowercase().replace(' ', "-"))
 }
 
-async fn fetch_location_jurisdiction(system: &str) -> Option<String> {
-    let slug = system_to_location_slug(system);
+/// Objet `data` de `/api/locations/{slug}` (système, planète, station…).
+pub(crate) async fn fetch_wiki_location(slug: &str) -> Option<serde_json::Value> {
     let url = format!("{WIKI_API_BASE}/api/locations/{slug}");
     let client = http_client().ok()?;
     let response = client.get(&url).send().await.ok()?;
     if !response.status().is_success() {
         return None;
     }
-    let json: serde_json::Value = response.json().await.ok()?;
-    json.get("data")?
+    let mut json: serde_json::Value = response.json().await.ok()?;
+    Some(json.get_mut("data")?.take())
+}
+
+async fn fetch_location_jurisdiction(system: &str) -> Option<String> {
+    let slug = system_to_location_slug(system);
+    fetch_wiki_location(&slug)
+        .await?
         .get("jurisdiction")?
         .get("name")?
         .as_str()
@@ -529,8 +536,7 @@ pub async fn build_unlock_index_background() {
                 }
                 if let Some(debug) = &m.debug_name {
                     for token in debug.split('_') {
-                        let lower = token.to_ascii_lowercase();
-                        if matches!(lower.as_str(), "nyx" | "stanton" | "pyro") {
+                        if is_known_star_system(token) {
                             let norm = normalize_system_name(token);
                             systems.insert(norm);
                         }
@@ -579,6 +585,135 @@ pub async fn build_unlock_index_background() {
     }
 }
 
+#[derive(Default)]
+struct MissionDrops {
+    blueprint_ids: HashSet<String>,
+    /// Noms EN/FR normalisés via [`product_match_key`].
+    name_keys: HashSet<String>,
+}
+
+/// Blueprints que chaque mission (par `debug_name`) peut donner, d'après le cache Wiki :
+/// `unlocking_missions` du catalogue + `blueprint_keys` du cache missions.
+#[derive(Default)]
+pub(crate) struct MissionDropIndex {
+    by_debug_name: HashMap<String, MissionDrops>,
+}
+
+impl MissionDropIndex {
+    pub(crate) fn insert(&mut self, debug_name: &str, blueprint_id: &str, names: &[&str]) {
+        let drops = self
+            .by_debug_name
+            .entry(debug_name.trim().to_ascii_lowercase())
+            .or_default();
+        drops
+            .blueprint_ids
+            .insert(normalize_bp_id_key(blueprint_id));
+        for name in names {
+            let key = product_match_key(name);
+            if key.len() >= 2 {
+                drops.name_keys.insert(key);
+            }
+        }
+    }
+
+    /// `None` si la mission est inconnue du Wiki (pas de verdict), sinon indique si elle
+    /// donne ce produit.
+    pub(crate) fn mission_drops(
+        &self,
+        debug_name: &str,
+        product_name: &str,
+        blueprint_id: Option<&str>,
+    ) -> Option<bool> {
+        let drops = self
+            .by_debug_name
+            .get(&debug_name.trim().to_ascii_lowercase())?;
+        if let Some(id) = blueprint_id {
+            if drops.blueprint_ids.contains(&normalize_bp_id_key(id)) {
+                return Some(true);
+            }
+        }
+        Some(drops.name_keys.contains(&product_match_key(product_name)))
+    }
+
+    fn insert_summary(&mut self, debug_name: &str, summary: &BlueprintSummary) {
+        let mut names = vec![summary.name_en.as_str()];
+        if let Some(fr) = summary.name_fr.as_deref() {
+            names.push(fr);
+        }
+        self.insert(debug_name, &summary.blueprint_id, &names);
+    }
+}
+
+fn build_mission_drop_index(
+    catalog: &[WikiBlueprint],
+    missions: &MissionCacheFile,
+) -> MissionDropIndex {
+    let mut index = MissionDropIndex::default();
+    let summaries: HashMap<&str, BlueprintSummary> = catalog
+        .iter()
+        .map(|bp| (bp.uuid.as_str(), summary_from_wiki(bp)))
+        .collect();
+    let mut debug_by_mission_uuid: HashMap<String, String> = HashMap::new();
+    for bp in catalog {
+        let Some(summary) = summaries.get(bp.uuid.as_str()) else {
+            continue;
+        };
+        for m in &bp.unlocking_missions {
+            let Some(debug) = m.debug_name.as_deref() else {
+                continue;
+            };
+            index.insert_summary(debug, summary);
+            if let Some(uuid) = m.web_url.as_deref().and_then(mission_uuid_from_url) {
+                debug_by_mission_uuid.insert(uuid, debug.to_string());
+            }
+        }
+    }
+    // Le détail mission peut lister des récompenses absentes de `unlocking_missions`.
+    for (uuid, cached) in &missions.missions {
+        let Some(debug) = debug_by_mission_uuid.get(uuid) else {
+            continue;
+        };
+        for key in &cached.blueprint_keys {
+            if let Some(summary) = summaries.get(key.as_str()) {
+                index.insert_summary(debug, summary);
+            }
+        }
+    }
+    index
+}
+
+struct CachedDropIndex {
+    sources_mtime: Vec<Option<SystemTime>>,
+    index: Arc<MissionDropIndex>,
+}
+
+static DROP_INDEX: Mutex<Option<CachedDropIndex>> = Mutex::new(None);
+
+fn drop_index_sources_mtime() -> Vec<Option<SystemTime>> {
+    [wiki_catalog_cache_path(), mission_cache_path()]
+        .into_iter()
+        .map(|p| p.and_then(|p| fs::metadata(p).ok()?.modified().ok()))
+        .collect()
+}
+
+/// Index mission → blueprints, reconstruit quand les caches Wiki changent sur disque.
+pub(crate) fn mission_drop_index() -> Arc<MissionDropIndex> {
+    let sources_mtime = drop_index_sources_mtime();
+    let mut guard = DROP_INDEX.lock().unwrap();
+    if let Some(cached) = guard.as_ref() {
+        if cached.sources_mtime == sources_mtime {
+            return Arc::clone(&cached.index);
+        }
+    }
+    let catalog = load_wiki_catalog_from_disk().unwrap_or_default();
+    let index = Arc::new(build_mission_drop_index(&catalog, &load_mission_cache()));
+    *guard = Some(CachedDropIndex {
+        sources_mtime,
+        index: Arc::clone(&index),
+    });
+    index
+}
+
 pub fn enrich_missions_from_cache(missions: &mut [MissionInfo]) {
     let cache = load_mission_cache();
     for m in missions.iter_mut() {
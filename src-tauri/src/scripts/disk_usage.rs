@@ -0,0 +1,610 @@
+//! Analyse de l'espace disque : installations par canal (`Data.p4k`, `USER`, logbackups,
+//! crashs), caches de shaders de `%LOCALAPPDATA%\Star Citizen` et données du Multitool
+//! (caches Wiki, archive des logs, cache des statistiques). Chaque poste est accompagné de
+//! suggestions de nettoyage chiffrées ; rien n'est supprimé ici (cf. `delete_folder`).
+
+use crate::scripts::game_log::cache::cache_file_path;
+use crate::scripts::gamelog_archive::{ensure_archive_dir, load_manifest};
+use crate::scripts::gamepath::get_star_citizen_versions_sync;
+use crate::scripts::gamepath_sources::wine_prefixes;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{command, AppHandle, Manager};
+
+/// Crashs plus anciens : leur rapport est archivé, le dump n'a plus d'intérêt.
+const OLD_CRASH_DAYS: u64 = 30;
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiskUsageKind {
+    GameData,
+    UserFolder,
+    LogBackups,
+    Crashes,
+    ShaderCache,
+    WikiCache,
+    GamelogArchive,
+    StatsCache,
+    AppData,
+}
+
+/// Conséquence d'un nettoyage : `Low` se régénère ou est déjà sauvegardé ailleurs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CleanupRisk {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CleanupSuggestion {
+    pub label: String,
+    pub detail: String,
+    pub estimated_savings: u64,
+    pub risk: CleanupRisk,
+    /// Chemins à supprimer pour appliquer la suggestion (vide : action ailleurs dans l'app).
+    pub paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskUsageEntry {
+    pub kind: DiskUsageKind,
+    pub label: String,
+    pub path: String,
+    pub bytes: u64,
+    pub file_count: u64,
+    pub suggestions: Vec<CleanupSuggestion>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelDiskUsage {
+    pub channel: String,
+    pub install_path: String,
+    /// Taille totale du dossier d'installation (y compris ce qui n'est pas détaillé).
+    pub total_bytes: u64,
+    pub entries: Vec<DiskUsageEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskUsageReport {
+    pub channels: Vec<ChannelDiskUsage>,
+    pub shader_caches: Vec<DiskUsageEntry>,
+    pub multitool: Vec<DiskUsageEntry>,
+    pub total_bytes: u64,
+    /// Somme des suggestions sans risque (`CleanupRisk::Low`).
+    pub total_savings: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct DirUsage {
+    bytes: u64,
+    files: u64,
+    newest_mtime_ms: u64,
+}
+
+impl DirUsage {
+    fn add(&mut self, other: DirUsage) {
+        self.bytes += other.bytes;
+        self.files += other.files;
+        self.newest_mtime_ms = self.newest_mtime_ms.max(other.newest_mtime_ms);
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn mtime_ms(meta: &fs::Metadata) -> u64 {
+    meta.modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Taille d'un fichier ou d'un dossier (récursif, liens symboliques non suivis).
+fn path_usage(path: &Path) -> DirUsage {
+    let Ok(meta) = fs::symlink_metadata(path) else {
+        return DirUsage::default();
+    };
+    if meta.is_file() {
+        return DirUsage {
+            bytes: meta.len(),
+            files: 1,
+            newest_mtime_ms: mtime_ms(&meta),
+        };
+    }
+    let mut usage = DirUsage::default();
+    if meta.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            usage.add(path_usage(&entry.path()));
+        }
+    }
+    usage
+}
+
+fn child_dirs(path: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(path)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+fn entry(kind: DiskUsageKind, label: &str, path: &Path, usage: DirUsage) -> DiskUsageEntry {
+    DiskUsageEntry {
+        kind,
+        label: label.to_string(),
+        path: path_string(path),
+        bytes: usage.bytes,
+        file_count: usage.files,
+        suggestions: Vec::new(),
+    }
+}
+
+/// Sources des logs archivés, vérifiés et encore présents dans le dossier du jeu.
+fn archived_sources(app: &AppHandle) -> HashSet<String> {
+    let Ok(root) = ensure_archive_dir(app.path()) else {
+        return HashSet::new();
+    };
+    load_manifest(&root)
+        .map(|manifest| {
+            manifest
+                .entries
+                .into_iter()
+                .filter(|e| e.is_active() && e.verified && !e.deleted_from_game)
+                .map(|e| e.source_path)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Dossier de crash dont chaque fichier a une copie vérifiée dans l'archive.
+fn crash_dir_archived(dir: &Path, archived: &HashSet<String>) -> bool {
+    let Ok(read) = fs::read_dir(dir) else {
+        return false;
+    };
+    let mut any = false;
+    for path in read.flatten().map(|e| e.path()) {
+        if !path.is_file() || !archived.contains(&path_string(&path)) {
+            return false;
+        }
+        any = true;
+    }
+    any
+}
+
+fn scan_channel(
+    channel: &str,
+    install: &Path,
+    archived: &HashSet<String>,
+    now_ms: u64,
+) -> ChannelDiskUsage {
+    let mut entries = Vec::new();
+
+    let p4k = install.join("Data.p4k");
+    let mut data = entry(
+        DiskUsageKind::GameData,
+        "Données du jeu (Data.p4k)",
+        &p4k,
+        path_usage(&p4k),
+    );
+    if !channel.eq_ignore_ascii_case("LIVE") && data.bytes > 0 {
+        data.suggestions.push(CleanupSuggestion {
+            label: format!("Désinstaller {channel}"),
+            detail: "Canal de test : le désinstaller depuis le launcher RSI libère toute \
+                     l'installation."
+                .to_string(),
+            estimated_savings: path_usage(install).bytes,
+            risk: CleanupRisk::Medium,
+            paths: Vec::new(),
+        });
+    }
+    entries.push(data);
+
+    let user = install.join("USER");
+    let mut user_entry = entry(
+        DiskUsageKind::UserFolder,
+        "Dossier USER",
+        &user,
+        path_usage(&user),
+    );
+    if user_entry.bytes > 0 {
+        user_entry.suggestions.push(CleanupSuggestion {
+            label: "Réinitialiser le dossier USER".to_string(),
+            detail: "Efface réglages, bindings et personnages : faire une sauvegarde de la \
+                     configuration avant."
+                .to_string(),
+            estimated_savings: user_entry.bytes,
+            risk: CleanupRisk::High,
+            paths: vec![path_string(&user)],
+        });
+    }
+    entries.push(user_entry);
+
+    let logbackups = install.join("logbackups");
+    let mut logs = entry(
+        DiskUsageKind::LogBackups,
+        "Logs précédents (logbackups)",
+        &logbackups,
+        path_usage(&logbackups),
+    );
+    let (archived_paths, archived_bytes) = fs::read_dir(&logbackups)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| archived.contains(&path_string(p)))
+        .fold((Vec::new(), 0u64), |(mut paths, bytes), p| {
+            let size = path_usage(&p).bytes;
+            paths.push(path_string(&p));
+            (paths, bytes + size)
+        });
+    if archived_bytes > 0 {
+        logs.suggestions.push(CleanupSuggestion {
+            label: "Retirer les logs déjà archivés".to_string(),
+            detail: format!(
+                "{} logs ont une copie vérifiée dans l'archive du Multitool.",
+                archived_paths.len()
+            ),
+            estimated_savings: archived_bytes,
+            risk: CleanupRisk::Low,
+            paths: archived_paths,
+        });
+    }
+    entries.push(logs);
+
+    let crashes = install.join("Crashes");
+    let mut crash_entry = entry(
+        DiskUsageKind::Crashes,
+        "Rapports de crash",
+        &crashes,
+        path_usage(&crashes),
+    );
+    let cutoff = now_ms.saturating_sub(OLD_CRASH_DAYS * DAY_MS);
+    // Seuls les crashs dont l'archive garde une copie vérifiée sont proposés à la suppression.
+    let (old_paths, old_bytes) = child_dirs(&crashes)
+        .into_iter()
+        .filter(|dir| crash_dir_archived(dir, archived))
+        .map(|dir| (path_usage(&dir), dir))
+        .filter(|(usage, _)| usage.newest_mtime_ms < cutoff)
+        .fold((Vec::new(), 0u64), |(mut paths, bytes), (usage, dir)| {
+            paths.push(path_string(&dir));
+            (paths, bytes + usage.bytes)
+        });
+    if old_bytes > 0 {
+        crash_entry.suggestions.push(CleanupSuggestion {
+            label: format!("Supprimer les crashs de plus de {OLD_CRASH_DAYS} jours"),
+            detail: format!(
+                "{} dossiers de crash (dumps mémoire inclus) ont une copie vérifiée dans \
+                 l'archive du Multitool.",
+                old_paths.len()
+            ),
+            estimated_savings: old_bytes,
+            risk: CleanupRisk::Low,
+            paths: old_paths,
+        });
+    }
+    entries.push(crash_entry);
+
+    ChannelDiskUsage {
+        channel: channel.to_string(),
+        install_path: path_string(install),
+        total_bytes: path_usage(install).bytes,
+        entries,
+    }
+}
+
+/// Dossiers `%LOCALAPPDATA%\Star Citizen` (natif et préfixes Wine).
+fn shader_cache_roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    if let Ok(local) = env::var("LOCALAPPDATA") {
+        roots.push(PathBuf::from(local).join("Star Citizen"));
+    } else if let Some(local) = dirs::data_local_dir() {
+        roots.push(local.join("Star Citizen"));
+    }
+    for prefix in wine_prefixes() {
+        for user in child_dirs(&prefix.join("drive_c").join("users")) {
+            roots.push(user.join("AppData").join("Local").join("Star Citizen"));
+        }
+    }
+    roots.retain(|r| r.is_dir());
+    roots.dedup();
+    roots
+}
+
+/// Un dossier par build (`sc-alpha-4.1.0`, …) : seul le plus récent sert encore.
+fn scan_shader_root(root: &Path) -> DiskUsageEntry {
+    let builds: Vec<(PathBuf, DirUsage)> = child_dirs(root)
+        .into_iter()
+        .map(|dir| {
+            let usage = path_usage(&dir);
+            (dir, usage)
+        })
+        .collect();
+    let mut total = DirUsage::default();
+    for (_, usage) in &builds {
+        total.add(*usage);
+    }
+    let mut cache = entry(DiskUsageKind::ShaderCache, "Cache de shaders", root, total);
+
+    let newest = builds
+        .iter()
+        .max_by_key(|(_, usage)| usage.newest_mtime_ms)
+        .map(|(dir, _)| dir.clone());
+    let stale: Vec<&(PathBuf, DirUsage)> = builds
+        .iter()
+        .filter(|(dir, _)| Some(dir) != newest.as_ref())
+        .collect();
+    let stale_bytes: u64 = stale.iter().map(|(_, usage)| usage.bytes).sum();
+    if stale_bytes > 0 {
+        cache.suggestions.push(CleanupSuggestion {
+            label: "Supprimer les caches des anciens builds".to_string(),
+            detail: format!("{} dossiers de builds précédents.", stale.len()),
+            estimated_savings: stale_bytes,
+            risk: CleanupRisk::Low,
+            paths: stale.iter().map(|(dir, _)| path_string(dir)).collect(),
+        });
+    }
+    if total.bytes > 0 {
+        cache.suggestions.push(CleanupSuggestion {
+            label: "Vider tout le cache de shaders".to_string(),
+            detail: "Regénéré au prochain lancement (premier chargement plus long).".to_string(),
+            estimated_savings: total.bytes,
+            risk: CleanupRisk::Medium,
+            paths: builds.iter().map(|(dir, _)| path_string(dir)).collect(),
+        });
+    }
+    cache
+}
+
+/// Sous-dossiers de `<données locales>/multitool` qui ne sont que des caches régénérables.
+/// Les autres (minuteurs de `hangar_exec`…) contiennent des données utilisateur : ignorés.
+const APP_CACHE_DIRS: &[(&str, &str)] = &[
+    ("blueprints", "Cache des blueprints"),
+    ("vehicles", "Cache des véhicules"),
+    ("paints", "Cache des peintures"),
+];
+
+fn scan_app_caches(root: &Path) -> Vec<DiskUsageEntry> {
+    let mut out = Vec::new();
+    for (name, label) in APP_CACHE_DIRS {
+        let sub = root.join(name);
+        if !sub.is_dir() {
+            continue;
+        }
+        let mut cache = entry(DiskUsageKind::WikiCache, label, &sub, path_usage(&sub));
+        if cache.bytes > 0 {
+            cache.suggestions.push(CleanupSuggestion {
+                label: "Vider le cache".to_string(),
+                detail: "Retéléchargé depuis le Wiki au prochain affichage.".to_string(),
+                estimated_savings: cache.bytes,
+                risk: CleanupRisk::Low,
+                paths: vec![path_string(&sub)],
+            });
+        }
+        out.push(cache);
+    }
+    out
+}
+
+fn stats_cache_entry(path: &Path) -> DiskUsageEntry {
+    let mut stats = entry(
+        DiskUsageKind::StatsCache,
+        "Cache des statistiques",
+        path,
+        path_usage(path),
+    );
+    if stats.bytes > 0 {
+        // Seule trace des logs supprimés par la rétention de l'archive : un scan complet ne
+        // peut pas la reconstituer.
+        stats.suggestions.push(CleanupSuggestion {
+            label: "Supprimer le cache des statistiques".to_string(),
+            detail: "Recalculé par un scan complet, mais l'historique des logs supprimés par la rétention de l'archive est perdu.".to_string(),
+            estimated_savings: stats.bytes,
+            risk: CleanupRisk::High,
+            paths: vec![path_string(path)],
+        });
+    }
+    stats
+}
+
+fn scan_multitool(app: &AppHandle) -> Vec<DiskUsageEntry> {
+    let mut out = Vec::new();
+
+    if let Some(dir) = dirs::data_local_dir().map(|d| d.join("multitool")) {
+        out.extend(scan_app_caches(&dir));
+    }
+
+    if let Ok(root) = ensure_archive_dir(app.path()) {
+        let mut archive = entry(
+            DiskUsageKind::GamelogArchive,
+            "Archive des logs",
+            &root,
+            path_usage(&root),
+        );
+        if let Ok(preview) = crate::scripts::gamelog_archive::preview_archive_retention_sync(app) {
+            if preview.bytes_to_free > 0 {
+                archive.suggestions.push(CleanupSuggestion {
+                    label: "Appliquer la rétention de l'archive".to_string(),
+                    detail: format!(
+                        "{} logs dépassent les limites configurées.",
+                        preview.candidates.len()
+                    ),
+                    estimated_savings: preview.bytes_to_free,
+                    risk: CleanupRisk::Medium,
+                    paths: Vec::new(),
+                });
+            }
+        }
+        out.push(archive);
+    }
+
+    if let Ok(path) = cache_file_path(app.path()) {
+        out.push(stats_cache_entry(&path));
+    }
+
+    if let Ok(dir) = app.path().app_data_dir() {
+        let backups = dir.join("patch_backups");
+        if backups.is_dir() {
+            out.push(entry(
+                DiskUsageKind::AppData,
+                "Sauvegardes post-patch",
+                &backups,
+                path_usage(&backups),
+            ));
+        }
+    }
+    out
+}
+
+/// Gain sans risque d'une entrée : ses suggestions se recouvrent, seule la plus forte compte.
+fn entry_savings(entry: &DiskUsageEntry) -> u64 {
+    entry
+        .suggestions
+        .iter()
+        .filter(|s| s.risk == CleanupRisk::Low)
+        .map(|s| s.estimated_savings)
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn get_disk_usage_report_sync(app: &AppHandle) -> Result<DiskUsageReport, String> {
+    let archived = archived_sources(app);
+    let now = now_ms();
+    let mut channels: Vec<ChannelDiskUsage> = get_star_citizen_versions_sync()
+        .versions
+        .into_iter()
+        .map(|(channel, info)| scan_channel(&channel, Path::new(&info.path), &archived, now))
+        .collect();
+    channels.sort_by(|a, b| a.channel.cmp(&b.channel));
+    let shader_caches: Vec<DiskUsageEntry> = shader_cache_roots()
+        .iter()
+        .map(|r| scan_shader_root(r))
+        .collect();
+    let multitool = scan_multitool(app);
+
+    let all_entries = || {
+        channels
+            .iter()
+            .flat_map(|c| c.entries.iter())
+            .chain(shader_caches.iter())
+            .chain(multitool.iter())
+    };
+    let total_bytes = channels.iter().map(|c| c.total_bytes).sum::<u64>()
+        + shader_caches.iter().map(|e| e.bytes).sum::<u64>()
+        + multitool.iter().map(|e| e.bytes).sum::<u64>();
+    let total_savings = all_entries().map(entry_savings).sum();
+
+    Ok(DiskUsageReport {
+        channels,
+        shader_caches,
+        multitool,
+        total_bytes,
+        total_savings,
+    })
+}
+
+/// Rapport d'occupation disque (installations, caches de shaders, données du Multitool).
+#[command]
+pub async fn get_disk_usage_report(app: AppHandle) -> Result<DiskUsageReport, String> {
+    tokio::task::spawn_blocking(move || get_disk_usage_report_sync(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, len: usize) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, vec![b'x'; len]).unwrap();
+    }
+
+    #[test]
+    fn channel_usage_and_suggestions() {
+        let base = std::env::temp_dir().join(format!("disk_usage_test_{}", std::process::id()));
+        let install = base.join("PTU");
+        write(&install.join("Data.p4k"), 1000);
+        write(&install.join("USER/Client/0/attributes.xml"), 10);
+        write(&install.join("logbackups/Game Build(1).log"), 200);
+        write(&install.join("logbackups/Game Build(2).log"), 300);
+        write(&install.join("Crashes/2024-01-01-00-00-00/dump.dmp"), 400);
+
+        let archived: HashSet<String> =
+            [path_string(&install.join("logbackups/Game Build(1).log"))].into();
+        let usage = scan_channel("PTU", &install, &archived, now_ms());
+        assert_eq!(usage.total_bytes, 1910);
+
+        let by_kind = |kind| usage.entries.iter().find(|e| e.kind == kind).unwrap();
+        let data = by_kind(DiskUsageKind::GameData);
+        assert_eq!(data.bytes, 1000);
+        assert_eq!(data.suggestions[0].estimated_savings, 1910);
+
+        let logs = by_kind(DiskUsageKind::LogBackups);
+        assert_eq!((logs.bytes, logs.file_count), (500, 2));
+        assert_eq!(logs.suggestions[0].estimated_savings, 200);
+
+        // Le dump vient d'être écrit : il n'a pas encore 30 jours.
+        assert!(by_kind(DiskUsageKind::Crashes).suggestions.is_empty());
+        let later = now_ms() + (OLD_CRASH_DAYS + 1) * DAY_MS;
+        let crash_suggestions = |archived: &HashSet<String>| {
+            scan_channel("PTU", &install, archived, later)
+                .entries
+                .into_iter()
+                .find(|e| e.kind == DiskUsageKind::Crashes)
+                .unwrap()
+                .suggestions
+        };
+        // Ancien mais jamais archivé : rien n'est proposé.
+        assert!(crash_suggestions(&archived).is_empty());
+        let mut archived = archived;
+        archived.insert(path_string(
+            &install.join("Crashes/2024-01-01-00-00-00/dump.dmp"),
+        ));
+        assert_eq!(crash_suggestions(&archived)[0].estimated_savings, 400);
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn app_caches_skip_user_data_and_stats_cache_is_high_risk() {
+        let base = std::env::temp_dir().join(format!("disk_usage_app_test_{}", std::process::id()));
+        write(&base.join("blueprints/wiki_blueprints_list.json"), 100);
+        write(&base.join("hangar_exec/terminal_timers.json"), 50);
+        let caches = scan_app_caches(&base);
+        assert_eq!(caches.len(), 1);
+        assert_eq!(caches[0].label, "Cache des blueprints");
+        assert_eq!(entry_savings(&caches[0]), 100);
+
+        let stats_path = base.join("game_stats_cache.json");
+        write(&stats_path, 30);
+        let stats = stats_cache_entry(&stats_path);
+        assert_eq!(stats.suggestions[0].risk, CleanupRisk::High);
+        assert_eq!(entry_savings(&stats), 0);
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+}
@@ -1,7 +1,7 @@
 use crate::scripts::game_log::snapshot::{GameStatsSnapshot, SNAPSHOT_SCHEMA_VERSION};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fs;
 use std::path::PathBuf;
 use tauri::path::PathResolver;
@@ -16,6 +16,9 @@ pub struct FileScanState {
     pub size: u64,
     pub last_processed_offset: u64,
     pub last_processed_ts: f64,
+    /// Empreinte de tête du contenu scanné : retrouve la session si le fichier est déplacé.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_hash: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +29,14 @@ pub struct GameStatsCacheFile {
     pub extractor_state: HashMap<String, Value>,
     pub file_state: HashMap<String, FileScanState>,
     pub last_scanned_newest_mtime_ms: u64,
+    /// État des extracteurs limité aux logs supprimés par la rétention de l'archive :
+    /// sert de point de départ aux rescans complets pour ne pas perdre leur historique.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub retired_extractor_state: HashMap<String, Value>,
+    /// Identités (`LogFingerprint::identity`) des logs déjà intégrés à l'état « retiré » : un
+    /// log dont la suppression a échoué ou a été interrompue n'est pas compté deux fois.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub retired_identities: BTreeSet<String>,
 }
 
 impl Default for GameStatsCacheFile {
@@ -36,6 +47,8 @@ impl Default for GameStatsCacheFile {
             extractor_state: HashMap::new(),
             file_state: HashMap::new(),
             last_scanned_newest_mtime_ms: 0,
+            retired_extractor_state: HashMap::new(),
+            retired_identities: BTreeSet::new(),
         }
     }
 }
@@ -85,6 +98,39 @@ pub fn save_cache(
     fs::write(file, json).map_err(|e| e.to_string())
 }
 
+/// Reporte l'état de scan d'un fichier déplacé à contenu identique (ex. archive recompressée),
+/// pour que le scan incrémental ne le relise pas sous son nouveau chemin.
+pub fn rename_file_state(
+    path: &PathResolver<impl Runtime>,
+    from: &str,
+    to: &str,
+    mtime_ms: u64,
+) -> Result<(), String> {
+    rename_file_states(path, &[(from.to_string(), to.to_string(), mtime_ms)])
+}
+
+/// Variante groupée de [`rename_file_state`] (déplacement de toute l'archive) : `(de, vers, mtime)`.
+pub fn rename_file_states(
+    path: &PathResolver<impl Runtime>,
+    moves: &[(String, String, u64)],
+) -> Result<(), String> {
+    let Some(mut cache) = load_cache(path)? else {
+        return Ok(());
+    };
+    let mut changed = false;
+    for (from, to, mtime_ms) in moves {
+        if let Some(mut state) = cache.file_state.remove(from) {
+            state.mtime_ms = *mtime_ms;
+            cache.file_state.insert(to.clone(), state);
+            changed = true;
+        }
+    }
+    if !changed {
+        return Ok(());
+    }
+    save_cache(path, &cache)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,11 @@
+use crate::scripts::game_log::io::{
+    is_compressed_log, log_content_len, log_fingerprint, LogFingerprint,
+};
 use crate::scripts::game_log::parse::is_game_build_log;
 use crate::scripts::gamelog_archive::{
     list_archived_log_files, list_live_session_game_logs, list_recoverable_archive_sources,
-    list_unarchived_logbackup_files, sync_logbackups_archive_sync, verified_archive_source_paths,
+    list_unarchived_logbackup_files, pruned_archive_identities, sync_logbackups_archive_sync,
+    verified_archive_source_paths,
 };
 use std::collections::HashSet;
 use std::fs;
@@ -15,9 +19,11 @@ pub struct GameLogFile {
     pub is_game_build: bool,
     pub mtime_ms: u64,
     pub size: u64,
-    /// Canal source (LIVE, HOTFIX) pour traçabilité.
+    /// Canal source (LIVE, HOTFIX, PTU…) pour traçabilité.
     #[allow(dead_code)]
     pub channel: Option<String>,
+    /// Identité du contenu (cf. `io::log_fingerprint`) ; `None` si le fichier est illisible.
+    pub fingerprint: Option<LogFingerprint>,
 }
 
 fn file_meta(path: &Path) -> (u64, u64) {
@@ -28,37 +34,77 @@ fn file_meta(path: &Path) -> (u64, u64) {
         .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
         .map(|d| d.as_millis() as u64)
         .unwrap_or(0);
-    let size = meta.map(|m| m.len()).unwrap_or(0);
+    // Archive compressée : taille du contenu décompressé (offsets du scan incrémental).
+    let size = if is_compressed_log(path) {
+        log_content_len(path).unwrap_or(0)
+    } else {
+        meta.map(|m| m.len()).unwrap_or(0)
+    };
     (mtime_ms, size)
 }
 
 fn is_log_file(path: &Path) -> bool {
     path.is_file()
-        && path
-            .extension()
-            .and_then(|e| e.to_str())
-            .map(|e| e.eq_ignore_ascii_case("log"))
-            .unwrap_or(false)
+        && (is_compressed_log(path)
+            || path
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(|e| e.eq_ignore_ascii_case("log"))
+                .unwrap_or(false))
 }
 
 fn path_dedup_key(path: &Path) -> String {
     path.to_string_lossy().to_ascii_lowercase()
 }
 
+/// Décrit un log isolé (hors listage du corpus) dont l'empreinte est déjà connue, ex. une
+/// archive avant sa suppression.
+pub(crate) fn describe_log_file(
+    path: PathBuf,
+    is_game_build: bool,
+    channel: Option<String>,
+    fingerprint: LogFingerprint,
+) -> GameLogFile {
+    let (mtime_ms, size) = file_meta(&path);
+    GameLogFile {
+        path,
+        is_game_build,
+        mtime_ms,
+        size,
+        channel,
+        fingerprint: Some(fingerprint),
+    }
+}
+
+/// Fichiers déjà retenus : par chemin, puis par contenu (une session renommée, copiée d'un
+/// canal à l'autre ou récupérée d'une autre source n'est comptée qu'une fois).
+#[derive(Default)]
+struct SeenLogs {
+    paths: HashSet<String>,
+    identities: HashSet<String>,
+}
+
 fn try_push_file(
     files: &mut Vec<GameLogFile>,
-    seen: &mut HashSet<String>,
+    seen: &mut SeenLogs,
     path: PathBuf,
     is_game_build: bool,
     channel: Option<String>,
+    known_fingerprint: Option<LogFingerprint>,
 ) {
     if !is_log_file(&path) {
         return;
     }
     let key = path_dedup_key(&path);
-    if !seen.insert(key) {
+    if !seen.paths.insert(key) {
         return;
     }
+    let fingerprint = known_fingerprint.or_else(|| log_fingerprint(&path).ok());
+    if let Some(fingerprint) = &fingerprint {
+        if !seen.identities.insert(fingerprint.identity.clone()) {
+            return;
+        }
+    }
     let (mtime_ms, size) = file_meta(&path);
     files.push(GameLogFile {
         path,
@@ -66,22 +112,29 @@ fn try_push_file(
         mtime_ms,
         size,
         channel,
+        fingerprint,
     });
 }
 
-/// Liste les logs de jeu pour les statistiques (corpus mergé LIVE + HOTFIX).
+/// Liste les logs de jeu pour les statistiques (corpus mergé des canaux suivis).
 ///
 /// Sources :
 /// - Archive locale Multitool (`gamelog_archive/files/`) — logbackups sauvegardés
 /// - `logbackups/` du jeu non encore archivés (évite de perdre des heures avant sync)
 /// - `Game.log` session courante par canal (intégration directe, non archivés)
+///
+/// Une même session présente à plusieurs endroits n'est listée qu'une fois, sous sa source la
+/// plus stable (archive d'abord) ; celles déjà purgées par la rétention sont écartées.
 pub fn list_game_log_files(app: &AppHandle) -> Result<Vec<GameLogFile>, String> {
     // Synchronise d'abord pour ne pas ignorer des logbackups pas encore copiés.
     let _ = sync_logbackups_archive_sync(app);
 
     let archived_sources = verified_archive_source_paths(app)?;
     let mut files: Vec<GameLogFile> = Vec::new();
-    let mut seen: HashSet<String> = HashSet::new();
+    let mut seen = SeenLogs {
+        identities: pruned_archive_identities(app)?,
+        ..SeenLogs::default()
+    };
 
     for archived in list_archived_log_files(app)? {
         try_push_file(
@@ -90,16 +143,18 @@ pub fn list_game_log_files(app: &AppHandle) -> Result<Vec<GameLogFile>, String>
             archived.path,
             archived.is_game_build,
             Some(archived.channel),
+            archived.fingerprint,
         );
     }
 
-    for pending in list_unarchived_logbackup_files(&archived_sources) {
+    for pending in list_unarchived_logbackup_files(app, &archived_sources) {
         try_push_file(
             &mut files,
             &mut seen,
             pending.path,
             pending.is_game_build,
             Some(pending.channel),
+            pending.fingerprint,
         );
     }
 
@@ -114,26 +169,35 @@ pub fn list_game_log_files(app: &AppHandle) -> Result<Vec<GameLogFile>, String>
             recoverable.path,
             recoverable.is_game_build,
             Some(recoverable.channel),
+            recoverable.fingerprint,
         );
     }
 
-    for live in list_live_session_game_logs() {
+    for live in list_live_session_game_logs(app) {
         try_push_file(
             &mut files,
             &mut seen,
             live.path.clone(),
             false,
             Some(live.channel.clone()),
+            None,
         );
     }
 
     // Fallback : Game Build à la racine des canaux (non encore dans logbackups)
-    for (channel, install) in crate::scripts::gamelog_archive::detected_archive_channels() {
+    for (channel, install) in crate::scripts::gamelog_channels::watched_game_channels(app) {
         if let Ok(entries) = fs::read_dir(&install) {
             for entry in entries.filter_map(|e| e.ok()) {
                 let path = entry.path();
                 if path.is_file() && is_game_build_log(&path) {
-                    try_push_file(&mut files, &mut seen, path, true, Some(channel.clone()));
+                    try_push_file(
+                        &mut files,
+                        &mut seen,
+                        path,
+                        true,
+                        Some(channel.clone()),
+                        None,
+                    );
                 }
             }
         }
@@ -16,7 +16,7 @@ pub struct GameStatsResponse {
 
 const SCAN_IN_PROGRESS_CODE: &str = "GAME_STATS_SCAN_IN_PROGRESS";
 
-fn scan_busy_error(status: GameStatsScanStatus) -> String {
+pub(crate) fn scan_busy_error(status: GameStatsScanStatus) -> String {
     serde_json::json!({
         "code": SCAN_IN_PROGRESS_CODE,
         "status": status,
@@ -39,41 +39,46 @@ pub async fn get_cached_game_stats(app: AppHandle) -> Result<Option<GameStatsSna
         .map_err(|e| e.to_string())?
 }
 
+/// Scan incrémental (complet sans cache) ; refusé si un scan est déjà en cours.
+pub fn load_game_stats_sync(
+    app: &AppHandle,
+    scan_state: &GameStatsScanState,
+) -> Result<GameStatsResponse, String> {
+    if let Err(busy) = scan_state.try_begin(GameStatsScanKind::Load) {
+        return Err(scan_busy_error(busy));
+    }
+    let resolver = app.path().clone();
+
+    let result = (|| {
+        let had_cache = load_cache(&resolver)?.is_some();
+        let mode = if had_cache {
+            ScanMode::Incremental
+        } else {
+            ScanMode::Full
+        };
+        let snapshot = run_scan(&resolver, mode, Some(app.clone()), Some(scan_state))?;
+        Ok(GameStatsResponse {
+            snapshot,
+            from_cache: had_cache,
+        })
+    })();
+
+    scan_state.finish();
+    if let Ok(ref response) = result {
+        let _ = app.emit(GAME_STATS_SCAN_FINISHED_EVENT, &response.snapshot);
+    }
+    result
+}
+
 #[command]
 pub async fn get_game_stats(
     app: AppHandle,
     _state: State<'_, GameStatsScanState>,
 ) -> Result<GameStatsResponse, String> {
-    let app_handle = app.clone();
     let scan_state = (*app.state::<GameStatsScanState>()).clone();
-    let resolver = app.path().clone();
-    tokio::task::spawn_blocking(move || {
-        if let Err(busy) = scan_state.try_begin(GameStatsScanKind::Load) {
-            return Err(scan_busy_error(busy));
-        }
-
-        let result = (|| {
-            let had_cache = load_cache(&resolver)?.is_some();
-            let mode = if had_cache {
-                ScanMode::Incremental
-            } else {
-                ScanMode::Full
-            };
-            let snapshot = run_scan(&resolver, mode, Some(app_handle.clone()), Some(&scan_state))?;
-            Ok(GameStatsResponse {
-                snapshot,
-                from_cache: had_cache,
-            })
-        })();
-
-        scan_state.finish();
-        if let Ok(ref response) = result {
-            let _ = app_handle.emit(GAME_STATS_SCAN_FINISHED_EVENT, &response.snapshot);
-        }
-        result
-    })
-    .await
-    .map_err(|e| e.to_string())?
+    tokio::task::spawn_blocking(move || load_game_stats_sync(&app, &scan_state))
+        .await
+        .map_err(|e| e.to_string())?
 }
 
 #[command]
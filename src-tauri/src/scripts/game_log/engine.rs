@@ -8,6 +8,7 @@ use crate::scripts::game_log::parse::parse_log_timestamp;
 use crate::scripts::game_log::progress::{file_basename, GameStatsScanProgress};
 use crate::scripts::game_log::scan_state::GameStatsScanState;
 use crate::scripts::game_log::snapshot::GameStatsSnapshot;
+use std::collections::HashMap;
 use std::path::Path;
 use tauri::path::PathResolver;
 use tauri::{AppHandle, Runtime};
@@ -22,9 +23,54 @@ fn path_key(path: &Path) -> String {
     path.to_string_lossy().into_owned()
 }
 
+fn file_head_hash(file: &GameLogFile) -> Option<String> {
+    file.fingerprint.as_ref().and_then(|f| f.head.clone())
+}
+
+/// Rattache à leur nouveau chemin les sessions déjà scannées qui ont été déplacées
+/// (`Game.log` → `logbackups/` → archive) : même empreinte de tête, au moins autant d'octets,
+/// et l'ancien chemin a disparu ou porte désormais un autre contenu. Le scan incrémental
+/// reprend alors à l'offset connu au lieu de recompter la session. Renvoie `(ancien, nouveau)`.
+fn adopt_moved_file_states(
+    files: &[GameLogFile],
+    file_state: &mut HashMap<String, FileScanState>,
+) -> Vec<(String, String)> {
+    let listed_heads: HashMap<String, Option<String>> = files
+        .iter()
+        .map(|f| (path_key(&f.path), file_head_hash(f)))
+        .collect();
+    let mut moved = Vec::new();
+    for file in files {
+        let key = path_key(&file.path);
+        let Some(head) = file_head_hash(file) else {
+            continue;
+        };
+        if file_state.contains_key(&key) {
+            continue;
+        }
+        let previous = file_state
+            .iter()
+            .find(|(old_key, state)| {
+                state.head_hash.as_deref() == Some(head.as_str())
+                    && state.last_processed_offset <= file.size
+                    && listed_heads
+                        .get(*old_key)
+                        .is_none_or(|listed| listed.as_deref() != Some(head.as_str()))
+            })
+            .map(|(old_key, _)| old_key.clone());
+        if let Some(old_key) = previous {
+            if let Some(state) = file_state.remove(&old_key) {
+                file_state.insert(key.clone(), state);
+                moved.push((old_key, key));
+            }
+        }
+    }
+    moved
+}
+
 fn should_scan_file(
     file: &GameLogFile,
-    file_state: &std::collections::HashMap<String, FileScanState>,
+    file_state: &HashMap<String, FileScanState>,
     mode: ScanMode,
 ) -> bool {
     if mode == ScanMode::Full {
@@ -61,7 +107,7 @@ fn process_lines(
 fn scan_file(
     extractors: &mut [Box<dyn GameLogStatExtractor>],
     file: &GameLogFile,
-    file_state: &mut std::collections::HashMap<String, FileScanState>,
+    file_state: &mut HashMap<String, FileScanState>,
     mode: ScanMode,
 ) -> Result<(), String> {
     let key = path_key(&file.path);
@@ -72,7 +118,12 @@ fn scan_file(
     };
 
     if let Some(p) = &prev {
-        if file.size < p.last_processed_offset {
+        // Fichier tronqué, ou même chemin réutilisé par une nouvelle session.
+        let head_changed = matches!(
+            (&p.head_hash, file_head_hash(file)),
+            (Some(old), Some(new)) if *old != new
+        );
+        if file.size < p.last_processed_offset || head_changed {
             start_offset = 0;
         }
     }
@@ -91,6 +142,7 @@ fn scan_file(
                 size: file.size,
                 last_processed_offset: file.size,
                 last_processed_ts: prev.as_ref().map(|p| p.last_processed_ts).unwrap_or(0.0),
+                head_hash: file_head_hash(file),
             },
         );
         return Ok(());
@@ -132,13 +184,14 @@ fn scan_file(
             size: file.size,
             last_processed_offset: file.size,
             last_processed_ts: last_ts,
+            head_hash: file_head_hash(file),
         },
     );
 
     Ok(())
 }
 
-fn build_snapshot(extractors: &[Box<dyn GameLogStatExtractor>]) -> GameStatsSnapshot {
+pub(crate) fn build_snapshot(extractors: &[Box<dyn GameLogStatExtractor>]) -> GameStatsSnapshot {
     let mut snapshot = GameStatsSnapshot {
         computed_at: chrono::Utc::now().timestamp(),
         ..GameStatsSnapshot::default()
@@ -156,6 +209,24 @@ fn scan_percent(files_done: u32, files_total: u32) -> u8 {
     (5 + (files_done.saturating_mul(85) / files_total)).min(90) as u8
 }
 
+/// Point de départ d'un rescan complet : l'état courant est oublié, seul subsiste celui des
+/// logs supprimés par la rétention, qui ne seront plus relus.
+fn seed_full_rescan(
+    extractors: &mut [Box<dyn GameLogStatExtractor>],
+    cache: &mut GameStatsCacheFile,
+) {
+    for ext in extractors.iter_mut() {
+        ext.reset();
+    }
+    cache.file_state.clear();
+    cache.extractor_state.clear();
+    for ext in extractors.iter_mut() {
+        if let Some(state) = cache.retired_extractor_state.get(ext.id()) {
+            ext.merge_cached(state);
+        }
+    }
+}
+
 pub fn run_scan(
     path_resolver: &PathResolver<impl Runtime>,
     mode: ScanMode,
@@ -193,23 +264,29 @@ pub fn run_scan(
     );
     let mut cache = match super::cache::load_cache(path_resolver)? {
         Some(c) if mode == ScanMode::Incremental => c,
-        _ => GameStatsCacheFile::default(),
+        Some(c) => GameStatsCacheFile {
+            retired_extractor_state: c.retired_extractor_state,
+            retired_identities: c.retired_identities,
+            ..GameStatsCacheFile::default()
+        },
+        None => GameStatsCacheFile::default(),
     };
 
     let mut extractors = default_extractors();
 
     if mode == ScanMode::Full {
-        for ext in extractors.iter_mut() {
-            ext.reset();
-        }
-        cache.file_state.clear();
-        cache.extractor_state.clear();
+        seed_full_rescan(&mut extractors, &mut cache);
     } else {
         for ext in extractors.iter_mut() {
             if let Some(state) = cache.extractor_state.get(ext.id()) {
                 ext.merge_cached(state);
             }
         }
+        for (from, to) in adopt_moved_file_states(&files, &mut cache.file_state) {
+            for ext in extractors.iter_mut() {
+                ext.rename_file(&from, &to);
+            }
+        }
     }
 
     for (index, file) in files.iter().enumerate() {
@@ -294,6 +371,102 @@ pub fn run_scan(
     Ok(cache.snapshot)
 }
 
+fn scan_into_state(
+    state: &std::collections::HashMap<String, serde_json::Value>,
+    file: &GameLogFile,
+    content: &str,
+) -> Vec<Box<dyn GameLogStatExtractor>> {
+    let mut extractors = default_extractors();
+    for ext in extractors.iter_mut() {
+        ext.reset();
+        if let Some(value) = state.get(ext.id()) {
+            ext.merge_cached(value);
+        }
+    }
+    process_lines(&mut extractors, content, file);
+    let file_ctx = FileContext {
+        file_path: path_key(&file.path),
+        is_game_build: file.is_game_build,
+    };
+    for ext in extractors.iter_mut() {
+        ext.on_file_end(&file.path, &file_ctx);
+    }
+    extractors
+}
+
+/// Intègre définitivement, un par un, des logs sur le point d'être supprimés : leur contenu
+/// rejoint l'état « retiré » (base des rescans complets) et, s'ils n'ont jamais été scannés,
+/// l'état courant. Le cache est chargé une fois puis enregistré après chaque log, avec son
+/// identité. L'appelant doit détenir le verrou de scan des statistiques.
+pub struct RetiredLogFolder<'a, R: Runtime> {
+    path_resolver: &'a PathResolver<R>,
+    cache: GameStatsCacheFile,
+}
+
+impl<'a, R: Runtime> RetiredLogFolder<'a, R> {
+    pub fn load(path_resolver: &'a PathResolver<R>) -> Result<Self, String> {
+        let cache = super::cache::load_cache(path_resolver)?.unwrap_or_default();
+        Ok(Self {
+            path_resolver,
+            cache,
+        })
+    }
+
+    /// Renvoie `false` (sans rien écrire) si ce log avait déjà été intégré.
+    pub fn fold(&mut self, file: &GameLogFile, content: &str) -> Result<bool, String> {
+        if !fold_retired_content(&mut self.cache, file, content) {
+            return Ok(false);
+        }
+        super::cache::save_cache(self.path_resolver, &self.cache)?;
+        Ok(true)
+    }
+}
+
+fn fold_retired_content(cache: &mut GameStatsCacheFile, file: &GameLogFile, content: &str) -> bool {
+    if let Some(fingerprint) = &file.fingerprint {
+        if !cache
+            .retired_identities
+            .insert(fingerprint.identity.clone())
+        {
+            return false;
+        }
+    }
+    let retired = scan_into_state(&cache.retired_extractor_state, file, content);
+    cache.retired_extractor_state = export_states(&retired);
+
+    let key = path_key(&file.path);
+    if cache.file_state.contains_key(&key) {
+        return true;
+    }
+    let current = scan_into_state(&cache.extractor_state, file, content);
+    cache.extractor_state = export_states(&current);
+    cache.snapshot = build_snapshot(&current);
+    let last_ts = content
+        .lines()
+        .filter_map(parse_log_timestamp)
+        .fold(0.0, f64::max);
+    cache.file_state.insert(
+        key,
+        FileScanState {
+            mtime_ms: file.mtime_ms,
+            size: file.size,
+            last_processed_offset: file.size,
+            last_processed_ts: last_ts,
+            head_hash: file_head_hash(file),
+        },
+    );
+    true
+}
+
+fn export_states(
+    extractors: &[Box<dyn GameLogStatExtractor>],
+) -> HashMap<String, serde_json::Value> {
+    extractors
+        .iter()
+        .map(|ext| (ext.id().to_string(), ext.export_cache()))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,7 +474,8 @@ mod tests {
         blueprints::BlueprintsExtractor, missions::MissionsExtractor, playtime::PlaytimeExtractor,
         star_systems::StarSystemsExtractor, vehicles::VehiclesExtractor,
     };
-    use std::path::Path;
+    use crate::scripts::game_log::io::LogFingerprint;
+    use std::path::{Path, PathBuf};
 
     #[test]
     fn playtime_fixture_session_duration() {
@@ -344,6 +518,102 @@ mod tests {
         assert_eq!(snapshot.playtime.session_count, 1);
     }
 
+    fn listed(path: &str, size: u64, head: &str) -> GameLogFile {
+        GameLogFile {
+            path: PathBuf::from(path),
+            is_game_build: !path.ends_with("Game.log"),
+            mtime_ms: 0,
+            size,
+            channel: Some("LIVE".to_string()),
+            fingerprint: Some(LogFingerprint {
+                identity: format!("{path}:{size}"),
+                head: Some(head.to_string()),
+            }),
+        }
+    }
+
+    #[test]
+    fn moved_session_keeps_its_scan_offset() {
+        let mut file_state = HashMap::new();
+        file_state.insert(
+            "LIVE/Game.log".to_string(),
+            FileScanState {
+                mtime_ms: 1,
+                size: 100,
+                last_processed_offset: 100,
+                last_processed_ts: 42.0,
+                head_hash: Some("h1".to_string()),
+            },
+        );
+        // Le jeu a redémarré : l'ancienne session est passée dans logbackups/.
+        let files = [
+            listed("LIVE/Game.log", 30, "h2"),
+            listed("LIVE/logbackups/Game Build(1).log", 120, "h1"),
+        ];
+
+        let moved = adopt_moved_file_states(&files, &mut file_state);
+        assert_eq!(
+            moved,
+            vec![(
+                "LIVE/Game.log".to_string(),
+                "LIVE/logbackups/Game Build(1).log".to_string()
+            )]
+        );
+        assert!(!file_state.contains_key("LIVE/Game.log"));
+        let adopted = &file_state["LIVE/logbackups/Game Build(1).log"];
+        assert_eq!(adopted.last_processed_offset, 100);
+        assert!(should_scan_file(
+            &files[0],
+            &file_state,
+            ScanMode::Incremental
+        ));
+    }
+
+    #[test]
+    fn full_rescan_after_pruning_keeps_folded_totals() {
+        let ended = |id: &str, kind: &str| {
+            format!(
+                "<2026-05-30T10:00:00.000Z> [Notice] <EndMission> MissionId[{id}] CompletionType[{kind}] Reason[Mission Ended]"
+            )
+        };
+        let scanned = listed("archive/files/a.log.gz", 10, "ha");
+        let unscanned = listed("archive/files/b.log.gz", 10, "hb");
+
+        // `a` a déjà été compté par un scan, `b` jamais : les deux sont supprimés.
+        let mut cache = GameStatsCacheFile::default();
+        cache.file_state.insert(
+            path_key(&scanned.path),
+            FileScanState {
+                mtime_ms: 0,
+                size: 10,
+                last_processed_offset: 10,
+                last_processed_ts: 0.0,
+                head_hash: Some("ha".to_string()),
+            },
+        );
+        for (file, content) in [
+            (&scanned, ended("m1", "Complete")),
+            (&unscanned, ended("m2", "Complete")),
+        ] {
+            assert!(fold_retired_content(&mut cache, file, &content));
+        }
+        assert_eq!(cache.snapshot.missions.completed, 1);
+        // Suppression échouée ou interrompue : la rétention suivante le présente à nouveau.
+        assert!(!fold_retired_content(
+            &mut cache,
+            &unscanned,
+            &ended("m2", "Complete")
+        ));
+
+        // Le cache relu puis un rescan complet qui ne retrouve plus aucun des deux logs.
+        let json = serde_json::to_string(&cache).unwrap();
+        let mut cache: GameStatsCacheFile = serde_json::from_str(&json).unwrap();
+        let mut extractors = default_extractors();
+        seed_full_rescan(&mut extractors, &mut cache);
+        assert!(cache.file_state.is_empty());
+        assert_eq!(build_snapshot(&extractors).missions.completed, 2);
+    }
+
     #[test]
     fn home_stats_extractors_on_synthetic_lines() {
         let lines = [
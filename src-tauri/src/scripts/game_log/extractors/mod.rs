@@ -30,6 +30,9 @@ pub trait GameLogStatExtractor: Send {
     fn reset(&mut self);
     fn on_line(&mut self, line: &str, ctx: &LineContext);
     fn on_file_end(&mut self, path: &Path, ctx: &FileContext);
+    /// Le log `from` a changé de place (`Game.log` → `logbackups/` → archive) : l'état propre
+    /// au fichier suit son contenu.
+    fn rename_file(&mut self, _from: &str, _to: &str) {}
     fn contribute(&self, out: &mut GameStatsSnapshot);
     fn merge_cached(&mut self, cached: &Value);
     fn export_cache(&self) -> Value;
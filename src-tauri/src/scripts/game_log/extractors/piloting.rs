@@ -169,6 +169,12 @@ impl GameLogStatExtractor for PilotingExtractor {
         self.current_file = None;
     }
 
+    fn rename_file(&mut self, from: &str, to: &str) {
+        if let Some(state) = self.open_by_file.remove(from) {
+            self.open_by_file.insert(to.to_string(), state);
+        }
+    }
+
     fn contribute(&self, out: &mut GameStatsSnapshot) {
         let merged = merge_time_intervals(self.intervals.clone());
         let total_seconds = total_seconds_from_intervals(&merged);
@@ -124,6 +124,15 @@ impl GameLogStatExtractor for PlaytimeExtractor {
         self.flush_current_file(path, ctx);
     }
 
+    fn rename_file(&mut self, from: &str, to: &str) {
+        if let Some(id) = self.file_session_ids.remove(from) {
+            self.file_session_ids.insert(to.to_string(), id);
+        }
+        if let Some(session) = self.sessions.remove(&format!("file:{from}")) {
+            self.sessions.insert(format!("file:{to}"), session);
+        }
+    }
+
     fn contribute(&self, out: &mut GameStatsSnapshot) {
         let oldest_iso = self.oldest_game_build_start_ts.map(iso_from_timestamp);
         out.period.oldest_backup_start_iso = oldest_iso.clone();
@@ -63,7 +63,7 @@ impl GameLogStatExtractor for StarSystemsExtractor {
 
     fn on_line(&mut self, line: &str, _ctx: &LineContext) {
         if let Some(system) = detect_star_system(line) {
-            self.enter_system(system);
+            self.enter_system(&system);
         }
     }
 
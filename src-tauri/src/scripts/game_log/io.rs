@@ -1,9 +1,55 @@
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fs;
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{self, Read, Seek, SeekFrom};
 use std::path::Path;
 
+/// Log archivé compressé (`gamelog_archive`, `*.log.gz`).
+pub fn is_compressed_log(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.to_ascii_lowercase().ends_with(".log.gz"))
+}
+
+/// Taille du contenu décompressé : les offsets du scan incrémental portent sur ce contenu.
+/// Pour un gzip, lit le trailer ISIZE (taille modulo 2^32, suffisant pour un Game.log).
+pub fn log_content_len(path: &Path) -> Result<u64, String> {
+    let mut file =
+        fs::File::open(path).map_err(|e| format!("Impossible d'ouvrir {}: {e}", path.display()))?;
+    let len = file.metadata().map_err(|e| e.to_string())?.len();
+    if !is_compressed_log(path) {
+        return Ok(len);
+    }
+    if len < 4 {
+        return Ok(0);
+    }
+    let mut trailer = [0u8; 4];
+    file.seek(SeekFrom::End(-4)).map_err(|e| e.to_string())?;
+    file.read_exact(&mut trailer).map_err(|e| e.to_string())?;
+    Ok(u32::from_le_bytes(trailer) as u64)
+}
+
+/// Lecteur du contenu brut, décompressé à la volée si besoin.
+pub fn open_log_reader(path: &Path) -> Result<Box<dyn Read>, String> {
+    let file =
+        fs::File::open(path).map_err(|e| format!("Impossible d'ouvrir {}: {e}", path.display()))?;
+    if is_compressed_log(path) {
+        Ok(Box::new(GzDecoder::new(io::BufReader::new(file))))
+    } else {
+        Ok(Box::new(file))
+    }
+}
+
 /// Lit le contenu à partir d'un offset (pour scan incrémental de gros fichiers).
 pub fn read_from_offset(path: &Path, offset: u64) -> Result<String, String> {
+    if is_compressed_log(path) {
+        let mut reader = open_log_reader(path)?;
+        io::copy(&mut (&mut reader).take(offset), &mut io::sink()).map_err(|e| e.to_string())?;
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).map_err(|e| e.to_string())?;
+        return Ok(String::from_utf8_lossy(&buf).into_owned());
+    }
     let mut file =
         fs::File::open(path).map_err(|e| format!("Impossible d'ouvrir {}: {e}", path.display()))?;
     let len = file.metadata().map_err(|e| e.to_string())?.len();
@@ -18,7 +64,135 @@ pub fn read_from_offset(path: &Path, offset: u64) -> Result<String, String> {
 }
 
 pub fn read_log_file_lossy(path: &Path) -> Result<String, String> {
-    let bytes =
-        fs::read(path).map_err(|e| format!("Impossible de lire {}: {e}", path.display()))?;
+    let mut bytes = Vec::new();
+    open_log_reader(path)?
+        .read_to_end(&mut bytes)
+        .map_err(|e| format!("Impossible de lire {}: {e}", path.display()))?;
     Ok(String::from_utf8_lossy(&bytes).into_owned())
 }
+
+/// Contenu décompressé d'un log et son empreinte, calculée sur les mêmes octets : une seule
+/// lecture (et décompression) du fichier.
+pub fn read_log_with_fingerprint(path: &Path) -> Result<(String, LogFingerprint), String> {
+    let mut bytes = Vec::new();
+    open_log_reader(path)?
+        .read_to_end(&mut bytes)
+        .map_err(|e| format!("Impossible de lire {}: {e}", path.display()))?;
+    let edge = FINGERPRINT_EDGE_BYTES.min(bytes.len());
+    let fingerprint = fingerprint_from_parts(
+        bytes.len() as u64,
+        &bytes[..edge],
+        &bytes[bytes.len() - edge..],
+    );
+    Ok((String::from_utf8_lossy(&bytes).into_owned(), fingerprint))
+}
+
+/// Octets lus en tête et en queue pour l'empreinte d'identité d'un log.
+const FINGERPRINT_EDGE_BYTES: usize = 64 * 1024;
+/// Préfixe servant à reconnaître une session qui a grandi ou changé de place.
+const HEAD_HASH_BYTES: usize = 8 * 1024;
+
+/// Identité d'un log indépendante de son emplacement (`Game.log`, `logbackups/`, archive) et
+/// de sa compression : deux copies d'une même session ont la même `identity`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogFingerprint {
+    /// sha256 de la taille décompressée + 64 Kio de tête + 64 Kio de queue.
+    pub identity: String,
+    /// sha256 des 8 premiers Kio : stable tant que la session ne fait que grandir. Absente
+    /// tant que le log est plus court.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head: Option<String>,
+}
+
+fn fingerprint_from_parts(len: u64, head: &[u8], tail: &[u8]) -> LogFingerprint {
+    let mut hasher = Sha256::new();
+    hasher.update(len.to_le_bytes());
+    hasher.update(head);
+    hasher.update(tail);
+    let identity = format!("{:x}", hasher.finalize());
+    let head = (head.len() >= HEAD_HASH_BYTES)
+        .then(|| format!("{:x}", Sha256::digest(&head[..HEAD_HASH_BYTES])));
+    LogFingerprint { identity, head }
+}
+
+/// Empreinte tête/queue d'un log. Lecture directe pour un fichier brut ; une copie gzip est
+/// décompressée en flux (seules la tête et la queue restent en mémoire).
+pub fn log_fingerprint(path: &Path) -> Result<LogFingerprint, String> {
+    if is_compressed_log(path) {
+        let mut reader = open_log_reader(path)?;
+        let mut head = Vec::with_capacity(FINGERPRINT_EDGE_BYTES);
+        let mut tail: Vec<u8> = Vec::with_capacity(FINGERPRINT_EDGE_BYTES * 2);
+        let mut buffer = vec![0u8; 65536];
+        let mut len = 0u64;
+        loop {
+            let n = reader.read(&mut buffer).map_err(|e| e.to_string())?;
+            if n == 0 {
+                break;
+            }
+            let chunk = &buffer[..n];
+            if head.len() < FINGERPRINT_EDGE_BYTES {
+                let take = (FINGERPRINT_EDGE_BYTES - head.len()).min(n);
+                head.extend_from_slice(&chunk[..take]);
+            }
+            tail.extend_from_slice(chunk);
+            if tail.len() > FINGERPRINT_EDGE_BYTES * 2 {
+                tail.drain(..tail.len() - FINGERPRINT_EDGE_BYTES);
+            }
+            len += n as u64;
+        }
+        let tail_start = tail.len().saturating_sub(FINGERPRINT_EDGE_BYTES);
+        return Ok(fingerprint_from_parts(len, &head, &tail[tail_start..]));
+    }
+
+    let mut file =
+        fs::File::open(path).map_err(|e| format!("Impossible d'ouvrir {}: {e}", path.display()))?;
+    let len = file.metadata().map_err(|e| e.to_string())?.len();
+    let edge = (FINGERPRINT_EDGE_BYTES as u64).min(len);
+    let mut head = vec![0u8; edge as usize];
+    file.read_exact(&mut head).map_err(|e| e.to_string())?;
+    file.seek(SeekFrom::Start(len - edge))
+        .map_err(|e| e.to_string())?;
+    let mut tail = vec![0u8; edge as usize];
+    file.read_exact(&mut tail).map_err(|e| e.to_string())?;
+    Ok(fingerprint_from_parts(len, &head, &tail))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    #[test]
+    fn fingerprint_ignores_location_and_compression() {
+        let dir = std::env::temp_dir().join(format!("log_fingerprint_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let content: String = (0..20_000)
+            .map(|i| format!("<2026-01-01T00:00:00.000Z> ligne {i}\n"))
+            .collect();
+
+        let plain = dir.join("Game.log");
+        fs::write(&plain, &content).unwrap();
+        let gz = dir.join("LIVE__Game Build(1).log.gz");
+        let mut encoder = GzEncoder::new(fs::File::create(&gz).unwrap(), Compression::default());
+        encoder.write_all(content.as_bytes()).unwrap();
+        encoder.finish().unwrap();
+
+        let a = log_fingerprint(&plain).unwrap();
+        assert_eq!(a, log_fingerprint(&gz).unwrap());
+        let (read, read_fingerprint) = read_log_with_fingerprint(&gz).unwrap();
+        assert_eq!(read, content);
+        assert_eq!(read_fingerprint, a);
+
+        // La session continue : même tête, identité différente.
+        fs::write(&plain, format!("{content}<2026-01-01T01:00:00.000Z> fin\n")).unwrap();
+        let grown = log_fingerprint(&plain).unwrap();
+        assert!(a.head.is_some());
+        assert_eq!(grown.head, a.head);
+        assert_ne!(grown.identity, a.identity);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
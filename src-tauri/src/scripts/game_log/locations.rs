@@ -0,0 +1,627 @@
+//! Catalogue des systèmes / planètes / stations reconnus dans le Game.log.
+//!
+//! Trois couches fusionnées dans cet ordre :
+//! - données embarquées (`data/locations.json`) — toujours disponibles hors ligne
+//! - cache Star Citizen Wiki (`wiki_locations_catalog.json`) — rafraîchi à la demande
+//! - surcharge utilisateur (`location_catalog.json` dans le dossier de configuration)
+
+use crate::scripts::blueprints_wiki_extended::{fetch_wiki_location, wiki_data_dir};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use tauri::path::PathResolver;
+use tauri::{command, AppHandle, Manager, Runtime};
+
+const BUNDLED_LOCATIONS_JSON: &str = include_str!("data/locations.json");
+const LOCATION_CATALOG_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationPatterns {
+    /// Chemins de socpak (`/pu/system/pyro/…`).
+    #[serde(default)]
+    pub socpaks: Vec<String>,
+    /// Noms de zone / location (`zone [stanton1`, `location[pyro`).
+    #[serde(default)]
+    pub zones: Vec<String>,
+    /// Conteneurs OOC (`OOC_Stanton_2b_Daymar`).
+    #[serde(default)]
+    pub ooc: Vec<String>,
+}
+
+impl LocationPatterns {
+    fn all(&self) -> impl Iterator<Item = &String> {
+        self.socpaks.iter().chain(&self.zones).chain(&self.ooc)
+    }
+
+    fn extend_from(&mut self, other: &LocationPatterns) {
+        extend_unique(&mut self.socpaks, &other.socpaks);
+        extend_unique(&mut self.zones, &other.zones);
+        extend_unique(&mut self.ooc, &other.ooc);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogStation {
+    pub name: String,
+    #[serde(default)]
+    pub patterns: LocationPatterns,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogPlanet {
+    pub name: String,
+    #[serde(default)]
+    pub patterns: LocationPatterns,
+    #[serde(default)]
+    pub stations: Vec<CatalogStation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogSystem {
+    pub name: String,
+    /// Slug `/api/locations/{slug}` du Star Citizen Wiki.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wiki_slug: Option<String>,
+    /// Surcharge utilisateur : remplace entièrement le système au lieu de le compléter.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub replace: bool,
+    #[serde(default)]
+    pub patterns: LocationPatterns,
+    #[serde(default)]
+    pub planets: Vec<CatalogPlanet>,
+    /// Stations hors orbite planétaire (ex. Checkmate dans Pyro).
+    #[serde(default)]
+    pub stations: Vec<CatalogStation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationCatalogFile {
+    #[serde(default)]
+    pub schema_version: u32,
+    #[serde(default)]
+    pub systems: Vec<CatalogSystem>,
+}
+
+impl Default for LocationCatalogFile {
+    fn default() -> Self {
+        Self {
+            schema_version: LOCATION_CATALOG_SCHEMA_VERSION,
+            systems: Vec::new(),
+        }
+    }
+}
+
+/// Lieu reconnu sur une ligne de log (du plus général au plus précis).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationMatch {
+    pub system: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub planet: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub station: Option<String>,
+}
+
+struct CompiledPattern {
+    needle: String,
+    depth: u8,
+    location: LocationMatch,
+}
+
+/// Catalogue prêt pour la détection : motifs en minuscules, triés par spécificité.
+pub struct CompiledLocationCatalog {
+    catalog: LocationCatalogFile,
+    patterns: Vec<CompiledPattern>,
+}
+
+impl CompiledLocationCatalog {
+    fn new(catalog: LocationCatalogFile) -> Self {
+        let mut patterns = Vec::new();
+        for system in &catalog.systems {
+            let system_loc = LocationMatch {
+                system: system.name.clone(),
+                planet: None,
+                station: None,
+            };
+            push_patterns(&mut patterns, &system.patterns, 0, &system_loc);
+            for station in &system.stations {
+                let loc = LocationMatch {
+                    station: Some(station.name.clone()),
+                    ..system_loc.clone()
+                };
+                push_patterns(&mut patterns, &station.patterns, 2, &loc);
+            }
+            for planet in &system.planets {
+                let planet_loc = LocationMatch {
+                    planet: Some(planet.name.clone()),
+                    ..system_loc.clone()
+                };
+                push_patterns(&mut patterns, &planet.patterns, 1, &planet_loc);
+                for station in &planet.stations {
+                    let loc = LocationMatch {
+                        station: Some(station.name.clone()),
+                        ..planet_loc.clone()
+                    };
+                    push_patterns(&mut patterns, &station.patterns, 2, &loc);
+                }
+            }
+        }
+        // Le plus précis d'abord (station > planète > système), puis le motif le plus long.
+        patterns.sort_by(|a, b| {
+            b.depth
+                .cmp(&a.depth)
+                .then_with(|| b.needle.len().cmp(&a.needle.len()))
+        });
+        Self { catalog, patterns }
+    }
+
+    pub fn detect(&self, line: &str) -> Option<LocationMatch> {
+        let lower = line.to_ascii_lowercase();
+        self.patterns
+            .iter()
+            .find(|p| lower.contains(&p.needle))
+            .map(|p| p.location.clone())
+    }
+
+    pub fn system_names(&self) -> impl Iterator<Item = &str> {
+        self.catalog.systems.iter().map(|s| s.name.as_str())
+    }
+
+    pub fn wiki_slug(&self, system: &str) -> Option<&str> {
+        self.catalog
+            .systems
+            .iter()
+            .find(|s| s.name.eq_ignore_ascii_case(system))
+            .and_then(|s| s.wiki_slug.as_deref())
+    }
+}
+
+fn push_patterns(
+    out: &mut Vec<CompiledPattern>,
+    patterns: &LocationPatterns,
+    depth: u8,
+    location: &LocationMatch,
+) {
+    for raw in patterns.all() {
+        let needle = raw.trim().to_ascii_lowercase();
+        if needle.is_empty() {
+            continue;
+        }
+        out.push(CompiledPattern {
+            needle,
+            depth,
+            location: location.clone(),
+        });
+    }
+}
+
+fn extend_unique(target: &mut Vec<String>, incoming: &[String]) {
+    for value in incoming {
+        if !target.iter().any(|v| v.eq_ignore_ascii_case(value)) {
+            target.push(value.clone());
+        }
+    }
+}
+
+fn merge_stations(target: &mut Vec<CatalogStation>, incoming: &[CatalogStation]) {
+    for station in incoming {
+        match target
+            .iter_mut()
+            .find(|s| s.name.eq_ignore_ascii_case(&station.name))
+        {
+            Some(existing) => existing.patterns.extend_from(&station.patterns),
+            None => target.push(station.clone()),
+        }
+    }
+}
+
+fn merge_planets(target: &mut Vec<CatalogPlanet>, incoming: &[CatalogPlanet]) {
+    for planet in incoming {
+        match target
+            .iter_mut()
+            .find(|p| p.name.eq_ignore_ascii_case(&planet.name))
+        {
+            Some(existing) => {
+                existing.patterns.extend_from(&planet.patterns);
+                merge_stations(&mut existing.stations, &planet.stations);
+            }
+            None => target.push(planet.clone()),
+        }
+    }
+}
+
+/// Fusionne `overlay` dans `base` (systèmes, planètes et stations appariés par nom).
+pub fn merge_location_catalogs(base: &mut LocationCatalogFile, overlay: &LocationCatalogFile) {
+    for system in &overlay.systems {
+        let existing = base
+            .systems
+            .iter_mut()
+            .find(|s| s.name.eq_ignore_ascii_case(&system.name));
+        match existing {
+            Some(existing) if system.replace => *existing = system.clone(),
+            Some(existing) => {
+                if system.wiki_slug.is_some() {
+                    existing.wiki_slug = system.wiki_slug.clone();
+                }
+                existing.patterns.extend_from(&system.patterns);
+                merge_planets(&mut existing.planets, &system.planets);
+                merge_stations(&mut existing.stations, &system.stations);
+            }
+            None => base.systems.push(system.clone()),
+        }
+    }
+}
+
+pub fn bundled_location_catalog() -> LocationCatalogFile {
+    serde_json::from_str(BUNDLED_LOCATIONS_JSON).expect("data/locations.json invalide")
+}
+
+fn wiki_catalog_cache_path() -> Option<PathBuf> {
+    wiki_data_dir().map(|d| d.join("wiki_locations_catalog.json"))
+}
+
+fn user_catalog_path(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+    let dir = resolver
+        .app_config_dir()
+        .map_err(|_| "Impossible d'obtenir le répertoire de configuration".to_string())?;
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir.join("location_catalog.json"))
+}
+
+fn read_catalog_file(path: &PathBuf) -> Option<LocationCatalogFile> {
+    let bytes = fs::read(path).ok()?;
+    match serde_json::from_slice(&bytes) {
+        Ok(catalog) => Some(catalog),
+        Err(e) => {
+            eprintln!("[locations] {} illisible, ignoré: {e}", path.display());
+            None
+        }
+    }
+}
+
+/// Catalogue embarqué + cache Wiki (sans surcharge utilisateur).
+fn load_base_catalog() -> LocationCatalogFile {
+    let mut catalog = bundled_location_catalog();
+    if let Some(wiki) = wiki_catalog_cache_path().and_then(|p| read_catalog_file(&p)) {
+        merge_location_catalogs(&mut catalog, &wiki);
+    }
+    catalog
+}
+
+static CATALOG: Lazy<RwLock<Arc<CompiledLocationCatalog>>> =
+    Lazy::new(|| RwLock::new(Arc::new(CompiledLocationCatalog::new(load_base_catalog()))));
+
+/// Catalogue actif (partagé, peu coûteux à cloner).
+pub fn location_catalog() -> Arc<CompiledLocationCatalog> {
+    CATALOG
+        .read()
+        .map(|c| Arc::clone(&c))
+        .unwrap_or_else(|e| Arc::clone(&e.into_inner()))
+}
+
+fn install_catalog(catalog: LocationCatalogFile) {
+    let compiled = Arc::new(CompiledLocationCatalog::new(catalog));
+    match CATALOG.write() {
+        Ok(mut guard) => *guard = compiled,
+        Err(e) => *e.into_inner() = compiled,
+    }
+}
+
+/// Recharge les trois couches et remplace le catalogue actif.
+pub fn reload_location_catalog_sync(app: &AppHandle) -> Result<LocationCatalogFile, String> {
+    let mut catalog = load_base_catalog();
+    let user_path = user_catalog_path(app.path())?;
+    if let Some(user) = read_catalog_file(&user_path) {
+        merge_location_catalogs(&mut catalog, &user);
+    }
+    install_catalog(catalog.clone());
+    Ok(catalog)
+}
+
+pub fn detect_location(line: &str) -> Option<LocationMatch> {
+    location_catalog().detect(line)
+}
+
+pub fn is_known_star_system(name: &str) -> bool {
+    location_catalog()
+        .system_names()
+        .any(|s| s.eq_ignore_ascii_case(name.trim()))
+}
+
+pub fn wiki_slug_for_system(system: &str) -> Option<String> {
+    location_catalog().wiki_slug(system).map(str::to_string)
+}
+
+// ---------------------------------------------------------------------------
+// Star Citizen Wiki
+// ---------------------------------------------------------------------------
+
+fn wiki_children(node: &Value) -> Vec<&Value> {
+    ["children", "locations"]
+        .iter()
+        .filter_map(|key| node.get(*key).and_then(|v| v.as_array()))
+        .flatten()
+        .collect()
+}
+
+fn wiki_node_name(node: &Value) -> Option<String> {
+    node.get("name")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+fn wiki_node_type(node: &Value) -> String {
+    node.get("type")
+        .or_else(|| node.get("classification"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_ascii_lowercase()
+}
+
+fn is_wiki_body(kind: &str) -> bool {
+    kind.contains("planet") || kind.contains("moon")
+}
+
+fn is_wiki_station(kind: &str) -> bool {
+    ["station", "landing", "city", "outpost"]
+        .iter()
+        .any(|k| kind.contains(k))
+}
+
+/// Motifs de zone dérivés du nom Wiki (`Port Tressler` → `zone [porttressler`).
+fn wiki_zone_patterns(name: &str) -> LocationPatterns {
+    let compact: String = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if compact.is_empty() {
+        return LocationPatterns::default();
+    }
+    LocationPatterns {
+        zones: vec![format!("zone [{compact}"), format!("location[{compact}")],
+        ..LocationPatterns::default()
+    }
+}
+
+fn wiki_stations(node: &Value) -> Vec<CatalogStation> {
+    wiki_children(node)
+        .into_iter()
+        .filter(|c| is_wiki_station(&wiki_node_type(c)))
+        .filter_map(|c| {
+            let name = wiki_node_name(c)?;
+            Some(CatalogStation {
+                patterns: wiki_zone_patterns(&name),
+                name,
+            })
+        })
+        .collect()
+}
+
+/// Convertit la réponse `/api/locations/{slug}` en système du catalogue.
+pub fn system_from_wiki_location(system: &str, data: &Value) -> CatalogSystem {
+    let mut planets = Vec::new();
+    for child in wiki_children(data) {
+        if !is_wiki_body(&wiki_node_type(child)) {
+            continue;
+        }
+        let Some(name) = wiki_node_name(child) else {
+            continue;
+        };
+        planets.push(CatalogPlanet {
+            patterns: wiki_zone_patterns(&name),
+            stations: wiki_stations(child),
+            name,
+        });
+    }
+    CatalogSystem {
+        name: system.to_string(),
+        wiki_slug: None,
+        replace: false,
+        patterns: LocationPatterns::default(),
+        planets,
+        stations: wiki_stations(data),
+    }
+}
+
+/// Remplace dans le cache Wiki les systèmes rafraîchis, sans toucher aux autres.
+fn replace_wiki_systems(cache: &mut LocationCatalogFile, fresh: LocationCatalogFile) {
+    for system in fresh.systems {
+        match cache
+            .systems
+            .iter_mut()
+            .find(|s| s.name.eq_ignore_ascii_case(&system.name))
+        {
+            Some(existing) => *existing = system,
+            None => cache.systems.push(system),
+        }
+    }
+    cache.schema_version = LOCATION_CATALOG_SCHEMA_VERSION;
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationCatalogRefreshResult {
+    pub systems_updated: Vec<String>,
+    pub systems_failed: Vec<String>,
+    pub catalog: LocationCatalogFile,
+}
+
+async fn refresh_location_catalog_from_wiki(
+    app: &AppHandle,
+) -> Result<LocationCatalogRefreshResult, String> {
+    let targets: Vec<(String, String)> = {
+        let catalog = location_catalog();
+        catalog
+            .catalog
+            .systems
+            .iter()
+            .filter_map(|s| Some((s.name.clone(), s.wiki_slug.clone()?)))
+            .collect()
+    };
+
+    let mut wiki_catalog = LocationCatalogFile::default();
+    let mut systems_updated = Vec::new();
+    let mut systems_failed = Vec::new();
+    for (system, slug) in targets {
+        match fetch_wiki_location(&slug).await {
+            Some(data) => {
+                wiki_catalog
+                    .systems
+                    .push(system_from_wiki_location(&system, &data));
+                systems_updated.push(system);
+            }
+            None => systems_failed.push(system),
+        }
+    }
+
+    // Systèmes injoignables : on garde leur entrée du cache précédent, les données embarquées
+    // restent actives.
+    if !systems_updated.is_empty() {
+        let path = wiki_catalog_cache_path()
+            .ok_or_else(|| "Impossible d'obtenir le répertoire de cache Wiki".to_string())?;
+        let mut cache = read_catalog_file(&path).unwrap_or_default();
+        replace_wiki_systems(&mut cache, wiki_catalog);
+        let json = serde_json::to_vec_pretty(&cache).map_err(|e| e.to_string())?;
+        fs::write(path, json).map_err(|e| e.to_string())?;
+    }
+
+    let catalog = reload_location_catalog_sync(app)?;
+    Ok(LocationCatalogRefreshResult {
+        systems_updated,
+        systems_failed,
+        catalog,
+    })
+}
+
+#[command]
+pub async fn get_location_catalog(app: AppHandle) -> Result<LocationCatalogFile, String> {
+    tokio::task::spawn_blocking(move || reload_location_catalog_sync(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[command]
+pub async fn refresh_location_catalog(
+    app: AppHandle,
+) -> Result<LocationCatalogRefreshResult, String> {
+    refresh_location_catalog_from_wiki(&app).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundled() -> CompiledLocationCatalog {
+        CompiledLocationCatalog::new(bundled_location_catalog())
+    }
+
+    #[test]
+    fn bundled_catalog_detects_planet_and_system() {
+        let catalog = bundled();
+        let loc = catalog
+            .detect("Loading socpak /pu/system/pyro/pyro2.socpak")
+            .unwrap();
+        assert_eq!(loc.system, "Pyro");
+        assert_eq!(loc.planet.as_deref(), Some("Monox"));
+
+        let loc = catalog.detect("zone [Stanton2b - Daymar]").unwrap();
+        assert_eq!(loc.system, "Stanton");
+        assert_eq!(loc.planet.as_deref(), Some("Daymar"));
+
+        let loc = catalog.detect("Loading OOC_Nyx_Glaciem_Ring").unwrap();
+        assert_eq!(loc.system, "Nyx");
+        assert!(loc.planet.is_none());
+    }
+
+    #[test]
+    fn station_is_preferred_over_parent_body() {
+        let loc = bundled().detect("zone [Lorville - Hurston]").unwrap();
+        assert_eq!(loc.system, "Stanton");
+        assert_eq!(loc.planet.as_deref(), Some("Hurston"));
+        assert_eq!(loc.station.as_deref(), Some("Lorville"));
+    }
+
+    #[test]
+    fn user_overlay_adds_new_system_and_extends_existing() {
+        let mut catalog = bundled_location_catalog();
+        let overlay: LocationCatalogFile = serde_json::from_str(
+            r#"{
+                "systems": [
+                    { "name": "Castra", "patterns": { "socpaks": ["/pu/system/castra/"] } },
+                    { "name": "nyx", "planets": [{ "name": "Delamar", "patterns": { "zones": ["zone [nyx1"] } }] }
+                ]
+            }"#,
+        )
+        .unwrap();
+        merge_location_catalogs(&mut catalog, &overlay);
+        let compiled = CompiledLocationCatalog::new(catalog);
+
+        let loc = compiled.detect("/pu/system/castra/castra1.socpak").unwrap();
+        assert_eq!(loc.system, "Castra");
+        let loc = compiled.detect("zone [Nyx1 - Delamar]").unwrap();
+        assert_eq!(loc.system, "Nyx");
+        assert_eq!(loc.planet.as_deref(), Some("Delamar"));
+    }
+
+    #[test]
+    fn partial_wiki_refresh_keeps_previously_cached_systems() {
+        let system = |name: &str, ooc: &str| CatalogSystem {
+            name: name.to_string(),
+            patterns: LocationPatterns {
+                ooc: vec![ooc.to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut cache = LocationCatalogFile {
+            schema_version: LOCATION_CATALOG_SCHEMA_VERSION,
+            systems: vec![system("Stanton", "OOC_Old"), system("Pyro", "OOC_Pyro")],
+        };
+        let fresh = LocationCatalogFile {
+            schema_version: LOCATION_CATALOG_SCHEMA_VERSION,
+            systems: vec![system("stanton", "OOC_New"), system("Nyx", "OOC_Nyx")],
+        };
+
+        replace_wiki_systems(&mut cache, fresh);
+        let names: Vec<_> = cache.systems.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["stanton", "Pyro", "Nyx"]);
+        assert_eq!(cache.systems[0].patterns.ooc, ["OOC_New"]);
+    }
+
+    #[test]
+    fn wiki_location_children_become_planets_and_stations() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "name": "Stanton",
+                "children": [
+                    { "name": "Hurston", "type": "Planet", "children": [
+                        { "name": "Everus Harbor", "type": "Space Station" }
+                    ] },
+                    { "name": "Stanton Jump Point", "type": "Jump Point" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let system = system_from_wiki_location("Stanton", &data);
+        assert_eq!(system.planets.len(), 1);
+        assert_eq!(system.planets[0].stations[0].name, "Everus Harbor");
+        assert!(system.planets[0].stations[0]
+            .patterns
+            .zones
+            .contains(&"zone [everusharbor".to_string()));
+        assert!(system.stations.is_empty());
+    }
+}
@@ -4,6 +4,7 @@ pub mod commands;
 pub mod engine;
 pub mod extractors;
 pub mod io;
+pub mod locations;
 pub mod parse;
 pub mod patterns;
 pub mod progress;
@@ -14,4 +15,5 @@ pub mod snapshot;
 pub use commands::{
     get_cached_game_stats, get_game_stats, get_game_stats_scan_status, sync_game_stats,
 };
+pub use locations::{get_location_catalog, refresh_location_catalog};
 pub use scan_state::GameStatsScanState;
@@ -1,44 +1,9 @@
+use crate::scripts::game_log::locations::detect_location;
 use regex::Regex;
 
-/// Motifs de détection des systèmes solaires (extensible).
-pub const STAR_SYSTEMS: &[(&str, &[&str])] = &[
-    (
-        "Pyro",
-        &[
-            "/pu/system/pyro/",
-            "zone [pyro",
-            "location[pyro",
-            "ooc_pyro",
-            "pyro1",
-            "pyro2",
-            "pyro3",
-            "pyro4",
-            "pyro5",
-        ],
-    ),
-    (
-        "Stanton",
-        &[
-            "/pu/system/stanton/",
-            "zone [stanton",
-            "location[stanton",
-            "ooc_stanton",
-        ],
-    ),
-    (
-        "Nyx",
-        &["/pu/system/nyx/", "zone [nyx", "location[nyx", "ooc_nyx"],
-    ),
-];
-
-pub fn detect_star_system(line: &str) -> Option<&'static str> {
-    let lower = line.to_ascii_lowercase();
-    for (name, patterns) in STAR_SYSTEMS {
-        if patterns.iter().any(|p| lower.contains(p)) {
-            return Some(*name);
-        }
-    }
-    None
+/// Système solaire reconnu sur la ligne (catalogue `locations`, extensible sans release).
+pub fn detect_star_system(line: &str) -> Option<String> {
+    detect_location(line).map(|loc| loc.system)
 }
 
 /// Retire le suffixe numérique d'instance (`ORIG_m80_373414673624` → `ORIG_m80`).
@@ -347,7 +312,7 @@ mod tests {
     #[test]
     fn detect_pyro_from_socpak() {
         let line = "Loading socpak /pu/system/pyro/pyro2.socpak";
-        assert_eq!(detect_star_system(line), Some("Pyro"));
+        assert_eq!(detect_star_system(line).as_deref(), Some("Pyro"));
     }
 
     #[test]
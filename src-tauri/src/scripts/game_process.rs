@@ -0,0 +1,446 @@
+//! Détection du client Star Citizen via `sysinfo` : démarrage du gamelog watcher au lancement
+//! du jeu, sync de l'archive + scan de stats incrémental à sa fermeture, et bornes exactes
+//! de chaque session de jeu.
+//!
+//! Événements Tauri : `game-started` / `game-exited` (payload [`GameProcessEvent`]).
+
+use crate::scripts::game_log::commands::load_game_stats_sync;
+use crate::scripts::game_log::scan_state::GameStatsScanState;
+use crate::scripts::gamelog_archive::sync_logbackups_archive_sync;
+use crate::scripts::gamelog_channels::detected_game_channels;
+use crate::scripts::gamelog_watcher::{
+    ensure_config_dir, start_gamelog_watcher_internal, GamelogWatcherState,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+use tauri::{command, AppHandle, Emitter, Manager, State};
+
+pub const GAME_STARTED_EVENT: &str = "game-started";
+pub const GAME_EXITED_EVENT: &str = "game-exited";
+const PROCESS_POLL_INTERVAL: Duration = Duration::from_secs(3);
+/// Historique conservé dans `game_sessions.json`.
+const MAX_RECORDED_SESSIONS: usize = 500;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameProcessConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Noms d'exécutable surveillés (casse et `.exe` ignorés).
+    #[serde(default = "default_process_names")]
+    pub process_names: Vec<String>,
+    #[serde(default = "default_true")]
+    pub start_watcher_on_launch: bool,
+    /// Sync de l'archive des logbackups et scan de stats à la fermeture du jeu.
+    #[serde(default = "default_true")]
+    pub sync_on_exit: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_process_names() -> Vec<String> {
+    vec!["StarCitizen.exe".to_string()]
+}
+
+impl Default for GameProcessConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            process_names: default_process_names(),
+            start_watcher_on_launch: true,
+            sync_on_exit: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunningGame {
+    pub pid: u32,
+    pub process_name: String,
+    pub exe_path: Option<String>,
+    /// Canal déduit du chemin de l'exécutable (LIVE, PTU…).
+    pub channel: Option<String>,
+    /// Démarrage du processus (epoch, secondes).
+    pub started_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameSessionRecord {
+    #[serde(flatten)]
+    pub game: RunningGame,
+    /// Fermeture observée (à l'intervalle de polling près).
+    pub ended_at: Option<u64>,
+    pub duration_sec: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameProcessEvent {
+    #[serde(flatten)]
+    pub game: RunningGame,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_sec: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GameSessionsFile {
+    #[serde(default)]
+    sessions: Vec<GameSessionRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameProcessStatus {
+    pub monitoring: bool,
+    pub config: GameProcessConfig,
+    pub running: Vec<RunningGame>,
+    /// Sessions récentes, la plus récente d'abord.
+    pub recent_sessions: Vec<GameSessionRecord>,
+}
+
+#[derive(Clone, Default)]
+pub struct GameProcessState {
+    config: Arc<Mutex<GameProcessConfig>>,
+    running: Arc<Mutex<HashMap<u32, RunningGame>>>,
+    monitoring: Arc<AtomicBool>,
+}
+
+fn config_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(ensure_config_dir(app.path())?.join("game_process.json"))
+}
+
+fn sessions_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(ensure_config_dir(app.path())?.join("game_sessions.json"))
+}
+
+pub fn load_game_process_config_sync(app: &AppHandle) -> Result<GameProcessConfig, String> {
+    let path = config_path(app)?;
+    if !path.exists() {
+        return Ok(GameProcessConfig::default());
+    }
+    let json = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+fn save_game_process_config_sync(
+    app: &AppHandle,
+    config: &GameProcessConfig,
+) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    fs::write(config_path(app)?, json).map_err(|e| e.to_string())
+}
+
+fn load_sessions(app: &AppHandle) -> GameSessionsFile {
+    sessions_path(app)
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_sessions(app: &AppHandle, file: &GameSessionsFile) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(file).map_err(|e| e.to_string())?;
+    fs::write(sessions_path(app)?, json).map_err(|e| e.to_string())
+}
+
+/// Ouvre une session, ou la referme si `ended_at` est fourni (même pid + démarrage).
+fn record_session(app: &AppHandle, game: &RunningGame, ended_at: Option<u64>) {
+    let mut file = load_sessions(app);
+    let existing = file
+        .sessions
+        .iter_mut()
+        .find(|s| s.game.pid == game.pid && s.game.started_at == game.started_at);
+    match (existing, ended_at) {
+        (Some(session), Some(end)) => {
+            session.ended_at = Some(end);
+            session.duration_sec = Some(end.saturating_sub(session.game.started_at));
+        }
+        (Some(_), None) => return,
+        (None, end) => file.sessions.push(GameSessionRecord {
+            game: game.clone(),
+            ended_at: end,
+            duration_sec: end.map(|e| e.saturating_sub(game.started_at)),
+        }),
+    }
+    if file.sessions.len() > MAX_RECORDED_SESSIONS {
+        let excess = file.sessions.len() - MAX_RECORDED_SESSIONS;
+        file.sessions.drain(..excess);
+    }
+    if let Err(e) = save_sessions(app, &file) {
+        eprintln!("[game_process] failed to save sessions: {e}");
+    }
+}
+
+fn normalize_process_name(name: &str) -> String {
+    let lower = name.trim().to_ascii_lowercase();
+    lower.strip_suffix(".exe").unwrap_or(&lower).to_string()
+}
+
+fn matches_process_name(name: &str, configured: &[String]) -> bool {
+    let name = normalize_process_name(name);
+    configured
+        .iter()
+        .any(|c| !c.trim().is_empty() && normalize_process_name(c) == name)
+}
+
+/// Canal dont le dossier d'installation contient l'exécutable (`…/StarCitizen/PTU/Bin64/…`).
+fn channel_for_exe(exe: &Path, channels: &[(String, PathBuf)]) -> Option<String> {
+    let exe = exe
+        .to_string_lossy()
+        .replace('\\', "/")
+        .to_ascii_lowercase();
+    channels
+        .iter()
+        .find(|(_, install)| {
+            let install = install
+                .to_string_lossy()
+                .replace('\\', "/")
+                .trim_end_matches('/')
+                .to_ascii_lowercase();
+            exe.starts_with(&format!("{install}/"))
+        })
+        .map(|(channel, _)| channel.clone())
+}
+
+fn scan_game_processes(sys: &mut System, config: &GameProcessConfig) -> Vec<RunningGame> {
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet),
+    );
+    let mut channels: Option<Vec<(String, PathBuf)>> = None;
+    sys.processes()
+        .iter()
+        .filter(|(_, p)| matches_process_name(&p.name().to_string_lossy(), &config.process_names))
+        .map(|(pid, p)| {
+            let channel = p.exe().and_then(|exe| {
+                channel_for_exe(exe, channels.get_or_insert_with(detected_game_channels))
+            });
+            RunningGame {
+                pid: pid.as_u32(),
+                process_name: p.name().to_string_lossy().into_owned(),
+                exe_path: p.exe().map(|e| e.display().to_string()),
+                channel,
+                started_at: p.start_time(),
+            }
+        })
+        .collect()
+}
+
+/// Processus apparus et disparus depuis le dernier passage (un pid réutilisé compte comme
+/// une nouvelle session).
+fn diff_running(
+    previous: &HashMap<u32, RunningGame>,
+    current: &[RunningGame],
+) -> (Vec<RunningGame>, Vec<RunningGame>) {
+    let started = current
+        .iter()
+        .filter(|g| previous.get(&g.pid).map(|p| p.started_at) != Some(g.started_at))
+        .cloned()
+        .collect();
+    let exited = previous
+        .values()
+        .filter(|p| {
+            !current
+                .iter()
+                .any(|g| g.pid == p.pid && g.started_at == p.started_at)
+        })
+        .cloned()
+        .collect();
+    (started, exited)
+}
+
+fn on_game_started(app: &AppHandle, game: &RunningGame, config: &GameProcessConfig) {
+    record_session(app, game, None);
+    let _ = app.emit(
+        GAME_STARTED_EVENT,
+        GameProcessEvent {
+            game: game.clone(),
+            ended_at: None,
+            duration_sec: None,
+        },
+    );
+    if !config.start_watcher_on_launch {
+        return;
+    }
+    let watcher = app.state::<GamelogWatcherState>().inner().clone();
+    let running = watcher.is_running.lock().map(|r| *r).unwrap_or(false);
+    if !running {
+        if let Err(e) = start_gamelog_watcher_internal(&watcher, app.clone()) {
+            eprintln!("[game_process] failed to start gamelog watcher: {e}");
+        }
+    }
+}
+
+fn on_game_exited(app: &AppHandle, game: &RunningGame, config: &GameProcessConfig) {
+    let ended_at = Utc::now().timestamp().max(0) as u64;
+    record_session(app, game, Some(ended_at));
+    let _ = app.emit(
+        GAME_EXITED_EVENT,
+        GameProcessEvent {
+            game: game.clone(),
+            ended_at: Some(ended_at),
+            duration_sec: Some(ended_at.saturating_sub(game.started_at)),
+        },
+    );
+    if !config.sync_on_exit {
+        return;
+    }
+    // Le Game.log de la session reste en place jusqu'au prochain lancement (le jeu le déplace
+    // alors dans logbackups) : les stats le relisent complet, l'archive reprend les logbackups
+    // en attente. Hors du thread de détection, qui doit continuer à sonder les processus.
+    let app = app.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        if let Err(e) = sync_logbackups_archive_sync(&app) {
+            eprintln!("[game_process] archive sync after exit: {e}");
+        }
+        let scan_state = app.state::<GameStatsScanState>().inner().clone();
+        if let Err(e) = load_game_stats_sync(&app, &scan_state) {
+            eprintln!("[game_process] stats scan after exit: {e}");
+        }
+    });
+}
+
+fn run_process_monitor(app: AppHandle, state: GameProcessState) {
+    let mut sys = System::new();
+    loop {
+        let config = state.config.lock().map(|c| c.clone()).unwrap_or_default();
+        let current = if config.enabled {
+            scan_game_processes(&mut sys, &config)
+        } else {
+            Vec::new()
+        };
+        let previous = state.running.lock().map(|r| r.clone()).unwrap_or_default();
+        let (started, exited) = diff_running(&previous, &current);
+        if let Ok(mut running) = state.running.lock() {
+            *running = current.into_iter().map(|g| (g.pid, g)).collect();
+        }
+        // Désactivation : pas de sync de sortie pour des processus toujours vivants.
+        if config.enabled {
+            for game in &exited {
+                on_game_exited(&app, game, &config);
+            }
+        }
+        for game in &started {
+            on_game_started(&app, game, &config);
+        }
+        thread::sleep(PROCESS_POLL_INTERVAL);
+    }
+}
+
+/// Lance la surveillance des processus du jeu (une seule fois par exécution de l'app).
+pub fn start_game_process_monitor(app: &AppHandle, state: &GameProcessState) -> Result<(), String> {
+    if state.monitoring.swap(true, Ordering::SeqCst) {
+        return Ok(());
+    }
+    let config = load_game_process_config_sync(app).unwrap_or_default();
+    if let Ok(mut guard) = state.config.lock() {
+        *guard = config;
+    }
+    let app = app.clone();
+    let state = state.clone();
+    thread::Builder::new()
+        .name("game-process-monitor".into())
+        .spawn(move || run_process_monitor(app, state))
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+#[command]
+pub fn get_game_process_status(
+    app: AppHandle,
+    state: State<'_, GameProcessState>,
+) -> Result<GameProcessStatus, String> {
+    let mut running: Vec<RunningGame> = state
+        .running
+        .lock()
+        .map(|r| r.values().cloned().collect())
+        .unwrap_or_default();
+    running.sort_by_key(|g| g.started_at);
+    let mut recent_sessions = load_sessions(&app).sessions;
+    recent_sessions.reverse();
+    recent_sessions.truncate(50);
+    Ok(GameProcessStatus {
+        monitoring: state.monitoring.load(Ordering::Relaxed),
+        config: state.config.lock().map(|c| c.clone()).unwrap_or_default(),
+        running,
+        recent_sessions,
+    })
+}
+
+#[command]
+pub fn save_game_process_config(
+    app: AppHandle,
+    state: State<'_, GameProcessState>,
+    config: GameProcessConfig,
+) -> Result<(), String> {
+    save_game_process_config_sync(&app, &config)?;
+    if let Ok(mut guard) = state.config.lock() {
+        *guard = config;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(pid: u32, started_at: u64) -> RunningGame {
+        RunningGame {
+            pid,
+            process_name: "StarCitizen.exe".to_string(),
+            exe_path: None,
+            channel: None,
+            started_at,
+        }
+    }
+
+    #[test]
+    fn process_names_match_case_and_exe_insensitively() {
+        let configured = default_process_names();
+        assert!(matches_process_name("StarCitizen.exe", &configured));
+        assert!(matches_process_name("starcitizen", &configured));
+        assert!(!matches_process_name("RSI Launcher.exe", &configured));
+
+        let channels = vec![
+            (
+                "LIVE".to_string(),
+                PathBuf::from(r"C:\Games\StarCitizen\LIVE"),
+            ),
+            (
+                "PTU".to_string(),
+                PathBuf::from(r"C:\Games\StarCitizen\PTU"),
+            ),
+        ];
+        let exe = Path::new(r"C:\Games\StarCitizen\PTU\Bin64\StarCitizen.exe");
+        assert_eq!(channel_for_exe(exe, &channels).as_deref(), Some("PTU"));
+    }
+
+    #[test]
+    fn diff_running_detects_starts_exits_and_reused_pids() {
+        let previous: HashMap<u32, RunningGame> = [(10, game(10, 100)), (20, game(20, 200))]
+            .into_iter()
+            .collect();
+        let current = vec![game(20, 200), game(10, 900), game(30, 300)];
+        let (started, exited) = diff_running(&previous, &current);
+        let mut started_pids: Vec<u32> = started.iter().map(|g| g.pid).collect();
+        started_pids.sort_unstable();
+        assert_eq!(started_pids, vec![10, 30]);
+        assert_eq!(exited, vec![game(10, 100)]);
+    }
+}
@@ -1,25 +1,76 @@
-//! Archive locale des `logbackups/` Star Citizen (LIVE + HOTFIX).
+//! Archive locale des `logbackups/` Star Citizen (canaux suivis, cf. `gamelog_channels`).
 //! Le `Game.log` de session courante n'est pas copié — intégration directe via le watcher.
+//! Les rapports de `Crashes/` suivent le même circuit (copie vérifiée puis suppression) ; les
+//! logs tournés du launcher RSI sont copiés sans être supprimés. Chaque entrée du manifeste
+//! porte son type.
+//! Les copies sont stockées compressées (gzip) ; les archives brutes antérieures sont migrées
+//! en arrière-plan.
+//! La rétention (taille, âge, sessions par canal) supprime les copies les plus anciennes non
+//! épinglées, après avoir intégré leur contenu aux statistiques et au magasin de blueprints.
 
-use crate::scripts::gamepath::get_star_citizen_versions_sync;
+use crate::scripts::game_log::cache::rename_file_states;
+use crate::scripts::game_log::catalog::describe_log_file;
+use crate::scripts::game_log::commands::scan_busy_error;
+use crate::scripts::game_log::engine::RetiredLogFolder;
+use crate::scripts::game_log::io::{log_fingerprint, read_log_with_fingerprint, LogFingerprint};
+use crate::scripts::game_log::scan_state::{GameStatsScanKind, GameStatsScanState};
+use crate::scripts::gamelog_channels::watched_game_channels;
+use crate::scripts::gamelog_watcher::{append_blueprints, scan_log_content_for_blueprints};
+use crate::scripts::gamepath::launcher_logs_dir;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::io::Read;
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
 use tauri::path::PathResolver;
-use tauri::{command, AppHandle, Manager, Runtime};
+use tauri::{command, AppHandle, Manager, Runtime, State};
 
-const MANIFEST_SCHEMA_VERSION: u32 = 1;
-const ARCHIVE_CHANNELS: &[&str] = &["LIVE", "HOTFIX"];
+pub(crate) const MANIFEST_SCHEMA_VERSION: u32 = 2;
+const LAUNCHER_CHANNEL: &str = "LAUNCHER";
+/// Log courant du launcher : seules ses rotations sont archivées.
+const LAUNCHER_ACTIVE_LOG: &str = "log.log";
+
+/// Attente entre deux tentatives quand une tâche de fond trouve un scan des statistiques en cours.
+const SCAN_BUSY_RETRY: Duration = Duration::from_secs(1);
+
+/// Sérialise les écritures du manifeste (sync et migration tournent sur des threads distincts).
+pub(crate) static ARCHIVE_LOCK: Mutex<()> = Mutex::new(());
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GamelogArchiveConfig {
     /// Supprimer les fichiers source dans logbackups/ du jeu après vérification réussie.
+    /// Ne s'applique pas aux logs du launcher (cf. [`keeps_source`]).
     #[serde(default = "default_delete_after_verified")]
     pub delete_after_verified: bool,
+    /// Taille maximale occupée par les copies archivées (octets stockés).
+    #[serde(default)]
+    pub max_total_bytes: Option<u64>,
+    /// Âge maximal d'un log archivé (date du log d'origine).
+    #[serde(default)]
+    pub max_age_days: Option<u32>,
+    /// Nombre de sessions les plus récentes conservées par canal.
+    #[serde(default)]
+    pub keep_last_sessions_per_channel: Option<u32>,
+    /// Dossier choisi pour l'archive (défaut : données de l'application). Modifié uniquement
+    /// par [`relocate_archive_sync`], qui déplace les fichiers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_directory: Option<String>,
+}
+
+impl GamelogArchiveConfig {
+    fn has_retention(&self) -> bool {
+        self.max_total_bytes.is_some()
+            || self.max_age_days.is_some()
+            || self.keep_last_sessions_per_channel.is_some()
+    }
 }
 
 fn default_delete_after_verified() -> bool {
@@ -30,10 +81,35 @@ impl Default for GamelogArchiveConfig {
     fn default() -> Self {
         Self {
             delete_after_verified: default_delete_after_verified(),
+            max_total_bytes: None,
+            max_age_days: None,
+            keep_last_sessions_per_channel: None,
+            archive_directory: None,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArchiveCompression {
+    /// Copie brute (archives antérieures à la compression, en attente de migration).
+    #[default]
+    None,
+    Gzip,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ArchiveEntryKind {
+    /// Log de session `logbackups/` (seul type intégré aux statistiques).
+    #[default]
+    GameLog,
+    /// Fichier d'un rapport de crash (`Crashes/`).
+    CrashReport,
+    /// Log tourné du launcher RSI.
+    LauncherLog,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ArchiveManifestEntry {
@@ -42,14 +118,51 @@ pub struct ArchiveManifestEntry {
     pub source_path: String,
     pub original_name: String,
     pub archive_name: String,
+    /// Empreinte et taille du log d'origine (non compressé).
     pub sha256: String,
     pub size: u64,
+    #[serde(default)]
+    pub compression: ArchiveCompression,
+    /// Empreinte et taille du fichier stocké dans `files/`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compressed_sha256: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compressed_size: Option<u64>,
     pub source_mtime_ms: u64,
     pub archived_at_ms: u64,
     pub verified: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verify_error: Option<String>,
     pub deleted_from_game: bool,
+    /// Session épinglée : jamais supprimée par la rétention.
+    #[serde(default)]
+    pub pinned: bool,
+    /// Copie supprimée par la rétention (contenu déjà intégré aux statistiques) ; l'entrée est
+    /// conservée pour que la source ne soit ni réarchivée ni rescannée.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pruned_at_ms: Option<u64>,
+    #[serde(default)]
+    pub kind: ArchiveEntryKind,
+    /// Dossier de crash d'origine (regroupe les fichiers d'un même rapport).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crash_id: Option<String>,
+    /// Empreinte tête/queue du log de jeu (identité partagée avec `logbackups/` et `Game.log`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<LogFingerprint>,
+}
+
+impl ArchiveManifestEntry {
+    pub(crate) fn is_active(&self) -> bool {
+        self.verified && self.pruned_at_ms.is_none()
+    }
+
+    fn is_game_log(&self) -> bool {
+        self.kind == ArchiveEntryKind::GameLog
+    }
+
+    fn stored_bytes(&self) -> u64 {
+        self.compressed_size.unwrap_or(self.size)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,10 +181,12 @@ impl Default for ArchiveManifest {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GamelogArchiveSyncResult {
     pub archived: u32,
+    pub crash_files_archived: u32,
+    pub launcher_logs_archived: u32,
     pub deleted_from_game: u32,
     pub pending_verification: u32,
     pub skipped_already_archived: u32,
@@ -83,14 +198,80 @@ pub struct GamelogArchiveSyncResult {
 #[serde(rename_all = "camelCase")]
 pub struct GamelogArchiveStatus {
     pub total_archived_files: u32,
+    /// Taille d'origine des logs archivés.
     pub total_bytes_archived: u64,
+    /// Taille réellement occupée sur disque.
+    pub total_bytes_stored: u64,
+    pub bytes_saved: u64,
+    pub compressed_count: u32,
+    /// Copies brutes restant à migrer.
+    pub pending_compression_count: u32,
     pub verified_count: u32,
     pub pending_count: u32,
     pub deleted_from_game_count: u32,
+    pub pinned_count: u32,
+    pub pruned_count: u32,
+    pub crash_report_files: u32,
+    pub launcher_log_count: u32,
     pub channels: Vec<String>,
     pub archive_directory: String,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveCompressionMigrationResult {
+    pub migrated: u32,
+    pub failed: u32,
+    pub bytes_saved: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RetentionReason {
+    MaxAge,
+    SessionCount,
+    TotalSize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionCandidate {
+    pub archive_name: String,
+    pub channel: String,
+    pub original_name: String,
+    pub source_mtime_ms: u64,
+    pub size: u64,
+    pub stored_bytes: u64,
+    pub kind: ArchiveEntryKind,
+    pub reason: RetentionReason,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionPreview {
+    pub candidates: Vec<RetentionCandidate>,
+    pub bytes_to_free: u64,
+    pub bytes_remaining: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionResult {
+    pub pruned: u32,
+    pub failed: u32,
+    pub bytes_freed: u64,
+    /// Blueprints absents du magasin, retrouvés dans les logs supprimés.
+    pub blueprints_added: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveRelocationResult {
+    pub archive_directory: String,
+    pub files_moved: u32,
+    pub bytes_moved: u64,
+}
+
 #[derive(Debug, Clone)]
 pub struct ArchivedLogFile {
     pub path: PathBuf,
@@ -99,6 +280,8 @@ pub struct ArchivedLogFile {
     pub mtime_ms: u64,
     #[allow(dead_code)]
     pub size: u64,
+    /// Identité connue du manifeste (absente pour un fichier pas encore archivé).
+    pub fingerprint: Option<LogFingerprint>,
 }
 
 #[derive(Debug, Clone)]
@@ -121,19 +304,38 @@ fn ensure_config_dir(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, S
     Ok(dir)
 }
 
-fn ensure_archive_dir(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
-    let dir = resolver
+fn default_archive_root(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+    Ok(resolver
         .app_data_dir()
         .map_err(|_| "Impossible d'obtenir le répertoire de données".to_string())?
-        .join("gamelog_archive")
-        .join("files");
+        .join("gamelog_archive"))
+}
+
+fn configured_archive_root(
+    resolver: &PathResolver<impl Runtime>,
+    config: &GamelogArchiveConfig,
+) -> Result<PathBuf, String> {
+    match config
+        .archive_directory
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+    {
+        Some(dir) => Ok(PathBuf::from(dir)),
+        None => default_archive_root(resolver),
+    }
+}
+
+pub(crate) fn ensure_archive_dir(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+    let root = configured_archive_root(resolver, &load_archive_config(resolver)?)?;
+    let dir = archive_files_dir(&root);
     if !dir.exists() {
         fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
     }
-    Ok(dir.parent().unwrap().to_path_buf())
+    Ok(root)
 }
 
-fn archive_files_dir(root: &Path) -> PathBuf {
+pub(crate) fn archive_files_dir(root: &Path) -> PathBuf {
     root.join("files")
 }
 
@@ -146,7 +348,13 @@ fn manifest_path(root: &Path) -> PathBuf {
 }
 
 pub fn load_archive_config_sync(app: &AppHandle) -> Result<GamelogArchiveConfig, String> {
-    let path = config_path(app.path())?;
+    load_archive_config(app.path())
+}
+
+fn load_archive_config(
+    resolver: &PathResolver<impl Runtime>,
+) -> Result<GamelogArchiveConfig, String> {
+    let path = config_path(resolver)?;
     if !path.exists() {
         return Ok(GamelogArchiveConfig::default());
     }
@@ -160,10 +368,13 @@ pub fn save_archive_config_sync(
 ) -> Result<(), String> {
     let path = config_path(app.path())?;
     let json = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
-    fs::write(path, json).map_err(|e| e.to_string())
+    // Écriture puis renommage : le dossier d'archive ne doit jamais pointer sur un fichier tronqué.
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, json).map_err(|e| e.to_string())?;
+    fs::rename(&tmp, &path).map_err(|e| e.to_string())
 }
 
-fn load_manifest(root: &Path) -> Result<ArchiveManifest, String> {
+pub(crate) fn load_manifest(root: &Path) -> Result<ArchiveManifest, String> {
     let path = manifest_path(root);
     if !path.exists() {
         return Ok(ArchiveManifest::default());
@@ -172,13 +383,13 @@ fn load_manifest(root: &Path) -> Result<ArchiveManifest, String> {
     serde_json::from_str(&json).map_err(|e| e.to_string())
 }
 
-fn save_manifest(root: &Path, manifest: &ArchiveManifest) -> Result<(), String> {
+pub(crate) fn save_manifest(root: &Path, manifest: &ArchiveManifest) -> Result<(), String> {
     let path = manifest_path(root);
     let json = serde_json::to_string_pretty(manifest).map_err(|e| e.to_string())?;
     fs::write(path, json).map_err(|e| e.to_string())
 }
 
-fn file_mtime_ms(path: &Path) -> u64 {
+pub(crate) fn file_mtime_ms(path: &Path) -> u64 {
     fs::metadata(path)
         .ok()
         .and_then(|m| m.modified().ok())
@@ -187,21 +398,71 @@ fn file_mtime_ms(path: &Path) -> u64 {
         .unwrap_or(0)
 }
 
-fn sha256_file(path: &Path) -> Result<String, String> {
-    let mut file = fs::File::open(path).map_err(|e| e.to_string())?;
+/// Empreinte sha256 et nombre d'octets lus.
+pub(crate) fn sha256_reader(mut reader: impl Read) -> Result<(String, u64), String> {
     let mut hasher = Sha256::new();
     let mut buffer = [0u8; 65536];
+    let mut total = 0u64;
     loop {
-        let n = file.read(&mut buffer).map_err(|e| e.to_string())?;
+        let n = reader.read(&mut buffer).map_err(|e| e.to_string())?;
         if n == 0 {
             break;
         }
         hasher.update(&buffer[..n]);
+        total += n as u64;
     }
-    Ok(format!("{:x}", hasher.finalize()))
+    Ok((format!("{:x}", hasher.finalize()), total))
+}
+
+pub(crate) fn sha256_file(path: &Path) -> Result<String, String> {
+    let file = fs::File::open(path).map_err(|e| e.to_string())?;
+    Ok(sha256_reader(file)?.0)
+}
+
+pub(crate) struct CompressedCopy {
+    pub(crate) compressed_sha256: String,
+    pub(crate) compressed_size: u64,
+}
+
+/// Écrit `source` compressé en gzip dans `dest`, puis relit `dest` décompressé : la copie n'est
+/// acceptée que si le contenu restitué correspond à `expected_sha256` / `expected_size`.
+pub(crate) fn write_verified_compressed_copy(
+    source: &Path,
+    dest: &Path,
+    expected_sha256: &str,
+    expected_size: u64,
+) -> Result<CompressedCopy, String> {
+    let mut input = fs::File::open(source).map_err(|e| e.to_string())?;
+    let output = fs::File::create(dest).map_err(|e| e.to_string())?;
+    let mut encoder = GzEncoder::new(io::BufWriter::new(output), Compression::default());
+    io::copy(&mut input, &mut encoder).map_err(|e| e.to_string())?;
+    encoder
+        .finish()
+        .and_then(|mut w| w.flush())
+        .map_err(|e| e.to_string())?;
+
+    // Décodage explicite : les fichiers de crash (`.dmp.gz`…) ne sont pas des logs.
+    let reader = GzDecoder::new(io::BufReader::new(
+        fs::File::open(dest).map_err(|e| e.to_string())?,
+    ));
+    let (content_sha256, content_size) = sha256_reader(reader)?;
+    if content_sha256 != expected_sha256 || content_size != expected_size {
+        return Err(format!(
+            "hash/size mismatch (src={expected_size}/{expected_sha256}, dest={content_size}/{content_sha256})"
+        ));
+    }
+    Ok(CompressedCopy {
+        compressed_sha256: sha256_file(dest)?,
+        compressed_size: fs::metadata(dest).map_err(|e| e.to_string())?.len(),
+    })
 }
 
 fn sanitize_archive_name(channel: &str, original: &str) -> String {
+    archive_name_for(channel, "logbackups", original)
+}
+
+/// Nom de copie dans `files/` : `<canal>__<dossier source>__<nom assaini>.gz`.
+fn archive_name_for(channel: &str, folder: &str, original: &str) -> String {
     let base = original
         .chars()
         .map(|c| {
@@ -212,7 +473,7 @@ fn sanitize_archive_name(channel: &str, original: &str) -> String {
             }
         })
         .collect::<String>();
-    format!("{channel}__logbackups__{base}")
+    format!("{channel}__{folder}__{base}.gz")
 }
 
 fn is_log_file(path: &Path) -> bool {
@@ -224,30 +485,10 @@ fn is_log_file(path: &Path) -> bool {
             .unwrap_or(false)
 }
 
-/// Canaux LIVE et HOTFIX détectés via le launcher RSI.
-pub fn detected_archive_channels() -> Vec<(String, PathBuf)> {
-    let versions = get_star_citizen_versions_sync();
+/// Chemins `Game.log` de session courante des canaux suivis (intégration directe).
+pub fn list_live_session_game_logs(app: &AppHandle) -> Vec<LiveGameLogFile> {
     let mut out = Vec::new();
-    for channel_key in ARCHIVE_CHANNELS {
-        if let Some(info) = versions.versions.get(*channel_key) {
-            out.push((channel_key.to_string(), PathBuf::from(&info.path)));
-            continue;
-        }
-        // Recherche insensible à la casse
-        for (key, info) in &versions.versions {
-            if key.eq_ignore_ascii_case(channel_key) {
-                out.push((channel_key.to_string(), PathBuf::from(&info.path)));
-                break;
-            }
-        }
-    }
-    out
-}
-
-/// Chemins `Game.log` de session courante pour LIVE et HOTFIX (intégration directe).
-pub fn list_live_session_game_logs() -> Vec<LiveGameLogFile> {
-    let mut out = Vec::new();
-    for (channel, install) in detected_archive_channels() {
+    for (channel, install) in watched_game_channels(app) {
         let path = install.join("Game.log");
         if path.is_file() {
             let (mtime_ms, size) = {
@@ -265,24 +506,27 @@ pub fn list_live_session_game_logs() -> Vec<LiveGameLogFile> {
     out
 }
 
-/// Fichiers archivés (pool mergé LIVE + HOTFIX).
+/// Fichiers archivés (pool mergé de tous les canaux).
 pub fn list_archived_log_files(app: &AppHandle) -> Result<Vec<ArchivedLogFile>, String> {
     let root = ensure_archive_dir(app.path())?;
     let manifest = load_manifest(&root)?;
     let files_dir = archive_files_dir(&root);
     let mut out = Vec::new();
+    // Une même session copiée depuis deux canaux (ou réimportée) n'est listée qu'une fois.
+    let mut seen_sha256: HashSet<&str> = HashSet::new();
     for entry in &manifest.entries {
-        if !entry.verified {
+        if !entry.verified || !entry.is_game_log() {
             continue;
         }
         let path = files_dir.join(&entry.archive_name);
-        if path.is_file() {
+        if path.is_file() && (entry.sha256.is_empty() || seen_sha256.insert(&entry.sha256)) {
             out.push(ArchivedLogFile {
                 path,
                 channel: entry.channel.clone(),
                 is_game_build: true,
                 mtime_ms: entry.source_mtime_ms,
                 size: entry.size,
+                fingerprint: entry.fingerprint.clone(),
             });
         }
     }
@@ -290,7 +534,8 @@ pub fn list_archived_log_files(app: &AppHandle) -> Result<Vec<ArchivedLogFile>,
     Ok(out)
 }
 
-/// Chemins source (`logbackups/…`) dont une copie vérifiée existe réellement sur disque.
+/// Chemins source (`logbackups/…`) dont une copie vérifiée existe réellement sur disque, ou
+/// dont la copie a été supprimée par la rétention (contenu déjà comptabilisé).
 pub fn verified_archive_source_paths(app: &AppHandle) -> Result<HashSet<String>, String> {
     let root = ensure_archive_dir(app.path())?;
     let manifest = load_manifest(&root)?;
@@ -298,7 +543,11 @@ pub fn verified_archive_source_paths(app: &AppHandle) -> Result<HashSet<String>,
     Ok(manifest
         .entries
         .iter()
-        .filter(|e| e.verified && files_dir.join(&e.archive_name).is_file())
+        .filter(|e| {
+            e.is_game_log()
+                && (e.pruned_at_ms.is_some()
+                    || (e.verified && files_dir.join(&e.archive_name).is_file()))
+        })
         .map(|e| e.source_path.to_ascii_lowercase())
         .collect())
 }
@@ -310,7 +559,10 @@ pub fn list_recoverable_archive_sources(app: &AppHandle) -> Result<Vec<ArchivedL
     let files_dir = archive_files_dir(&root);
     let mut out = Vec::new();
     for entry in &manifest.entries {
-        if !entry.verified || files_dir.join(&entry.archive_name).is_file() {
+        if !entry.is_active()
+            || !entry.is_game_log()
+            || files_dir.join(&entry.archive_name).is_file()
+        {
             continue;
         }
         let source = PathBuf::from(&entry.source_path);
@@ -325,6 +577,7 @@ pub fn list_recoverable_archive_sources(app: &AppHandle) -> Result<Vec<ArchivedL
             size: fs::metadata(&entry.source_path)
                 .map(|m| m.len())
                 .unwrap_or(entry.size),
+            fingerprint: entry.fingerprint.clone(),
         });
     }
     out.sort_by_key(|f| f.mtime_ms);
@@ -332,9 +585,12 @@ pub fn list_recoverable_archive_sources(app: &AppHandle) -> Result<Vec<ArchivedL
 }
 
 /// Fichiers encore présents dans `logbackups/` du jeu mais pas encore archivés localement.
-pub fn list_unarchived_logbackup_files(archived_sources: &HashSet<String>) -> Vec<ArchivedLogFile> {
+pub fn list_unarchived_logbackup_files(
+    app: &AppHandle,
+    archived_sources: &HashSet<String>,
+) -> Vec<ArchivedLogFile> {
     let mut out = Vec::new();
-    for (channel, install) in detected_archive_channels() {
+    for (channel, install) in watched_game_channels(app) {
         let logbackups = install.join("logbackups");
         if !logbackups.is_dir() {
             continue;
@@ -363,6 +619,7 @@ pub fn list_unarchived_logbackup_files(archived_sources: &HashSet<String>) -> Ve
                 is_game_build: true,
                 mtime_ms,
                 size,
+                fingerprint: None,
             });
         }
     }
@@ -370,6 +627,19 @@ pub fn list_unarchived_logbackup_files(archived_sources: &HashSet<String>) -> Ve
     out
 }
 
+/// Identités des sessions dont la copie a été supprimée par la rétention : leur contenu est
+/// déjà dans les statistiques, une autre copie du même log ne doit pas être recomptée.
+pub fn pruned_archive_identities(app: &AppHandle) -> Result<HashSet<String>, String> {
+    let root = ensure_archive_dir(app.path())?;
+    let manifest = load_manifest(&root)?;
+    Ok(manifest
+        .entries
+        .iter()
+        .filter(|e| e.is_game_log() && e.pruned_at_ms.is_some())
+        .filter_map(|e| e.fingerprint.as_ref().map(|f| f.identity.clone()))
+        .collect())
+}
+
 fn find_manifest_entry<'a>(
     manifest: &'a ArchiveManifest,
     channel: &str,
@@ -382,7 +652,216 @@ fn find_manifest_entry<'a>(
     })
 }
 
+/// Une source à archiver : log de session, fichier de rapport de crash ou log du launcher.
+struct ArchiveSource {
+    path: PathBuf,
+    channel: String,
+    kind: ArchiveEntryKind,
+    archive_name: String,
+    crash_id: Option<String>,
+}
+
+/// Les logs tournés du launcher restent dans son dossier : il en limite lui-même le nombre, et
+/// la détection des sources de jeu et le journal d'événements du launcher ne lisent que là.
+fn keeps_source(kind: ArchiveEntryKind) -> bool {
+    kind == ArchiveEntryKind::LauncherLog
+}
+
+/// Copie compressée, vérification d'empreinte, puis suppression de la source si configuré.
+fn archive_source(
+    manifest: &mut ArchiveManifest,
+    files_dir: &Path,
+    config: &GamelogArchiveConfig,
+    source: ArchiveSource,
+    result: &mut GamelogArchiveSyncResult,
+) {
+    let source_str = source.path.to_string_lossy().into_owned();
+    let original_name = source
+        .path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "unknown.log".to_string());
+    let id = match &source.crash_id {
+        Some(crash_id) => format!("{}__{crash_id}__{original_name}", source.channel),
+        None => format!("{}__{original_name}", source.channel),
+    };
+    let dest = files_dir.join(&source.archive_name);
+    let source_size = fs::metadata(&source.path).map(|m| m.len()).unwrap_or(0);
+    let source_mtime_ms = file_mtime_ms(&source.path);
+
+    let mut entry = ArchiveManifestEntry {
+        id,
+        channel: source.channel,
+        source_path: source_str,
+        original_name,
+        archive_name: source.archive_name,
+        sha256: String::new(),
+        size: source_size,
+        compression: ArchiveCompression::Gzip,
+        compressed_sha256: None,
+        compressed_size: None,
+        source_mtime_ms,
+        archived_at_ms: chrono::Utc::now().timestamp_millis() as u64,
+        verified: false,
+        verify_error: None,
+        deleted_from_game: false,
+        pinned: false,
+        pruned_at_ms: None,
+        kind: source.kind,
+        crash_id: source.crash_id,
+        fingerprint: None,
+    };
+
+    let source_hash = match sha256_file(&source.path) {
+        Ok(h) => h,
+        Err(e) => {
+            entry.verify_error = Some(e);
+            manifest.entries.push(entry);
+            result.pending_verification += 1;
+            return;
+        }
+    };
+    // Le launcher réécrit le même fichier à chaque rotation : seule l'empreinte distingue
+    // un contenu déjà archivé.
+    if source.kind == ArchiveEntryKind::LauncherLog
+        && manifest.entries.iter().any(|e| {
+            e.kind == ArchiveEntryKind::LauncherLog && e.verified && e.sha256 == source_hash
+        })
+    {
+        result.skipped_already_archived += 1;
+        return;
+    }
+    entry.sha256 = source_hash;
+    if entry.is_game_log() {
+        entry.fingerprint = log_fingerprint(&source.path).ok();
+    }
+
+    match write_verified_compressed_copy(&source.path, &dest, &entry.sha256, source_size) {
+        Ok(copy) => {
+            entry.verified = true;
+            entry.compressed_sha256 = Some(copy.compressed_sha256);
+            entry.compressed_size = Some(copy.compressed_size);
+        }
+        Err(e) => {
+            entry.verify_error = Some(e);
+            let _ = fs::remove_file(&dest);
+        }
+    }
+
+    if entry.verified
+        && config.delete_after_verified
+        && !keeps_source(entry.kind)
+        && fs::remove_file(&source.path).is_ok()
+    {
+        entry.deleted_from_game = true;
+        result.deleted_from_game += 1;
+        result.bytes_freed += source_size;
+    }
+
+    if entry.verified {
+        result.archived += 1;
+        match entry.kind {
+            ArchiveEntryKind::GameLog => {}
+            ArchiveEntryKind::CrashReport => result.crash_files_archived += 1,
+            ArchiveEntryKind::LauncherLog => result.launcher_logs_archived += 1,
+        }
+    } else {
+        result.pending_verification += 1;
+    }
+    manifest.entries.push(entry);
+}
+
+fn sorted_dir_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file())
+        .collect();
+    files.sort();
+    files
+}
+
+/// Fichiers de `Crashes/` : un sous-dossier par crash (son nom sert d'identifiant), ou des
+/// fichiers isolés (identifiant : nom sans extension).
+fn list_crash_sources(channel: &str, install: &Path) -> Vec<ArchiveSource> {
+    let crashes = install.join("Crashes");
+    let mut out = Vec::new();
+    let mut dirs: Vec<PathBuf> = fs::read_dir(&crashes)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .collect();
+    dirs.sort();
+    for path in dirs {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let (crash_id, files) = if path.is_dir() {
+            (name, sorted_dir_files(&path))
+        } else {
+            let stem = path
+                .file_stem()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or(name);
+            (stem, vec![path])
+        };
+        for file in files {
+            let original = file
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            out.push(ArchiveSource {
+                archive_name: archive_name_for(
+                    channel,
+                    "crashes",
+                    &format!("{crash_id}__{original}"),
+                ),
+                path: file,
+                channel: channel.to_string(),
+                kind: ArchiveEntryKind::CrashReport,
+                crash_id: Some(crash_id.clone()),
+            });
+        }
+    }
+    out
+}
+
+/// Logs du launcher RSI déjà tournés (le `log.log` actif reste en place).
+fn list_launcher_log_sources() -> Vec<ArchiveSource> {
+    let Some(dir) = launcher_logs_dir() else {
+        return Vec::new();
+    };
+    sorted_dir_files(&dir)
+        .into_iter()
+        .filter(|p| is_log_file(p))
+        .filter(|p| {
+            !p.file_name()
+                .is_some_and(|n| n.eq_ignore_ascii_case(LAUNCHER_ACTIVE_LOG))
+        })
+        .map(|path| {
+            let original = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            // Même nom à chaque rotation : la date du fichier distingue les copies.
+            let stamped = format!("{}__{original}", file_mtime_ms(&path));
+            ArchiveSource {
+                archive_name: archive_name_for(LAUNCHER_CHANNEL, "logs", &stamped),
+                path,
+                channel: LAUNCHER_CHANNEL.to_string(),
+                kind: ArchiveEntryKind::LauncherLog,
+                crash_id: None,
+            }
+        })
+        .collect()
+}
+
 pub fn sync_logbackups_archive_sync(app: &AppHandle) -> Result<GamelogArchiveSyncResult, String> {
+    let _guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
     let config = load_archive_config_sync(app)?;
     let root = ensure_archive_dir(app.path())?;
     let files_dir = archive_files_dir(&root);
@@ -391,130 +870,65 @@ pub fn sync_logbackups_archive_sync(app: &AppHandle) -> Result<GamelogArchiveSyn
     }
 
     let mut manifest = load_manifest(&root)?;
-    let mut result = GamelogArchiveSyncResult {
-        archived: 0,
-        deleted_from_game: 0,
-        pending_verification: 0,
-        skipped_already_archived: 0,
-        bytes_freed: 0,
-        channels_scanned: Vec::new(),
-    };
+    let mut result = GamelogArchiveSyncResult::default();
 
-    let channels = detected_archive_channels();
+    let channels = watched_game_channels(app);
     for (channel, install_path) in channels {
         result.channels_scanned.push(channel.clone());
+        let mut sources: Vec<ArchiveSource> = Vec::new();
+
         let logbackups = install_path.join("logbackups");
-        if !logbackups.is_dir() {
-            continue;
+        if logbackups.is_dir() {
+            let mut logs: Vec<PathBuf> = fs::read_dir(&logbackups)
+                .map_err(|e| e.to_string())?
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| is_log_file(p))
+                .collect();
+            logs.sort();
+            sources.extend(logs.into_iter().map(|path| {
+                let original_name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| "unknown.log".to_string());
+                ArchiveSource {
+                    archive_name: sanitize_archive_name(&channel, &original_name),
+                    path,
+                    channel: channel.clone(),
+                    kind: ArchiveEntryKind::GameLog,
+                    crash_id: None,
+                }
+            }));
         }
-
-        let mut sources: Vec<PathBuf> = fs::read_dir(&logbackups)
-            .map_err(|e| e.to_string())?
-            .filter_map(|e| e.ok())
-            .map(|e| e.path())
-            .filter(|p| is_log_file(p))
-            .collect();
-        sources.sort();
+        sources.extend(list_crash_sources(&channel, &install_path));
 
         for source in sources {
-            let source_str = source.to_string_lossy().into_owned();
+            let source_str = source.path.to_string_lossy().into_owned();
             if find_manifest_entry(&manifest, &channel, &source_str).is_some() {
                 result.skipped_already_archived += 1;
                 continue;
             }
+            archive_source(&mut manifest, &files_dir, &config, source, &mut result);
+        }
 
-            let original_name = source
-                .file_name()
-                .map(|n| n.to_string_lossy().into_owned())
-                .unwrap_or_else(|| "unknown.log".to_string());
-            let archive_name = sanitize_archive_name(&channel, &original_name);
-            let dest = files_dir.join(&archive_name);
-
-            let source_size = fs::metadata(&source).map(|m| m.len()).unwrap_or(0);
-            let source_hash = match sha256_file(&source) {
-                Ok(h) => h,
-                Err(e) => {
-                    manifest.entries.push(ArchiveManifestEntry {
-                        id: format!("{channel}__{original_name}"),
-                        channel: channel.clone(),
-                        source_path: source_str,
-                        original_name: original_name.clone(),
-                        archive_name: archive_name.clone(),
-                        sha256: String::new(),
-                        size: source_size,
-                        source_mtime_ms: file_mtime_ms(&source),
-                        archived_at_ms: chrono::Utc::now().timestamp_millis() as u64,
-                        verified: false,
-                        verify_error: Some(e),
-                        deleted_from_game: false,
-                    });
-                    result.pending_verification += 1;
-                    continue;
-                }
-            };
-
-            if let Err(e) = fs::copy(&source, &dest) {
-                manifest.entries.push(ArchiveManifestEntry {
-                    id: format!("{channel}__{original_name}"),
-                    channel: channel.clone(),
-                    source_path: source_str,
-                    original_name: original_name.clone(),
-                    archive_name: archive_name.clone(),
-                    sha256: source_hash,
-                    size: source_size,
-                    source_mtime_ms: file_mtime_ms(&source),
-                    archived_at_ms: chrono::Utc::now().timestamp_millis() as u64,
-                    verified: false,
-                    verify_error: Some(e.to_string()),
-                    deleted_from_game: false,
-                });
-                result.pending_verification += 1;
-                continue;
-            }
-
-            let dest_size = fs::metadata(&dest).map(|m| m.len()).unwrap_or(0);
-            let dest_hash = sha256_file(&dest)?;
-
-            let verified = source_size == dest_size && source_hash == dest_hash;
-            let verify_error = if verified {
-                None
-            } else {
-                Some(format!(
-                    "hash/size mismatch (src={source_size}/{source_hash}, dest={dest_size}/{dest_hash})"
-                ))
-            };
-
-            let mut deleted_from_game = false;
-            if verified && config.delete_after_verified && fs::remove_file(&source).is_ok() {
-                deleted_from_game = true;
-                result.deleted_from_game += 1;
-                result.bytes_freed += source_size;
-            }
-
-            if verified {
-                result.archived += 1;
-            } else {
-                result.pending_verification += 1;
-                let _ = fs::remove_file(&dest);
+        // Dossiers de crash vidés par la suppression après vérification.
+        if config.delete_after_verified {
+            for dir in fs::read_dir(install_path.join("Crashes"))
+                .into_iter()
+                .flatten()
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.is_dir())
+            {
+                let _ = fs::remove_dir(dir);
             }
-
-            manifest.entries.push(ArchiveManifestEntry {
-                id: format!("{channel}__{original_name}"),
-                channel: channel.clone(),
-                source_path: source_str,
-                original_name,
-                archive_name,
-                sha256: source_hash,
-                size: source_size,
-                source_mtime_ms: file_mtime_ms(&source),
-                archived_at_ms: chrono::Utc::now().timestamp_millis() as u64,
-                verified,
-                verify_error,
-                deleted_from_game,
-            });
         }
     }
 
+    for source in list_launcher_log_sources() {
+        archive_source(&mut manifest, &files_dir, &config, source, &mut result);
+    }
+
     manifest.schema_version = MANIFEST_SCHEMA_VERSION;
     save_manifest(&root, &manifest)?;
     Ok(result)
@@ -533,26 +947,552 @@ pub fn get_archive_status_sync(app: &AppHandle) -> Result<GamelogArchiveStatus,
     let mut channels = channels;
     channels.sort();
 
+    let verified = || manifest.entries.iter().filter(|e| e.is_active());
+    let total_bytes_archived: u64 = verified().map(|e| e.size).sum();
+    let total_bytes_stored: u64 = verified().map(ArchiveManifestEntry::stored_bytes).sum();
+
     Ok(GamelogArchiveStatus {
-        total_archived_files: manifest.entries.iter().filter(|e| e.verified).count() as u32,
-        total_bytes_archived: manifest
-            .entries
-            .iter()
-            .filter(|e| e.verified)
-            .map(|e| e.size)
-            .sum(),
-        verified_count: manifest.entries.iter().filter(|e| e.verified).count() as u32,
+        total_archived_files: verified().count() as u32,
+        total_bytes_archived,
+        total_bytes_stored,
+        bytes_saved: total_bytes_archived.saturating_sub(total_bytes_stored),
+        compressed_count: verified()
+            .filter(|e| e.compression != ArchiveCompression::None)
+            .count() as u32,
+        pending_compression_count: verified()
+            .filter(|e| e.compression == ArchiveCompression::None)
+            .count() as u32,
+        verified_count: verified().count() as u32,
         pending_count: manifest.entries.iter().filter(|e| !e.verified).count() as u32,
         deleted_from_game_count: manifest
             .entries
             .iter()
             .filter(|e| e.deleted_from_game)
             .count() as u32,
+        pinned_count: verified().filter(|e| e.pinned).count() as u32,
+        crash_report_files: verified()
+            .filter(|e| e.kind == ArchiveEntryKind::CrashReport)
+            .count() as u32,
+        launcher_log_count: verified()
+            .filter(|e| e.kind == ArchiveEntryKind::LauncherLog)
+            .count() as u32,
+        pruned_count: manifest
+            .entries
+            .iter()
+            .filter(|e| e.pruned_at_ms.is_some())
+            .count() as u32,
         channels,
         archive_directory: root.display().to_string(),
     })
 }
 
+/// Reporte des fichiers déplacés dans le cache des statistiques en détenant le verrou de scan :
+/// un scan en cours enregistrerait son cache par-dessus les chemins renommés. Réservé aux
+/// tâches de fond, qui attendent la fin du scan plutôt que d'abandonner.
+fn rename_stats_states_when_idle(
+    app: &AppHandle,
+    scan_state: &GameStatsScanState,
+    moves: &[(String, String, u64)],
+) -> Result<(), String> {
+    while scan_state.try_begin(GameStatsScanKind::Sync).is_err() {
+        thread::sleep(SCAN_BUSY_RETRY);
+    }
+    let result = rename_file_states(app.path(), moves);
+    scan_state.finish();
+    result
+}
+
+/// Recompresse les copies brutes des versions précédentes, un fichier à la fois (le manifeste
+/// est enregistré après chaque fichier : une interruption ne perd rien).
+pub fn migrate_uncompressed_archives_sync(
+    app: &AppHandle,
+    scan_state: &GameStatsScanState,
+) -> Result<ArchiveCompressionMigrationResult, String> {
+    let root = ensure_archive_dir(app.path())?;
+    let files_dir = archive_files_dir(&root);
+    let pending: Vec<String> = {
+        let _guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
+        load_manifest(&root)?
+            .entries
+            .iter()
+            .filter(|e| e.is_active() && e.compression == ArchiveCompression::None)
+            .map(|e| e.archive_name.clone())
+            .collect()
+    };
+
+    let mut result = ArchiveCompressionMigrationResult::default();
+    for archive_name in pending {
+        let guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
+        let mut manifest = load_manifest(&root)?;
+        let Some(entry) = manifest.entries.iter_mut().find(|e| {
+            e.is_active()
+                && e.compression == ArchiveCompression::None
+                && e.archive_name == archive_name
+        }) else {
+            continue;
+        };
+        let plain = files_dir.join(&entry.archive_name);
+        if !plain.is_file() {
+            continue;
+        }
+        let gz_name = format!("{}.gz", entry.archive_name);
+        let gz = files_dir.join(&gz_name);
+        let copy = match write_verified_compressed_copy(&plain, &gz, &entry.sha256, entry.size) {
+            Ok(copy) => copy,
+            Err(e) => {
+                eprintln!("[gamelog_archive] compression de {archive_name}: {e}");
+                let _ = fs::remove_file(&gz);
+                result.failed += 1;
+                continue;
+            }
+        };
+
+        result.bytes_saved += entry.size.saturating_sub(copy.compressed_size);
+        entry.archive_name = gz_name;
+        entry.compression = ArchiveCompression::Gzip;
+        entry.compressed_sha256 = Some(copy.compressed_sha256);
+        entry.compressed_size = Some(copy.compressed_size);
+        manifest.schema_version = MANIFEST_SCHEMA_VERSION;
+        save_manifest(&root, &manifest)?;
+        let _ = fs::remove_file(&plain);
+        // Verrou de l'archive relâché avant d'attendre celui du scan (ordre de la rétention).
+        drop(guard);
+        let moves = [(
+            plain.to_string_lossy().into_owned(),
+            gz.to_string_lossy().into_owned(),
+            file_mtime_ms(&gz),
+        )];
+        if let Err(e) = rename_stats_states_when_idle(app, scan_state, &moves) {
+            eprintln!("[gamelog_archive] cache stats après compression: {e}");
+        }
+        result.migrated += 1;
+    }
+    Ok(result)
+}
+
+/// Calcule l'empreinte tête/queue des logs archivés avant son introduction (copie gzip lue
+/// en flux). Renvoie le nombre d'entrées complétées.
+pub fn backfill_archive_fingerprints_sync(app: &AppHandle) -> Result<u32, String> {
+    let root = ensure_archive_dir(app.path())?;
+    let files_dir = archive_files_dir(&root);
+    let pending: Vec<String> = {
+        let _guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
+        load_manifest(&root)?
+            .entries
+            .iter()
+            .filter(|e| e.is_active() && e.is_game_log() && e.fingerprint.is_none())
+            .map(|e| e.archive_name.clone())
+            .collect()
+    };
+
+    let mut filled = 0u32;
+    for archive_name in pending {
+        // Calcul hors verrou : seule l'écriture du manifeste est sérialisée.
+        let fingerprint = match log_fingerprint(&files_dir.join(&archive_name)) {
+            Ok(fingerprint) => fingerprint,
+            Err(e) => {
+                eprintln!("[gamelog_archive] empreinte de {archive_name}: {e}");
+                continue;
+            }
+        };
+        let _guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
+        let mut manifest = load_manifest(&root)?;
+        let Some(entry) = manifest
+            .entries
+            .iter_mut()
+            .find(|e| e.archive_name == archive_name && e.fingerprint.is_none())
+        else {
+            continue;
+        };
+        entry.fingerprint = Some(fingerprint);
+        save_manifest(&root, &manifest)?;
+        filled += 1;
+    }
+    Ok(filled)
+}
+
+/// Sélectionne les copies à supprimer, des plus anciennes aux plus récentes. Chaque copie
+/// porte la première règle qui l'a retenue ; les sessions épinglées ne sont jamais retenues.
+fn plan_retention(
+    entries: &[ArchiveManifestEntry],
+    config: &GamelogArchiveConfig,
+    now_ms: u64,
+) -> Vec<RetentionCandidate> {
+    let mut active: Vec<&ArchiveManifestEntry> = entries.iter().filter(|e| e.is_active()).collect();
+    active.sort_by_key(|e| e.source_mtime_ms);
+    let mut reasons: HashMap<&str, RetentionReason> = HashMap::new();
+
+    if let Some(days) = config.max_age_days {
+        let cutoff = now_ms.saturating_sub(u64::from(days) * 86_400_000);
+        for entry in active.iter().filter(|e| !e.pinned) {
+            if entry.source_mtime_ms < cutoff {
+                reasons.insert(&entry.archive_name, RetentionReason::MaxAge);
+            }
+        }
+    }
+
+    if let Some(keep) = config.keep_last_sessions_per_channel {
+        let mut by_channel: HashMap<String, Vec<&ArchiveManifestEntry>> = HashMap::new();
+        for entry in active.iter().filter(|e| e.is_game_log()) {
+            by_channel
+                .entry(entry.channel.to_ascii_uppercase())
+                .or_default()
+                .push(entry);
+        }
+        for sessions in by_channel.values() {
+            let excess = sessions.len().saturating_sub(keep as usize);
+            for entry in sessions[..excess].iter().filter(|e| !e.pinned) {
+                reasons
+                    .entry(&entry.archive_name)
+                    .or_insert(RetentionReason::SessionCount);
+            }
+        }
+    }
+
+    if let Some(max_bytes) = config.max_total_bytes {
+        let mut total: u64 = active
+            .iter()
+            .filter(|e| !reasons.contains_key(e.archive_name.as_str()))
+            .map(|e| e.stored_bytes())
+            .sum();
+        for entry in &active {
+            if total <= max_bytes {
+                break;
+            }
+            if entry.pinned || reasons.contains_key(entry.archive_name.as_str()) {
+                continue;
+            }
+            reasons.insert(&entry.archive_name, RetentionReason::TotalSize);
+            total = total.saturating_sub(entry.stored_bytes());
+        }
+    }
+
+    active
+        .iter()
+        .filter_map(|entry| {
+            let reason = *reasons.get(entry.archive_name.as_str())?;
+            Some(RetentionCandidate {
+                archive_name: entry.archive_name.clone(),
+                channel: entry.channel.clone(),
+                original_name: entry.original_name.clone(),
+                source_mtime_ms: entry.source_mtime_ms,
+                size: entry.size,
+                stored_bytes: entry.stored_bytes(),
+                kind: entry.kind,
+                reason,
+            })
+        })
+        .collect()
+}
+
+/// Simulation : liste ce que la rétention supprimerait, sans rien modifier.
+pub fn preview_archive_retention_sync(app: &AppHandle) -> Result<RetentionPreview, String> {
+    let config = load_archive_config_sync(app)?;
+    let root = ensure_archive_dir(app.path())?;
+    let manifest = load_manifest(&root)?;
+    let candidates = plan_retention(
+        &manifest.entries,
+        &config,
+        chrono::Utc::now().timestamp_millis() as u64,
+    );
+    let bytes_to_free: u64 = candidates.iter().map(|c| c.stored_bytes).sum();
+    let bytes_stored: u64 = manifest
+        .entries
+        .iter()
+        .filter(|e| e.is_active())
+        .map(ArchiveManifestEntry::stored_bytes)
+        .sum();
+    Ok(RetentionPreview {
+        candidates,
+        bytes_to_free,
+        bytes_remaining: bytes_stored.saturating_sub(bytes_to_free),
+    })
+}
+
+/// Intègre un log archivé à supprimer au magasin de blueprints puis à la base « retirée » des
+/// statistiques (ignoré s'il y figure déjà). Renvoie le nombre de blueprints ajoutés.
+fn fold_archived_log<R: Runtime>(
+    app: &AppHandle,
+    folder: &mut RetiredLogFolder<'_, R>,
+    path: &Path,
+    channel: &str,
+    content: &str,
+    fingerprint: LogFingerprint,
+) -> Result<u32, String> {
+    let blueprints = scan_log_content_for_blueprints(content);
+    let added = if blueprints.is_empty() {
+        0
+    } else {
+        append_blueprints(app, &blueprints)?
+    };
+    let file = describe_log_file(
+        path.to_path_buf(),
+        true,
+        Some(channel.to_string()),
+        fingerprint,
+    );
+    folder.fold(&file, content)?;
+    Ok(added as u32)
+}
+
+/// Applique la rétention. Détient le verrou de scan des statistiques : le cache ne doit pas
+/// être réécrit par un scan concurrent pendant l'intégration des logs supprimés.
+pub fn apply_archive_retention_sync(
+    app: &AppHandle,
+    scan_state: &GameStatsScanState,
+) -> Result<RetentionResult, String> {
+    let config = load_archive_config_sync(app)?;
+    if !config.has_retention() {
+        return Ok(RetentionResult::default());
+    }
+    scan_state
+        .try_begin(GameStatsScanKind::Sync)
+        .map_err(scan_busy_error)?;
+
+    let result = (|| {
+        let _guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
+        let root = ensure_archive_dir(app.path())?;
+        let files_dir = archive_files_dir(&root);
+        let mut manifest = load_manifest(&root)?;
+        let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+        let mut result = RetentionResult::default();
+
+        let candidates = plan_retention(&manifest.entries, &config, now_ms);
+        let mut folder = RetiredLogFolder::load(app.path())?;
+
+        // Un fichier à la fois (intégration, suppression, manifeste) : un seul log en mémoire.
+        for candidate in candidates {
+            let path = files_dir.join(&candidate.archive_name);
+            let mut fingerprint = None;
+            if path.is_file() {
+                // Rapports de crash et logs du launcher : rien à intégrer aux statistiques.
+                if candidate.kind == ArchiveEntryKind::GameLog {
+                    let (content, log_id) = match read_log_with_fingerprint(&path) {
+                        Ok(read) => read,
+                        Err(e) => {
+                            eprintln!(
+                                "[gamelog_archive] rétention, intégration de {}: {e}",
+                                candidate.archive_name
+                            );
+                            result.failed += 1;
+                            continue;
+                        }
+                    };
+                    result.blueprints_added += fold_archived_log(
+                        app,
+                        &mut folder,
+                        &path,
+                        &candidate.channel,
+                        &content,
+                        log_id.clone(),
+                    )?;
+                    // Conservée pour écarter toute autre copie de cette session.
+                    fingerprint = Some(log_id);
+                }
+                if let Err(e) = fs::remove_file(&path) {
+                    eprintln!(
+                        "[gamelog_archive] rétention, suppression de {}: {e}",
+                        candidate.archive_name
+                    );
+                    result.failed += 1;
+                    continue;
+                }
+            }
+            if let Some(entry) = manifest
+                .entries
+                .iter_mut()
+                .find(|e| e.archive_name == candidate.archive_name)
+            {
+                entry.pruned_at_ms = Some(now_ms);
+                if entry.fingerprint.is_none() {
+                    entry.fingerprint = fingerprint;
+                }
+            }
+            // Enregistré après chaque fichier. Un log intégré mais pas encore marqué supprimé
+            // (échec, interruption) est reconnu à son identité et n'est pas recompté.
+            save_manifest(&root, &manifest)?;
+            result.pruned += 1;
+            result.bytes_freed += candidate.stored_bytes;
+        }
+        Ok(result)
+    })();
+
+    scan_state.finish();
+    result
+}
+
+/// Copie `files/` et le manifeste vers `target`, chaque copie étant contrôlée (empreinte du
+/// fichier stocké si connue, sinon taille). Renvoie les fichiers déplacés et leur volume.
+fn copy_archive_tree(
+    from: &Path,
+    target: &Path,
+    manifest: &ArchiveManifest,
+) -> Result<(Vec<String>, u64), String> {
+    let from_files = archive_files_dir(from);
+    let target_files = archive_files_dir(target);
+    fs::create_dir_all(&target_files).map_err(|e| e.to_string())?;
+    let expected: HashMap<&str, &str> = manifest
+        .entries
+        .iter()
+        .filter_map(|e| Some((e.archive_name.as_str(), e.compressed_sha256.as_deref()?)))
+        .collect();
+
+    let mut names = Vec::new();
+    let mut bytes = 0u64;
+    for dir_entry in fs::read_dir(&from_files).map_err(|e| e.to_string())? {
+        let path = dir_entry.map_err(|e| e.to_string())?.path();
+        if !path.is_file() {
+            continue;
+        }
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let dest = target_files.join(&name);
+        let copied = fs::copy(&path, &dest).map_err(|e| format!("{name}: {e}"))?;
+        let source_len = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        if copied != source_len {
+            return Err(format!("{name}: copie incomplète"));
+        }
+        if let Some(hash) = expected.get(name.as_str()) {
+            if sha256_file(&dest)? != *hash {
+                return Err(format!("{name}: empreinte différente après copie"));
+            }
+        }
+        bytes += copied;
+        names.push(name);
+    }
+    fs::copy(manifest_path(from), manifest_path(target)).map_err(|e| e.to_string())?;
+    Ok((names, bytes))
+}
+
+fn remove_archive_tree(root: &Path) {
+    let _ = fs::remove_dir_all(archive_files_dir(root));
+    // Index de recherche : chemins absolus, reconstruit à la prochaine recherche.
+    let _ = fs::remove_dir_all(root.join("search_index"));
+    let _ = fs::remove_file(manifest_path(root));
+    let _ = fs::remove_dir(root);
+}
+
+/// Déplace l'archive vers `target_dir` (`None` : emplacement par défaut). Détient le verrou de
+/// scan des statistiques : les chemins du cache sont réécrits vers le nouvel emplacement.
+pub fn relocate_archive_sync(
+    app: &AppHandle,
+    scan_state: &GameStatsScanState,
+    target_dir: Option<String>,
+) -> Result<ArchiveRelocationResult, String> {
+    scan_state
+        .try_begin(GameStatsScanKind::Sync)
+        .map_err(scan_busy_error)?;
+    let result = relocate_archive(app, target_dir);
+    scan_state.finish();
+    result
+}
+
+/// La configuration n'est modifiée qu'une fois la copie vérifiée, et l'ancien dossier supprimé
+/// seulement ensuite.
+fn relocate_archive(
+    app: &AppHandle,
+    target_dir: Option<String>,
+) -> Result<ArchiveRelocationResult, String> {
+    let _guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
+    let mut config = load_archive_config_sync(app)?;
+    let current = ensure_archive_dir(app.path())?;
+    let default_root = default_archive_root(app.path())?;
+    let target = match target_dir
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+    {
+        Some(dir) => PathBuf::from(dir),
+        None => default_root.clone(),
+    };
+
+    let canonical_current = fs::canonicalize(&current).unwrap_or_else(|_| current.clone());
+    let canonical_target = fs::canonicalize(&target).unwrap_or_else(|_| target.clone());
+    if canonical_current == canonical_target {
+        return Err("L'archive est déjà dans ce dossier.".to_string());
+    }
+    if canonical_target.starts_with(&canonical_current) {
+        return Err("Le dossier cible ne peut pas être à l'intérieur de l'archive.".to_string());
+    }
+    let target_is_empty = fs::read_dir(&target)
+        .map(|mut entries| entries.next().is_none())
+        .unwrap_or(true);
+    if !target_is_empty {
+        return Err("Le dossier cible doit être vide.".to_string());
+    }
+
+    let manifest = load_manifest(&current)?;
+    // Matérialise le manifeste s'il n'a jamais été écrit : il fait partie de la copie.
+    save_manifest(&current, &manifest)?;
+    let (names, bytes_moved) = match copy_archive_tree(&current, &target, &manifest) {
+        Ok(copied) => copied,
+        Err(e) => {
+            remove_archive_tree(&target);
+            return Err(format!("Déplacement de l'archive interrompu : {e}"));
+        }
+    };
+
+    config.archive_directory = (target != default_root).then(|| target.display().to_string());
+    if let Err(e) = save_archive_config_sync(app, &config) {
+        remove_archive_tree(&target);
+        return Err(e);
+    }
+
+    let from_files = archive_files_dir(&current);
+    let target_files = archive_files_dir(&target);
+    let moves: Vec<(String, String, u64)> = names
+        .iter()
+        .map(|name| {
+            let to = target_files.join(name);
+            (
+                from_files.join(name).to_string_lossy().into_owned(),
+                to.to_string_lossy().into_owned(),
+                file_mtime_ms(&to),
+            )
+        })
+        .collect();
+    if let Err(e) = rename_file_states(app.path(), &moves) {
+        eprintln!("[gamelog_archive] cache stats après déplacement: {e}");
+    }
+    remove_archive_tree(&current);
+
+    Ok(ArchiveRelocationResult {
+        archive_directory: target.display().to_string(),
+        files_moved: names.len() as u32,
+        bytes_moved,
+    })
+}
+
+pub fn list_archive_entries_sync(app: &AppHandle) -> Result<Vec<ArchiveManifestEntry>, String> {
+    let root = ensure_archive_dir(app.path())?;
+    let mut entries: Vec<ArchiveManifestEntry> = load_manifest(&root)?
+        .entries
+        .into_iter()
+        .filter(|e| e.is_active())
+        .collect();
+    entries.sort_by_key(|e| std::cmp::Reverse(e.source_mtime_ms));
+    Ok(entries)
+}
+
+pub fn set_archive_entry_pinned_sync(
+    app: &AppHandle,
+    archive_name: &str,
+    pinned: bool,
+) -> Result<(), String> {
+    let _guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
+    let root = ensure_archive_dir(app.path())?;
+    let mut manifest = load_manifest(&root)?;
+    let entry = manifest
+        .entries
+        .iter_mut()
+        .find(|e| e.is_active() && e.archive_name == archive_name)
+        .ok_or_else(|| format!("Log archivé introuvable : {archive_name}"))?;
+    entry.pinned = pinned;
+    save_manifest(&root, &manifest)
+}
+
 #[command]
 pub fn load_gamelog_archive_config(app: AppHandle) -> Result<GamelogArchiveConfig, String> {
     load_archive_config_sync(&app)
@@ -563,7 +1503,16 @@ pub fn save_gamelog_archive_config(
     app: AppHandle,
     config: GamelogArchiveConfig,
 ) -> Result<(), String> {
-    save_archive_config_sync(&app, &config)
+    let _guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
+    // Le dossier d'archive ne change que par déplacement effectif des fichiers.
+    let archive_directory = load_archive_config_sync(&app)?.archive_directory;
+    save_archive_config_sync(
+        &app,
+        &GamelogArchiveConfig {
+            archive_directory,
+            ..config
+        },
+    )
 }
 
 #[command]
@@ -580,10 +1529,235 @@ pub async fn get_gamelog_archive_status(app: AppHandle) -> Result<GamelogArchive
         .map_err(|e| e.to_string())?
 }
 
+#[command]
+pub async fn list_gamelog_archive_entries(
+    app: AppHandle,
+) -> Result<Vec<ArchiveManifestEntry>, String> {
+    tokio::task::spawn_blocking(move || list_archive_entries_sync(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[command]
+pub async fn relocate_gamelog_archive(
+    app: AppHandle,
+    scan_state: State<'_, GameStatsScanState>,
+    target_dir: Option<String>,
+) -> Result<ArchiveRelocationResult, String> {
+    let scan_state = scan_state.inner().clone();
+    tokio::task::spawn_blocking(move || relocate_archive_sync(&app, &scan_state, target_dir))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[command]
+pub fn set_gamelog_archive_pinned(
+    app: AppHandle,
+    archive_name: String,
+    pinned: bool,
+) -> Result<(), String> {
+    set_archive_entry_pinned_sync(&app, &archive_name, pinned)
+}
+
+#[command]
+pub async fn preview_gamelog_archive_retention(app: AppHandle) -> Result<RetentionPreview, String> {
+    tokio::task::spawn_blocking(move || preview_archive_retention_sync(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[command]
+pub async fn apply_gamelog_archive_retention(
+    app: AppHandle,
+    scan_state: State<'_, GameStatsScanState>,
+) -> Result<RetentionResult, String> {
+    let scan_state = scan_state.inner().clone();
+    tokio::task::spawn_blocking(move || apply_archive_retention_sync(&app, &scan_state))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Fixtures partagées par les tests de l'archive et de ses modules (vérification, bundles).
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::*;
+
+    /// Entrée de manifeste sans fichier sur disque.
+    pub(crate) fn manifest_entry(
+        name: &str,
+        channel: &str,
+        mtime_ms: u64,
+        stored: u64,
+    ) -> ArchiveManifestEntry {
+        ArchiveManifestEntry {
+            id: name.to_string(),
+            channel: channel.to_string(),
+            source_path: format!("logbackups/{name}"),
+            original_name: name.to_string(),
+            archive_name: name.to_string(),
+            sha256: String::new(),
+            size: stored * 10,
+            compression: ArchiveCompression::Gzip,
+            compressed_sha256: None,
+            compressed_size: Some(stored),
+            source_mtime_ms: mtime_ms,
+            archived_at_ms: mtime_ms,
+            verified: true,
+            verify_error: None,
+            deleted_from_game: true,
+            pinned: false,
+            pruned_at_ms: None,
+            kind: ArchiveEntryKind::GameLog,
+            crash_id: None,
+            fingerprint: None,
+        }
+    }
+
+    /// Archive réellement `content` sous `root` (copie gzip vérifiée `<name>.log.gz`) ;
+    /// le log source `<name>.log` n'est conservé que si `keep_source`.
+    pub(crate) fn archive_log(
+        root: &Path,
+        name: &str,
+        content: &str,
+        keep_source: bool,
+    ) -> ArchiveManifestEntry {
+        let files_dir = archive_files_dir(root);
+        fs::create_dir_all(&files_dir).unwrap();
+        let source = root.join(format!("{name}.log"));
+        fs::write(&source, content).unwrap();
+        let (sha256, size) = sha256_reader(content.as_bytes()).unwrap();
+        let archive_name = format!("{name}.log.gz");
+        let copy =
+            write_verified_compressed_copy(&source, &files_dir.join(&archive_name), &sha256, size)
+                .unwrap();
+        if !keep_source {
+            fs::remove_file(&source).unwrap();
+        }
+        ArchiveManifestEntry {
+            source_path: source.to_string_lossy().into_owned(),
+            original_name: format!("{name}.log"),
+            archive_name,
+            sha256,
+            size,
+            compressed_sha256: Some(copy.compressed_sha256),
+            compressed_size: Some(copy.compressed_size),
+            source_mtime_ms: 0,
+            archived_at_ms: 0,
+            deleted_from_game: !keep_source,
+            ..manifest_entry(name, "LIVE", 0, 0)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::test_support::manifest_entry as entry;
     use super::*;
 
+    #[test]
+    fn plan_retention_respects_pins_and_limits() {
+        let day = 86_400_000;
+        let now = 100 * day;
+        let mut entries = vec![
+            entry("a", "LIVE", day, 100),
+            entry("b", "LIVE", 50 * day, 100),
+            entry("c", "LIVE", 90 * day, 100),
+            entry("d", "LIVE", 99 * day, 100),
+            entry("e", "PTU", 98 * day, 100),
+        ];
+        entries[0].pinned = true;
+        let mut pruned = entry("old", "LIVE", 0, 100);
+        pruned.pruned_at_ms = Some(day);
+        entries.push(pruned);
+
+        let config = GamelogArchiveConfig {
+            max_age_days: Some(30),
+            keep_last_sessions_per_channel: Some(2),
+            max_total_bytes: Some(300),
+            ..GamelogArchiveConfig::default()
+        };
+        let plan = plan_retention(&entries, &config, now);
+        let reasons: Vec<(&str, RetentionReason)> = plan
+            .iter()
+            .map(|c| (c.archive_name.as_str(), c.reason))
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                ("b", RetentionReason::MaxAge),
+                ("c", RetentionReason::TotalSize),
+            ]
+        );
+
+        let config = GamelogArchiveConfig {
+            keep_last_sessions_per_channel: Some(1),
+            ..GamelogArchiveConfig::default()
+        };
+        let names: Vec<String> = plan_retention(&entries, &config, now)
+            .into_iter()
+            .map(|c| c.archive_name)
+            .collect();
+        assert_eq!(names, vec!["b", "c"]);
+        assert!(plan_retention(&entries, &GamelogArchiveConfig::default(), now).is_empty());
+    }
+
+    #[test]
+    fn compressed_copy_round_trips_through_log_readers() {
+        use crate::scripts::game_log::io::{
+            log_content_len, read_from_offset, read_log_file_lossy,
+        };
+
+        let dir = std::env::temp_dir().join(format!("gamelog_archive_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("Game Build.log");
+        let content = "<2026-05-16T11:28:35.195Z> line one\n".repeat(200);
+        fs::write(&source, &content).unwrap();
+        let (hash, size) = sha256_reader(fs::File::open(&source).unwrap()).unwrap();
+
+        let dest = dir.join(sanitize_archive_name("LIVE", "Game Build.log"));
+        let copy = write_verified_compressed_copy(&source, &dest, &hash, size).unwrap();
+        assert!(copy.compressed_size < size);
+        assert_eq!(read_log_file_lossy(&dest).unwrap(), content);
+        assert_eq!(log_content_len(&dest).unwrap(), size);
+        assert_eq!(read_from_offset(&dest, 10).unwrap(), content[10..]);
+        assert!(write_verified_compressed_copy(&source, &dest, "bad", size).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn launcher_logs_stay_in_place_after_archiving() {
+        let root =
+            std::env::temp_dir().join(format!("gamelog_launcher_test_{}", std::process::id()));
+        let files_dir = archive_files_dir(&root);
+        fs::create_dir_all(&files_dir).unwrap();
+        let config = GamelogArchiveConfig::default();
+        let mut manifest = ArchiveManifest::default();
+        let mut result = GamelogArchiveSyncResult::default();
+
+        for (name, kind) in [
+            ("log.1.log", ArchiveEntryKind::LauncherLog),
+            ("Game Build(1).log", ArchiveEntryKind::GameLog),
+        ] {
+            let path = root.join(name);
+            fs::write(&path, "[2026-05-16 10:00:00.000] [info] line\n").unwrap();
+            let source = ArchiveSource {
+                archive_name: sanitize_archive_name("LIVE", name),
+                path,
+                channel: "LIVE".to_string(),
+                kind,
+                crash_id: None,
+            };
+            archive_source(&mut manifest, &files_dir, &config, source, &mut result);
+        }
+
+        assert_eq!((result.archived, result.deleted_from_game), (2, 1));
+        assert!(root.join("log.1.log").is_file());
+        assert!(!root.join("Game Build(1).log").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
     #[test]
     fn sanitize_archive_name_replaces_spaces() {
         let name = sanitize_archive_name("LIVE", "Game Build 2025.log");
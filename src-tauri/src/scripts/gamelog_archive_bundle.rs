@@ -0,0 +1,345 @@
+//! Bundle portable de l'archive des logs (zip : `manifest.json` + `files/…`) pour la
+//! transférer vers un autre PC. L'import fusionne les manifestes par empreinte sha256 du log
+//! d'origine : un log déjà archivé localement n'est jamais dupliqué.
+
+use crate::scripts::gamelog_archive::{
+    archive_files_dir, ensure_archive_dir, load_manifest, save_manifest, ArchiveManifest,
+    ARCHIVE_LOCK, MANIFEST_SCHEMA_VERSION,
+};
+use crate::scripts::gamelog_archive_verify::check_entry;
+use crate::scripts::gamelog_watcher::{append_blueprints, scan_file_for_blueprints};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use tauri::{command, AppHandle, Manager};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+const BUNDLE_MANIFEST_NAME: &str = "manifest.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveBundleExportResult {
+    pub files_packed: u32,
+    pub bytes_packed: u64,
+    pub skipped: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveBundleImportResult {
+    pub imported: u32,
+    /// Logs déjà présents localement (même empreinte).
+    pub skipped_duplicates: u32,
+    pub failed: u32,
+    pub blueprints_added: u32,
+}
+
+fn bundle_file_name(archive_name: &str) -> String {
+    format!("files/{archive_name}")
+}
+
+fn export_bundle(root: &Path, dest: &Path) -> Result<ArchiveBundleExportResult, String> {
+    let files_dir = archive_files_dir(root);
+    let manifest = load_manifest(root)?;
+
+    if let Some(parent) = dest.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+    }
+    let mut zip = ZipWriter::new(File::create(dest).map_err(|e| e.to_string())?);
+    let mut bundle = ArchiveManifest::default();
+    let mut result = ArchiveBundleExportResult {
+        files_packed: 0,
+        bytes_packed: 0,
+        skipped: Vec::new(),
+    };
+
+    for entry in manifest.entries.iter().filter(|e| e.is_active()) {
+        let path = files_dir.join(&entry.archive_name);
+        let Ok(mut file) = File::open(&path) else {
+            result.skipped.push(entry.archive_name.clone());
+            continue;
+        };
+        let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+        // Les copies gzip ne gagnent rien à être recompressées.
+        let method = match entry.compressed_sha256 {
+            Some(_) => CompressionMethod::Stored,
+            None => CompressionMethod::Deflated,
+        };
+        let options = SimpleFileOptions::default()
+            .compression_method(method)
+            .large_file(len >= u32::MAX as u64);
+        zip.start_file(bundle_file_name(&entry.archive_name), options)
+            .map_err(|e| e.to_string())?;
+        let copied = io::copy(&mut file, &mut zip).map_err(|e| e.to_string())?;
+        result.files_packed += 1;
+        result.bytes_packed += copied;
+        bundle.entries.push(entry.clone());
+    }
+
+    let json = serde_json::to_string_pretty(&bundle).map_err(|e| e.to_string())?;
+    zip.start_file(BUNDLE_MANIFEST_NAME, SimpleFileOptions::default())
+        .map_err(|e| e.to_string())?;
+    zip.write_all(json.as_bytes()).map_err(|e| e.to_string())?;
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(result)
+}
+
+/// Le nom vient du manifeste du bundle, non fiable : il doit désigner un simple fichier de
+/// `files/` (ni chemin absolu, ni `..`, ni sous-dossier).
+fn is_plain_file_name(name: &str) -> bool {
+    let mut components = Path::new(name).components();
+    matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(_)), None)
+    )
+}
+
+/// Nom libre dans `files/` : les bundles de plusieurs PC peuvent réutiliser les mêmes noms.
+fn unique_archive_name(files_dir: &Path, taken: &HashSet<String>, wanted: &str) -> String {
+    let mut name = wanted.to_string();
+    let mut n = 1;
+    while taken.contains(&name) || files_dir.join(&name).exists() {
+        name = format!("import{n}__{wanted}");
+        n += 1;
+    }
+    name
+}
+
+/// Fusionne le bundle dans l'archive de `root`. Renvoie aussi les copies ajoutées.
+fn import_bundle(
+    root: &Path,
+    bundle_path: &Path,
+) -> Result<(ArchiveBundleImportResult, Vec<PathBuf>), String> {
+    let mut zip = ZipArchive::new(File::open(bundle_path).map_err(|e| e.to_string())?)
+        .map_err(|e| format!("Bundle illisible : {e}"))?;
+    let bundle: ArchiveManifest = {
+        let mut json = String::new();
+        zip.by_name(BUNDLE_MANIFEST_NAME)
+            .map_err(|_| "Bundle invalide : manifest.json absent.".to_string())?
+            .read_to_string(&mut json)
+            .map_err(|e| e.to_string())?;
+        serde_json::from_str(&json).map_err(|e| format!("Manifeste du bundle invalide : {e}"))?
+    };
+    if bundle.schema_version > MANIFEST_SCHEMA_VERSION {
+        return Err("Bundle créé par une version plus récente de l'application.".to_string());
+    }
+
+    let files_dir = archive_files_dir(root);
+    let mut manifest = load_manifest(root)?;
+    let mut known: HashSet<String> = manifest
+        .entries
+        .iter()
+        .filter(|e| !e.sha256.is_empty())
+        .map(|e| e.sha256.clone())
+        .collect();
+    let mut taken: HashSet<String> = manifest
+        .entries
+        .iter()
+        .map(|e| e.archive_name.clone())
+        .collect();
+    let mut result = ArchiveBundleImportResult::default();
+    let mut imported = Vec::new();
+
+    for mut entry in bundle.entries.into_iter().filter(|e| e.is_active()) {
+        if entry.sha256.is_empty() || known.contains(&entry.sha256) {
+            result.skipped_duplicates += 1;
+            continue;
+        }
+        if !is_plain_file_name(&entry.archive_name) {
+            eprintln!(
+                "[gamelog_archive] import de {}: nom d'archive refusé ({})",
+                entry.original_name, entry.archive_name
+            );
+            result.failed += 1;
+            continue;
+        }
+        let name = unique_archive_name(&files_dir, &taken, &entry.archive_name);
+        let dest = files_dir.join(&name);
+        let extracted = zip
+            .by_name(&bundle_file_name(&entry.archive_name))
+            .map_err(|e| e.to_string())
+            .and_then(|mut file| {
+                let mut out = File::create(&dest).map_err(|e| e.to_string())?;
+                io::copy(&mut file, &mut out).map_err(|e| e.to_string())
+            });
+        entry.archive_name = name;
+        let issue = match extracted {
+            Ok(_) => check_entry(&files_dir, &entry).map(|(_, detail)| detail),
+            Err(e) => Some(e),
+        };
+        if let Some(detail) = issue {
+            eprintln!(
+                "[gamelog_archive] import de {}: {detail}",
+                entry.original_name
+            );
+            let _ = fs::remove_file(&dest);
+            result.failed += 1;
+            continue;
+        }
+
+        known.insert(entry.sha256.clone());
+        taken.insert(entry.archive_name.clone());
+        entry.pruned_at_ms = None;
+        imported.push(dest);
+        manifest.entries.push(entry);
+        result.imported += 1;
+    }
+
+    manifest.schema_version = MANIFEST_SCHEMA_VERSION;
+    save_manifest(root, &manifest)?;
+    Ok((result, imported))
+}
+
+pub fn export_archive_bundle_sync(
+    app: &AppHandle,
+    dest_path: String,
+) -> Result<ArchiveBundleExportResult, String> {
+    let _guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
+    let root = ensure_archive_dir(app.path())?;
+    export_bundle(&root, &PathBuf::from(dest_path.trim()))
+}
+
+/// Importe un bundle puis reporte ses blueprints dans le magasin ; les statistiques les
+/// intègrent au prochain scan incrémental (copies listées comme archives locales).
+pub fn import_archive_bundle_sync(
+    app: &AppHandle,
+    bundle_path: String,
+) -> Result<ArchiveBundleImportResult, String> {
+    let (mut result, imported) = {
+        let _guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
+        let root = ensure_archive_dir(app.path())?;
+        import_bundle(&root, &PathBuf::from(bundle_path.trim()))?
+    };
+    for path in imported {
+        match scan_file_for_blueprints(&path).and_then(|found| append_blueprints(app, &found)) {
+            Ok(added) => result.blueprints_added += added as u32,
+            Err(e) => eprintln!("[gamelog_archive] blueprints de {}: {e}", path.display()),
+        }
+    }
+    Ok(result)
+}
+
+#[command]
+pub async fn export_gamelog_archive_bundle(
+    app: AppHandle,
+    dest_path: String,
+) -> Result<ArchiveBundleExportResult, String> {
+    tokio::task::spawn_blocking(move || export_archive_bundle_sync(&app, dest_path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[command]
+pub async fn import_gamelog_archive_bundle(
+    app: AppHandle,
+    bundle_path: String,
+) -> Result<ArchiveBundleImportResult, String> {
+    tokio::task::spawn_blocking(move || import_archive_bundle_sync(&app, bundle_path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scripts::gamelog_archive::test_support::archive_log;
+
+    fn add_log(root: &Path, manifest: &mut ArchiveManifest, name: &str, content: &str) {
+        let mut entry = archive_log(root, name, content, false);
+        entry.pinned = name == "shared";
+        manifest.entries.push(entry);
+    }
+
+    #[test]
+    fn bundle_import_merges_by_sha256() {
+        let base = std::env::temp_dir().join(format!("gamelog_bundle_test_{}", std::process::id()));
+        let old_pc = base.join("old");
+        let new_pc = base.join("new");
+
+        let mut old_manifest = ArchiveManifest::default();
+        add_log(
+            &old_pc,
+            &mut old_manifest,
+            "shared",
+            "<2026-01-01T00:00:00.000Z> a\n",
+        );
+        add_log(
+            &old_pc,
+            &mut old_manifest,
+            "only_old",
+            "<2026-01-02T00:00:00.000Z> b\n",
+        );
+        save_manifest(&old_pc, &old_manifest).unwrap();
+
+        let mut new_manifest = ArchiveManifest::default();
+        add_log(
+            &new_pc,
+            &mut new_manifest,
+            "shared",
+            "<2026-01-01T00:00:00.000Z> a\n",
+        );
+        save_manifest(&new_pc, &new_manifest).unwrap();
+
+        let bundle = base.join("bundle.zip");
+        let exported = export_bundle(&old_pc, &bundle).unwrap();
+        assert_eq!(exported.files_packed, 2);
+
+        let (result, imported) = import_bundle(&new_pc, &bundle).unwrap();
+        assert_eq!(
+            (result.imported, result.skipped_duplicates, result.failed),
+            (1, 1, 0)
+        );
+        assert_eq!(imported.len(), 1);
+        let merged = load_manifest(&new_pc).unwrap();
+        assert_eq!(merged.entries.len(), 2);
+        assert!(merged.entries[1].archive_name.ends_with("only_old.log.gz"));
+
+        let (again, _) = import_bundle(&new_pc, &bundle).unwrap();
+        assert_eq!((again.imported, again.skipped_duplicates), (0, 2));
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn bundle_import_rejects_archive_names_outside_files_dir() {
+        let base = std::env::temp_dir().join(format!(
+            "gamelog_bundle_traversal_test_{}",
+            std::process::id()
+        ));
+        let root = base.join("pc");
+        fs::create_dir_all(archive_files_dir(&root)).unwrap();
+
+        let mut hostile = ArchiveManifest::default();
+        let mut entry = archive_log(&base.join("src"), "evil", "x\n", false);
+        entry.archive_name = "../escape.log.gz".to_string();
+        hostile.entries.push(entry);
+        let bundle = base.join("hostile.zip");
+        let mut zip = ZipWriter::new(File::create(&bundle).unwrap());
+        zip.start_file("files/../escape.log.gz", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"payload").unwrap();
+        zip.start_file(BUNDLE_MANIFEST_NAME, SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(serde_json::to_string(&hostile).unwrap().as_bytes())
+            .unwrap();
+        zip.finish().unwrap();
+
+        let (result, imported) = import_bundle(&root, &bundle).unwrap();
+        assert_eq!((result.imported, result.failed), (0, 1));
+        assert!(imported.is_empty());
+        assert!(!root.join("escape.log.gz").exists());
+        assert!(load_manifest(&root).unwrap().entries.is_empty());
+
+        assert!(is_plain_file_name("LIVE__a.log.gz"));
+        for name in ["", ".", "..", "/etc/passwd", "files/a.gz", "a/../../b"] {
+            assert!(!is_plain_file_name(name), "{name}");
+        }
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+}
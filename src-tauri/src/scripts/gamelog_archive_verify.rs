@@ -0,0 +1,438 @@
+//! Revérification de l'archive locale des logs (`gamelog_archive`) : l'empreinte n'est contrôlée
+//! qu'à la copie, ce module détecte ensuite les fichiers absents, altérés ou orphelins, et
+//! répare ce qui peut l'être (recopie depuis la source encore présente, entrée abandonnée).
+
+use crate::scripts::game_log::cache::rename_file_state;
+use crate::scripts::game_log::commands::scan_busy_error;
+use crate::scripts::game_log::io::open_log_reader;
+use crate::scripts::game_log::scan_state::{GameStatsScanKind, GameStatsScanState};
+use crate::scripts::gamelog_archive::{
+    archive_files_dir, ensure_archive_dir, file_mtime_ms, load_manifest, save_manifest,
+    sha256_file, sha256_reader, write_verified_compressed_copy, ArchiveCompression,
+    ArchiveManifestEntry, ARCHIVE_LOCK, MANIFEST_SCHEMA_VERSION,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Emitter, Manager, State};
+
+pub const GAMELOG_ARCHIVE_VERIFY_PROGRESS_EVENT: &str = "gamelog-archive-verify-progress";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogArchiveVerifyProgress {
+    pub phase: String,
+    pub files_done: u32,
+    pub files_total: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_file: Option<String>,
+    pub percent: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ArchiveIssueKind {
+    Missing,
+    Corrupted,
+    /// Fichier présent dans `files/` sans entrée de manifeste.
+    Orphaned,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ArchiveRepairAction {
+    /// La source `logbackups/` existe encore avec la même empreinte.
+    RecopyFromSource,
+    /// Copie et source perdues : l'entrée est retirée du manifeste.
+    DropEntry,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveIssue {
+    pub kind: ArchiveIssueKind,
+    pub archive_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_name: Option<String>,
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repair: Option<ArchiveRepairAction>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogArchiveVerifyReport {
+    pub checked: u32,
+    pub ok: u32,
+    pub missing: u32,
+    pub corrupted: u32,
+    pub orphaned: u32,
+    pub issues: Vec<ArchiveIssue>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogArchiveRepairResult {
+    pub recopied: u32,
+    pub dropped: u32,
+    pub failed: u32,
+    /// Problèmes sans réparation possible (copie altérée sans source, orphelins).
+    pub unrepairable: u32,
+}
+
+fn verify_percent(files_done: u32, files_total: u32) -> u8 {
+    if files_total == 0 {
+        return 95;
+    }
+    (5 + (files_done.saturating_mul(90) / files_total)).min(95) as u8
+}
+
+fn emit_verify_progress(app: &AppHandle, progress: GamelogArchiveVerifyProgress) {
+    let _ = app.emit(GAMELOG_ARCHIVE_VERIFY_PROGRESS_EVENT, &progress);
+}
+
+/// Contrôle une copie : empreinte du fichier stocké si connue, sinon du contenu décompressé.
+pub(crate) fn check_entry(
+    files_dir: &Path,
+    entry: &ArchiveManifestEntry,
+) -> Option<(ArchiveIssueKind, String)> {
+    let path = files_dir.join(&entry.archive_name);
+    if !path.is_file() {
+        return Some((
+            ArchiveIssueKind::Missing,
+            "Copie absente du disque".to_string(),
+        ));
+    }
+    let check = match (&entry.compression, &entry.compressed_sha256) {
+        (ArchiveCompression::Gzip, Some(expected)) => sha256_file(&path).map(|hash| {
+            (hash != *expected).then(|| "Empreinte du fichier compressé différente".to_string())
+        }),
+        _ => open_log_reader(&path)
+            .and_then(sha256_reader)
+            .map(|(hash, size)| {
+                (hash != entry.sha256 || size != entry.size)
+                    .then(|| "Empreinte du contenu différente".to_string())
+            }),
+    };
+    match check {
+        Ok(None) => None,
+        Ok(Some(detail)) | Err(detail) => Some((ArchiveIssueKind::Corrupted, detail)),
+    }
+}
+
+fn source_matches(entry: &ArchiveManifestEntry) -> bool {
+    let source = Path::new(&entry.source_path);
+    source.is_file() && sha256_file(source).is_ok_and(|hash| hash == entry.sha256)
+}
+
+fn verify_archive_root(
+    root: &Path,
+    mut on_progress: impl FnMut(GamelogArchiveVerifyProgress),
+) -> Result<GamelogArchiveVerifyReport, String> {
+    let manifest = load_manifest(root)?;
+    let files_dir = archive_files_dir(root);
+    let entries: Vec<&ArchiveManifestEntry> =
+        manifest.entries.iter().filter(|e| e.is_active()).collect();
+    let files_total = entries.len() as u32;
+    let mut report = GamelogArchiveVerifyReport::default();
+
+    for (index, entry) in entries.iter().enumerate() {
+        on_progress(GamelogArchiveVerifyProgress {
+            phase: "verifying".to_string(),
+            files_done: index as u32,
+            files_total,
+            current_file: Some(entry.archive_name.clone()),
+            percent: verify_percent(index as u32, files_total),
+        });
+        report.checked += 1;
+        let Some((kind, detail)) = check_entry(&files_dir, entry) else {
+            report.ok += 1;
+            continue;
+        };
+        let repair = if source_matches(entry) {
+            Some(ArchiveRepairAction::RecopyFromSource)
+        } else if kind == ArchiveIssueKind::Missing {
+            Some(ArchiveRepairAction::DropEntry)
+        } else {
+            None
+        };
+        match kind {
+            ArchiveIssueKind::Missing => report.missing += 1,
+            _ => report.corrupted += 1,
+        }
+        report.issues.push(ArchiveIssue {
+            kind,
+            archive_name: entry.archive_name.clone(),
+            channel: Some(entry.channel.clone()),
+            original_name: Some(entry.original_name.clone()),
+            detail,
+            repair,
+        });
+    }
+
+    let known: HashSet<&str> = manifest
+        .entries
+        .iter()
+        .map(|e| e.archive_name.as_str())
+        .collect();
+    let mut orphans: Vec<String> = fs::read_dir(&files_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file())
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .filter(|name| !known.contains(name.as_str()))
+        .collect();
+    orphans.sort();
+    for name in orphans {
+        report.orphaned += 1;
+        report.issues.push(ArchiveIssue {
+            kind: ArchiveIssueKind::Orphaned,
+            archive_name: name,
+            channel: None,
+            original_name: None,
+            detail: "Fichier absent du manifeste".to_string(),
+            repair: None,
+        });
+    }
+
+    Ok(report)
+}
+
+pub fn verify_archive_sync(app: &AppHandle) -> Result<GamelogArchiveVerifyReport, String> {
+    let root = ensure_archive_dir(app.path())?;
+    let report = verify_archive_root(&root, |progress| emit_verify_progress(app, progress))?;
+    emit_verify_progress(
+        app,
+        GamelogArchiveVerifyProgress {
+            phase: "done".to_string(),
+            files_done: report.checked,
+            files_total: report.checked,
+            current_file: None,
+            percent: 100,
+        },
+    );
+    Ok(report)
+}
+
+/// Réécrit la copie depuis la source ; une archive brute est recompressée au passage.
+fn recopy_from_source(files_dir: &Path, entry: &mut ArchiveManifestEntry) -> Result<(), String> {
+    let name = match entry.compression {
+        ArchiveCompression::Gzip => entry.archive_name.clone(),
+        ArchiveCompression::None => format!("{}.gz", entry.archive_name),
+    };
+    let dest = files_dir.join(&name);
+    let copy = write_verified_compressed_copy(
+        &PathBuf::from(&entry.source_path),
+        &dest,
+        &entry.sha256,
+        entry.size,
+    )
+    .inspect_err(|_| {
+        let _ = fs::remove_file(&dest);
+    })?;
+    if name != entry.archive_name {
+        let _ = fs::remove_file(files_dir.join(&entry.archive_name));
+    }
+    entry.archive_name = name;
+    entry.compression = ArchiveCompression::Gzip;
+    entry.compressed_sha256 = Some(copy.compressed_sha256);
+    entry.compressed_size = Some(copy.compressed_size);
+    entry.verify_error = None;
+    Ok(())
+}
+
+fn repair_archive_root(
+    root: &Path,
+    report: &GamelogArchiveVerifyReport,
+) -> Result<(GamelogArchiveRepairResult, Vec<(PathBuf, PathBuf)>), String> {
+    let _guard = ARCHIVE_LOCK.lock().map_err(|e| e.to_string())?;
+    let files_dir = archive_files_dir(root);
+    let mut manifest = load_manifest(root)?;
+    let mut result = GamelogArchiveRepairResult::default();
+    let mut renamed = Vec::new();
+
+    for issue in &report.issues {
+        let Some(action) = issue.repair else {
+            result.unrepairable += 1;
+            continue;
+        };
+        let Some(index) = manifest
+            .entries
+            .iter()
+            .position(|e| e.is_active() && e.archive_name == issue.archive_name)
+        else {
+            continue;
+        };
+        match action {
+            ArchiveRepairAction::RecopyFromSource => {
+                let entry = &mut manifest.entries[index];
+                let before = files_dir.join(&entry.archive_name);
+                match recopy_from_source(&files_dir, entry) {
+                    Ok(()) => {
+                        result.recopied += 1;
+                        let after = files_dir.join(&entry.archive_name);
+                        if after != before {
+                            renamed.push((before, after));
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("[gamelog_archive] recopie de {}: {e}", issue.archive_name);
+                        result.failed += 1;
+                    }
+                }
+            }
+            ArchiveRepairAction::DropEntry => {
+                // Revérifié sous verrou : la copie a pu réapparaître depuis le contrôle.
+                if files_dir.join(&issue.archive_name).is_file() {
+                    continue;
+                }
+                manifest.entries.remove(index);
+                result.dropped += 1;
+            }
+        }
+    }
+
+    manifest.schema_version = MANIFEST_SCHEMA_VERSION;
+    save_manifest(root, &manifest)?;
+    Ok((result, renamed))
+}
+
+/// Revérifie puis répare : le rapport client n'est pas réutilisé, l'état disque a pu changer.
+/// Détient le verrou de scan des statistiques : les chemins réparés sont reportés dans le cache.
+pub fn repair_archive_sync(
+    app: &AppHandle,
+    scan_state: &GameStatsScanState,
+) -> Result<GamelogArchiveRepairResult, String> {
+    scan_state
+        .try_begin(GameStatsScanKind::Sync)
+        .map_err(scan_busy_error)?;
+    let result = repair_archive(app);
+    scan_state.finish();
+    result
+}
+
+fn repair_archive(app: &AppHandle) -> Result<GamelogArchiveRepairResult, String> {
+    let root = ensure_archive_dir(app.path())?;
+    let report = verify_archive_root(&root, |progress| emit_verify_progress(app, progress))?;
+    emit_verify_progress(
+        app,
+        GamelogArchiveVerifyProgress {
+            phase: "repairing".to_string(),
+            files_done: report.checked,
+            files_total: report.checked,
+            current_file: None,
+            percent: 96,
+        },
+    );
+    let (result, renamed) = repair_archive_root(&root, &report)?;
+    for (from, to) in renamed {
+        if let Err(e) = rename_file_state(
+            app.path(),
+            &from.to_string_lossy(),
+            &to.to_string_lossy(),
+            file_mtime_ms(&to),
+        ) {
+            eprintln!("[gamelog_archive] cache stats après réparation: {e}");
+        }
+    }
+    emit_verify_progress(
+        app,
+        GamelogArchiveVerifyProgress {
+            phase: "done".to_string(),
+            files_done: report.checked,
+            files_total: report.checked,
+            current_file: None,
+            percent: 100,
+        },
+    );
+    Ok(result)
+}
+
+#[command]
+pub async fn verify_gamelog_archive(app: AppHandle) -> Result<GamelogArchiveVerifyReport, String> {
+    tokio::task::spawn_blocking(move || verify_archive_sync(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[command]
+pub async fn repair_gamelog_archive(
+    app: AppHandle,
+    scan_state: State<'_, GameStatsScanState>,
+) -> Result<GamelogArchiveRepairResult, String> {
+    let scan_state = scan_state.inner().clone();
+    tokio::task::spawn_blocking(move || repair_archive_sync(&app, &scan_state))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scripts::gamelog_archive::test_support::archive_log;
+    use crate::scripts::gamelog_archive::ArchiveManifest;
+
+    #[test]
+    fn verify_and_repair_detect_each_issue_kind() {
+        let root = std::env::temp_dir().join(format!("gamelog_verify_test_{}", std::process::id()));
+        let files_dir = archive_files_dir(&root);
+        fs::create_dir_all(&files_dir).unwrap();
+        let content = "<2026-05-16T11:28:35.195Z> line\n".repeat(50);
+
+        let entries = vec![
+            archive_log(&root, "ok", &content, false),
+            archive_log(&root, "lost_with_source", &content, true),
+            archive_log(&root, "lost", &content, false),
+            archive_log(&root, "rotten", &content, false),
+        ];
+        fs::remove_file(files_dir.join("lost_with_source.log.gz")).unwrap();
+        fs::remove_file(files_dir.join("lost.log.gz")).unwrap();
+        fs::write(files_dir.join("rotten.log.gz"), b"not gzip").unwrap();
+        fs::write(files_dir.join("stray.log.gz"), b"stray").unwrap();
+        save_manifest(
+            &root,
+            &ArchiveManifest {
+                schema_version: MANIFEST_SCHEMA_VERSION,
+                entries,
+            },
+        )
+        .unwrap();
+
+        let report = verify_archive_root(&root, |_| {}).unwrap();
+        assert_eq!(
+            (report.ok, report.missing, report.corrupted, report.orphaned),
+            (1, 2, 1, 1)
+        );
+        let repairs: Vec<(&str, Option<ArchiveRepairAction>)> = report
+            .issues
+            .iter()
+            .map(|i| (i.archive_name.as_str(), i.repair))
+            .collect();
+        assert_eq!(
+            repairs,
+            vec![
+                (
+                    "lost_with_source.log.gz",
+                    Some(ArchiveRepairAction::RecopyFromSource)
+                ),
+                ("lost.log.gz", Some(ArchiveRepairAction::DropEntry)),
+                ("rotten.log.gz", None),
+                ("stray.log.gz", None),
+            ]
+        );
+
+        let (result, _) = repair_archive_root(&root, &report).unwrap();
+        assert_eq!(
+            (result.recopied, result.dropped, result.unrepairable),
+            (1, 1, 2)
+        );
+        let after = verify_archive_root(&root, |_| {}).unwrap();
+        assert_eq!((after.ok, after.missing, after.corrupted), (2, 0, 1));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
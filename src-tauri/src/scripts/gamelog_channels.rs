@@ -0,0 +1,199 @@
+//! Canaux Star Citizen suivis par le gamelog watcher et l'archive des logbackups
+//! (LIVE, HOTFIX, PTU, EPTU, TECH-PREVIEW…).
+//!
+//! Par défaut, tout canal détecté via le launcher RSI est suivi ; la config ne stocke que
+//! les drapeaux explicitement posés par l'utilisateur.
+
+use crate::scripts::gamelog_watcher::{apply_gamelog_channel_selection, GamelogWatcherState};
+use crate::scripts::gamepath::get_star_citizen_versions_sync;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use tauri::path::PathResolver;
+use tauri::{command, AppHandle, Manager, Runtime, State};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogChannelSetting {
+    pub channel: String,
+    #[serde(default = "default_channel_enabled")]
+    pub enabled: bool,
+}
+
+fn default_channel_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogChannelsConfig {
+    #[serde(default)]
+    pub channels: Vec<GamelogChannelSetting>,
+}
+
+impl GamelogChannelsConfig {
+    /// Un canal sans réglage explicite est suivi.
+    pub fn is_enabled(&self, channel: &str) -> bool {
+        self.channels
+            .iter()
+            .find(|c| c.channel.eq_ignore_ascii_case(channel))
+            .map(|c| c.enabled)
+            .unwrap_or_else(default_channel_enabled)
+    }
+
+    pub fn set_enabled(&mut self, channel: &str, enabled: bool) {
+        match self
+            .channels
+            .iter_mut()
+            .find(|c| c.channel.eq_ignore_ascii_case(channel))
+        {
+            Some(setting) => setting.enabled = enabled,
+            None => self.channels.push(GamelogChannelSetting {
+                channel: channel.to_string(),
+                enabled,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogChannelInfo {
+    pub channel: String,
+    pub install_path: Option<String>,
+    /// Installation détectée (launcher RSI, dossiers usuels, préfixe Wine ou chemin manuel).
+    pub detected: bool,
+    pub enabled: bool,
+}
+
+fn config_path(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+    let dir = resolver
+        .app_config_dir()
+        .map_err(|_| "Impossible d'obtenir le répertoire de configuration".to_string())?;
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir.join("gamelog_channels.json"))
+}
+
+pub fn load_channels_config_sync(app: &AppHandle) -> Result<GamelogChannelsConfig, String> {
+    let path = config_path(app.path())?;
+    if !path.exists() {
+        return Ok(GamelogChannelsConfig::default());
+    }
+    let json = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+pub fn save_channels_config_sync(
+    app: &AppHandle,
+    config: &GamelogChannelsConfig,
+) -> Result<(), String> {
+    let path = config_path(app.path())?;
+    let json = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+/// LIVE d'abord, puis ordre alphabétique : le premier canal sert de référence à l'affichage.
+fn channel_sort_key(channel: &str) -> (bool, String) {
+    (
+        !channel.eq_ignore_ascii_case("LIVE"),
+        channel.to_ascii_uppercase(),
+    )
+}
+
+/// Tous les canaux installés détectés via le launcher RSI.
+pub fn detected_game_channels() -> Vec<(String, PathBuf)> {
+    let versions = get_star_citizen_versions_sync();
+    let mut out: Vec<(String, PathBuf)> = versions
+        .versions
+        .into_iter()
+        .filter(|(key, _)| key != "UNKNOWN")
+        .map(|(key, info)| (key, PathBuf::from(info.path)))
+        .collect();
+    out.sort_by_key(|(channel, _)| channel_sort_key(channel));
+    out
+}
+
+/// Canaux détectés et activés : ceux que le watcher suit et que l'archive synchronise.
+pub fn watched_game_channels(app: &AppHandle) -> Vec<(String, PathBuf)> {
+    let config = load_channels_config_sync(app).unwrap_or_default();
+    detected_game_channels()
+        .into_iter()
+        .filter(|(channel, _)| config.is_enabled(channel))
+        .collect()
+}
+
+pub fn list_gamelog_channels_sync(app: &AppHandle) -> Result<Vec<GamelogChannelInfo>, String> {
+    let config = load_channels_config_sync(app)?;
+    let mut out: Vec<GamelogChannelInfo> = detected_game_channels()
+        .into_iter()
+        .map(|(channel, install)| GamelogChannelInfo {
+            enabled: config.is_enabled(&channel),
+            install_path: Some(install.display().to_string()),
+            detected: true,
+            channel,
+        })
+        .collect();
+    // Réglages conservés pour un canal désinstallé (ex. PTU entre deux vagues de test).
+    for setting in &config.channels {
+        if !out
+            .iter()
+            .any(|c| c.channel.eq_ignore_ascii_case(&setting.channel))
+        {
+            out.push(GamelogChannelInfo {
+                channel: setting.channel.clone(),
+                install_path: None,
+                detected: false,
+                enabled: setting.enabled,
+            });
+        }
+    }
+    out.sort_by_key(|c| channel_sort_key(&c.channel));
+    Ok(out)
+}
+
+#[command]
+pub async fn get_gamelog_channels(app: AppHandle) -> Result<Vec<GamelogChannelInfo>, String> {
+    tokio::task::spawn_blocking(move || list_gamelog_channels_sync(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Active/désactive un canal ; le watcher en cours démarre ou arrête seulement ce canal.
+#[command]
+pub async fn set_gamelog_channel_enabled(
+    app: AppHandle,
+    state: State<'_, GamelogWatcherState>,
+    channel: String,
+    enabled: bool,
+) -> Result<Vec<GamelogChannelInfo>, String> {
+    let watcher = state.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        let mut config = load_channels_config_sync(&app)?;
+        config.set_enabled(&channel, enabled);
+        save_channels_config_sync(&app, &config)?;
+
+        apply_gamelog_channel_selection(&watcher, &app)?;
+        list_gamelog_channels_sync(&app)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channels_default_to_enabled_until_toggled() {
+        let mut config = GamelogChannelsConfig::default();
+        assert!(config.is_enabled("PTU"));
+        config.set_enabled("PTU", false);
+        assert!(!config.is_enabled("ptu"));
+        assert!(config.is_enabled("LIVE"));
+        config.set_enabled("ptu", true);
+        assert_eq!(config.channels.len(), 1);
+        assert!(config.is_enabled("PTU"));
+    }
+}
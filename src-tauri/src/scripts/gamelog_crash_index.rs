@@ -0,0 +1,279 @@
+//! Index des rapports de crash archivés (`Crashes/`, cf. `gamelog_archive`) : un rapport par
+//! dossier de crash, regroupé par jour, build et signature de crash.
+
+use crate::scripts::gamelog_archive::{
+    archive_files_dir, ensure_archive_dir, load_manifest, ArchiveEntryKind, ArchiveManifestEntry,
+};
+use chrono::{DateTime, NaiveDateTime};
+use flate2::read::GzDecoder;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{BufReader, Read};
+use std::path::Path;
+use std::sync::OnceLock;
+use tauri::{command, AppHandle, Manager};
+
+/// Au-delà, le fichier est un dump binaire : il n'est pas lu pour l'index.
+const MAX_TEXT_BYTES: u64 = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashReportSummary {
+    pub crash_id: String,
+    pub channel: String,
+    /// Jour du crash (`AAAA-MM-JJ`, UTC).
+    pub date: String,
+    pub occurred_at_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    pub archive_names: Vec<String>,
+    pub total_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashIndexGroup {
+    pub date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    pub count: u32,
+    pub crash_ids: Vec<String>,
+    pub last_occurred_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashIndex {
+    pub reports: Vec<CrashReportSummary>,
+    pub groups: Vec<CrashIndexGroup>,
+}
+
+fn branch_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)\bbranch\s*[:=]\s*([\w.\-]+)").unwrap())
+}
+
+fn changelist_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)\bchangelist\s*[:=]\s*(\d+)").unwrap())
+}
+
+/// Motifs de signature, du plus précis au plus générique.
+fn signature_regexes() -> &'static [Regex] {
+    static RE: OnceLock<Vec<Regex>> = OnceLock::new();
+    RE.get_or_init(|| {
+        [
+            r#"(?i)"?crash_?reason"?\s*[:=]\s*"?([^"\r\n,]+)"#,
+            r"(?i)exception\s*code\s*[:=]\s*(0x[0-9a-f]+)",
+            r"(?i)faulting\s+module[^:]*:\s*([^\s,]+)",
+            r"(?i)unhandled\s+exception[^:]*:\s*([^\r\n]+)",
+            r"(?i)<crash>\s*([^\r\n]+)",
+            r"(?i)\bfatal\s+error\s*[:\-]?\s*([^\r\n]+)",
+        ]
+        .iter()
+        .map(|p| Regex::new(p).unwrap())
+        .collect()
+    })
+}
+
+fn volatile_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"0x[0-9A-Fa-f]{9,}|\b\d{5,}\b").unwrap())
+}
+
+/// Retire ce qui varie d'un crash à l'autre (adresses, compteurs) pour regrouper.
+fn normalize_signature(raw: &str) -> String {
+    let trimmed = raw.trim().trim_matches('"').trim();
+    let normalized = volatile_regex().replace_all(trimmed, "#");
+    normalized.chars().take(160).collect()
+}
+
+/// Build (`branche (changelist)`) et signature extraits du texte d'un rapport.
+fn extract_build_and_signature(text: &str) -> (Option<String>, Option<String>) {
+    let branch = branch_regex().captures(text).map(|c| c[1].to_string());
+    let changelist = changelist_regex().captures(text).map(|c| c[1].to_string());
+    let build = match (branch, changelist) {
+        (Some(b), Some(c)) => Some(format!("{b} ({c})")),
+        (Some(b), None) => Some(b),
+        (None, Some(c)) => Some(c),
+        (None, None) => None,
+    };
+    let signature = signature_regexes()
+        .iter()
+        .find_map(|re| re.captures(text))
+        .map(|c| normalize_signature(&c[1]))
+        .filter(|s| !s.is_empty());
+    (build, signature)
+}
+
+/// Date encodée dans le nom du dossier de crash (`2025-03-14-21-05-33`, `2025-03-14_…`).
+fn date_from_crash_id(crash_id: &str) -> Option<i64> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| {
+        Regex::new(r"(\d{4})-(\d{2})-(\d{2})(?:[-_T ](\d{2})[-_:.](\d{2})[-_:.](\d{2}))?").unwrap()
+    });
+    let caps = re.captures(crash_id)?;
+    let time = match (caps.get(4), caps.get(5), caps.get(6)) {
+        (Some(h), Some(m), Some(s)) => format!("{}:{}:{}", h.as_str(), m.as_str(), s.as_str()),
+        _ => "00:00:00".to_string(),
+    };
+    let raw = format!("{}-{}-{} {time}", &caps[1], &caps[2], &caps[3]);
+    NaiveDateTime::parse_from_str(&raw, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|dt| dt.and_utc().timestamp_millis())
+}
+
+fn read_archived_text(path: &Path) -> Option<String> {
+    let file = fs::File::open(path).ok()?;
+    let mut bytes = Vec::new();
+    GzDecoder::new(BufReader::new(file))
+        .take(MAX_TEXT_BYTES)
+        .read_to_end(&mut bytes)
+        .ok()?;
+    // Dump binaire : trop d'octets nuls pour être du texte.
+    if bytes.iter().take(4096).filter(|b| **b == 0).count() > 16 {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn summarize_report(
+    files_dir: &Path,
+    crash_id: &str,
+    entries: &[&ArchiveManifestEntry],
+) -> CrashReportSummary {
+    let occurred_at_ms = date_from_crash_id(crash_id)
+        .map(|ms| ms as u64)
+        .unwrap_or_else(|| entries.iter().map(|e| e.source_mtime_ms).min().unwrap_or(0));
+    let date = DateTime::from_timestamp_millis(occurred_at_ms as i64)
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_default();
+
+    let mut build = None;
+    let mut signature = None;
+    for entry in entries.iter().filter(|e| e.size <= MAX_TEXT_BYTES) {
+        let Some(text) = read_archived_text(&files_dir.join(&entry.archive_name)) else {
+            continue;
+        };
+        let (found_build, found_signature) = extract_build_and_signature(&text);
+        build = build.or(found_build);
+        signature = signature.or(found_signature);
+        if build.is_some() && signature.is_some() {
+            break;
+        }
+    }
+
+    CrashReportSummary {
+        crash_id: crash_id.to_string(),
+        channel: entries
+            .first()
+            .map(|e| e.channel.clone())
+            .unwrap_or_default(),
+        date,
+        occurred_at_ms,
+        build,
+        signature,
+        archive_names: entries.iter().map(|e| e.archive_name.clone()).collect(),
+        total_bytes: entries.iter().map(|e| e.size).sum(),
+    }
+}
+
+fn group_reports(reports: &[CrashReportSummary]) -> Vec<CrashIndexGroup> {
+    let mut groups: BTreeMap<(String, Option<String>, Option<String>), CrashIndexGroup> =
+        BTreeMap::new();
+    for report in reports {
+        let key = (
+            report.date.clone(),
+            report.build.clone(),
+            report.signature.clone(),
+        );
+        let group = groups.entry(key).or_insert_with(|| CrashIndexGroup {
+            date: report.date.clone(),
+            build: report.build.clone(),
+            signature: report.signature.clone(),
+            count: 0,
+            crash_ids: Vec::new(),
+            last_occurred_at_ms: 0,
+        });
+        group.count += 1;
+        group.crash_ids.push(report.crash_id.clone());
+        group.last_occurred_at_ms = group.last_occurred_at_ms.max(report.occurred_at_ms);
+    }
+    let mut out: Vec<CrashIndexGroup> = groups.into_values().collect();
+    out.sort_by(|a, b| {
+        b.last_occurred_at_ms
+            .cmp(&a.last_occurred_at_ms)
+            .then_with(|| b.count.cmp(&a.count))
+    });
+    out
+}
+
+pub fn get_crash_index_sync(app: &AppHandle) -> Result<CrashIndex, String> {
+    let root = ensure_archive_dir(app.path())?;
+    let files_dir = archive_files_dir(&root);
+    let manifest = load_manifest(&root)?;
+
+    let mut by_crash: BTreeMap<(String, String), Vec<&ArchiveManifestEntry>> = BTreeMap::new();
+    for entry in manifest
+        .entries
+        .iter()
+        .filter(|e| e.is_active() && e.kind == ArchiveEntryKind::CrashReport)
+    {
+        let crash_id = entry.crash_id.clone().unwrap_or_else(|| entry.id.clone());
+        by_crash
+            .entry((entry.channel.clone(), crash_id))
+            .or_default()
+            .push(entry);
+    }
+
+    let mut reports: Vec<CrashReportSummary> = by_crash
+        .iter()
+        .map(|((_, crash_id), entries)| summarize_report(&files_dir, crash_id, entries))
+        .collect();
+    reports.sort_by_key(|r| std::cmp::Reverse(r.occurred_at_ms));
+    let groups = group_reports(&reports);
+    Ok(CrashIndex { reports, groups })
+}
+
+#[command]
+pub async fn get_gamelog_crash_index(app: AppHandle) -> Result<CrashIndex, String> {
+    tokio::task::spawn_blocking(move || get_crash_index_sync(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_build_signature_and_date() {
+        let text = "Log started\nBranch: sc-alpha-4.1.0\nChangelist: 9612345\n\
+                    <2025-03-14T21:05:33.000Z> Unhandled exception at 0x00007FF6A1B2C3D4: \
+                    access violation\n";
+        let (build, signature) = extract_build_and_signature(text);
+        assert_eq!(build.as_deref(), Some("sc-alpha-4.1.0 (9612345)"));
+        assert_eq!(signature.as_deref(), Some("access violation"));
+
+        let json = r#"{"crash_reason": "EXCEPTION_ACCESS_VIOLATION_READ at 0x00007FF6A1B2C3D4"}"#;
+        assert_eq!(
+            extract_build_and_signature(json).1.as_deref(),
+            Some("EXCEPTION_ACCESS_VIOLATION_READ at #")
+        );
+
+        let ms = date_from_crash_id("2025-03-14-21-05-33").unwrap();
+        assert_eq!(
+            DateTime::from_timestamp_millis(ms)
+                .unwrap()
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            "2025-03-14 21:05"
+        );
+    }
+}
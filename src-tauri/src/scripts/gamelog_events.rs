@@ -0,0 +1,176 @@
+//! Événements typés reconnus par le gamelog watcher.
+//!
+//! Chaque événement est publié comme événement Tauri (`gamelog-watcher-event`) et sur un canal
+//! in-process : les autres modules Rust s'abonnent via [`subscribe_watcher_events`] au lieu de
+//! relire le Game.log. Les derniers événements restent consultables (diagnostic du watcher).
+
+use crate::scripts::gamelog_watcher::BlueprintEntry;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast;
+
+pub const GAMELOG_WATCHER_EVENT: &str = "gamelog-watcher-event";
+/// Un abonné trop lent perd les événements les plus anciens (`RecvError::Lagged`).
+const EVENT_BUS_CAPACITY: usize = 256;
+const RECENT_EVENTS_CAPACITY: usize = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum WatcherEventKind {
+    #[serde(rename_all = "camelCase")]
+    MissionAccepted {
+        mission_guid: String,
+        debug_name: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    MissionEnded {
+        mission_guid: String,
+        debug_name: Option<String>,
+        completion_type: String,
+        reason: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    BlueprintReceived { entry: BlueprintEntry },
+    #[serde(rename_all = "camelCase")]
+    Purchase {
+        shop_name: String,
+        item: String,
+        price: f64,
+        quantity: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    ShipBoarded { ship_name: String, ship_id: String },
+    #[serde(rename_all = "camelCase")]
+    SystemEntered {
+        system: String,
+        planet: Option<String>,
+        station: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    OwnerDetected { handle: String },
+    #[serde(rename_all = "camelCase")]
+    LogRotated { log_path: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatcherEvent {
+    /// Canal du Game.log source (LIVE, HOTFIX…).
+    pub channel: String,
+    pub ts: f64,
+    /// Événement rejoué depuis un log enregistré (cf. `gamelog_replay`), pas une partie en cours.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub replay: bool,
+    /// Relu depuis le contenu déjà présent au démarrage du tailer (rattrapage, pas du direct).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub backfill: bool,
+    #[serde(flatten)]
+    pub kind: WatcherEventKind,
+}
+
+static EVENT_BUS: Lazy<broadcast::Sender<WatcherEvent>> =
+    Lazy::new(|| broadcast::channel(EVENT_BUS_CAPACITY).0);
+
+static RECENT_EVENTS: Lazy<Mutex<VecDeque<WatcherEvent>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(RECENT_EVENTS_CAPACITY)));
+
+/// Abonnement in-process (utilisable en async via `recv().await` ou en thread via `blocking_recv`).
+pub fn subscribe_watcher_events() -> broadcast::Receiver<WatcherEvent> {
+    EVENT_BUS.subscribe()
+}
+
+/// Derniers événements publiés, du plus ancien au plus récent.
+pub fn recent_watcher_events() -> Vec<WatcherEvent> {
+    RECENT_EVENTS
+        .lock()
+        .map(|events| events.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Ajoute au tampon circulaire en évinçant le plus ancien une fois plein.
+fn push_recent(recent: &mut VecDeque<WatcherEvent>, event: WatcherEvent) {
+    while recent.len() >= RECENT_EVENTS_CAPACITY {
+        recent.pop_front();
+    }
+    recent.push_back(event);
+}
+
+pub fn publish_watcher_event(app: Option<&AppHandle>, event: WatcherEvent) {
+    if let Some(handle) = app {
+        let _ = handle.emit(GAMELOG_WATCHER_EVENT, &event);
+    }
+    if let Ok(mut recent) = RECENT_EVENTS.lock() {
+        push_recent(&mut recent, event.clone());
+    }
+    // `Err` uniquement quand personne n'écoute.
+    let _ = EVENT_BUS.send(event);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscribers_receive_published_events() {
+        let mut rx = subscribe_watcher_events();
+        publish_watcher_event(
+            None,
+            WatcherEvent {
+                channel: "PTU".to_string(),
+                ts: 1.0,
+                replay: false,
+                backfill: false,
+                kind: WatcherEventKind::OwnerDetected {
+                    handle: "Onivoid".to_string(),
+                },
+            },
+        );
+        // Bus global : d'autres tests en parallèle peuvent publier avant ou pendant celui-ci.
+        let event = std::iter::from_fn(|| loop {
+            match rx.try_recv() {
+                Ok(event) => return Some(event),
+                Err(broadcast::error::TryRecvError::Lagged(_)) => continue,
+                Err(_) => return None,
+            }
+        })
+        .find(|e| {
+            matches!(&e.kind, WatcherEventKind::OwnerDetected { handle } if handle == "Onivoid")
+        })
+        .expect("event on bus");
+        assert_eq!(event.channel, "PTU");
+        assert!(recent_watcher_events().iter().any(
+            |e| matches!(&e.kind, WatcherEventKind::OwnerDetected { handle } if handle == "Onivoid")
+        ));
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["kind"], "ownerDetected");
+        assert_eq!(json["handle"], "Onivoid");
+    }
+
+    #[test]
+    fn recent_events_ring_evicts_oldest_first() {
+        let mut recent = VecDeque::new();
+        for i in 0..RECENT_EVENTS_CAPACITY + 5 {
+            push_recent(
+                &mut recent,
+                WatcherEvent {
+                    channel: "LIVE".to_string(),
+                    ts: i as f64,
+                    replay: false,
+                    backfill: false,
+                    kind: WatcherEventKind::LogRotated {
+                        log_path: String::new(),
+                    },
+                },
+            );
+        }
+        assert_eq!(recent.len(), RECENT_EVENTS_CAPACITY);
+        assert_eq!(recent.front().unwrap().ts, 5.0);
+        assert_eq!(
+            recent.back().unwrap().ts,
+            (RECENT_EVENTS_CAPACITY + 4) as f64
+        );
+    }
+}
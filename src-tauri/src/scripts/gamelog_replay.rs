@@ -0,0 +1,430 @@
+//! Relecture d'un Game.log enregistré à travers le même pipeline que le tailer live
+//! (`process_line`, événements, extracteurs de stats), au rythme d'origine ou accéléré.
+//!
+//! Les événements sont publiés normalement mais marqués `replay` (canal `REPLAY`) ; les
+//! blueprints et les stats vont dans un bac à sable (`gamelog_replay/`) : le store réel et le
+//! cache de stats ne sont jamais modifiés.
+
+use crate::scripts::game_log::engine::build_snapshot;
+use crate::scripts::game_log::extractors::{
+    default_extractors, FileContext, GameLogStatExtractor, LineContext,
+};
+use crate::scripts::game_log::io::read_log_file_lossy;
+use crate::scripts::game_log::parse::{is_game_build_log, parse_log_timestamp};
+use crate::scripts::game_log::snapshot::GameStatsSnapshot;
+use crate::scripts::gamelog_events::{publish_watcher_event, WatcherEvent, WatcherEventKind};
+use crate::scripts::gamelog_watcher::{
+    ensure_config_dir, merge_blueprint_entries, process_line, BlueprintEntry, BlueprintStoreFile,
+    WatcherState,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use tauri::{command, AppHandle, Emitter, Manager, State};
+use tauri_plugin_dialog::DialogExt;
+
+const REPLAY_CHANNEL: &str = "REPLAY";
+pub const GAMELOG_REPLAY_PROGRESS_EVENT: &str = "gamelog-replay-progress";
+/// Les silences plus longs (menus, AFK) sont raccourcis à cette durée avant accélération.
+const DEFAULT_MAX_GAP_SEC: f64 = 10.0;
+/// Granularité des pauses : l'arrêt reste réactif même à vitesse réelle.
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+/// Vitesse positive minimale (ralenti ×100) : en dessous, la pause calculée n'a plus de sens.
+const MIN_REPLAY_SPEED: f64 = 0.01;
+/// Pause max entre deux lignes, quels que soient l'écart et la vitesse demandés.
+const MAX_REPLAY_DELAY: Duration = Duration::from_secs(10 * 60);
+const PROGRESS_EVERY_LINES: usize = 500;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogReplayStatus {
+    pub running: bool,
+    pub source: Option<String>,
+    /// 1 = horodatages d'origine, 10 = dix fois plus vite, 0 = sans pause.
+    pub speed: f64,
+    pub lines_done: usize,
+    pub lines_total: usize,
+    pub events_emitted: usize,
+    pub blueprints_found: usize,
+    /// Horodatage (log) de la dernière ligne rejouée.
+    pub current_ts: Option<f64>,
+    pub finished: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogReplayResults {
+    pub blueprints: BlueprintStoreFile,
+    pub stats: Option<GameStatsSnapshot>,
+}
+
+#[derive(Clone, Default)]
+pub struct GamelogReplayState {
+    status: Arc<Mutex<GamelogReplayStatus>>,
+    stop_flag: Arc<Mutex<Option<Arc<AtomicBool>>>>,
+    thread_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+/// Pipeline d'une relecture : état du watcher, extracteurs de stats et blueprints trouvés.
+struct ReplaySession {
+    state: WatcherState,
+    extractors: Vec<Box<dyn GameLogStatExtractor>>,
+    file_path: PathBuf,
+    is_game_build: bool,
+    blueprints: Vec<BlueprintEntry>,
+}
+
+impl ReplaySession {
+    fn new(file_path: &Path) -> Self {
+        Self {
+            state: WatcherState::for_replay(REPLAY_CHANNEL),
+            extractors: default_extractors(),
+            file_path: file_path.to_path_buf(),
+            is_game_build: is_game_build_log(file_path),
+            blueprints: Vec::new(),
+        }
+    }
+
+    fn feed_line(&mut self, line: &str) -> Vec<WatcherEvent> {
+        let ctx = LineContext {
+            file_path: self.file_path.to_string_lossy().into_owned(),
+            is_game_build: self.is_game_build,
+            line_ts: parse_log_timestamp(line),
+        };
+        for ext in self.extractors.iter_mut() {
+            ext.on_line(line, &ctx);
+        }
+        let events = process_line(line, &mut self.state);
+        for event in &events {
+            if let WatcherEventKind::BlueprintReceived { entry } = &event.kind {
+                self.blueprints.push(entry.clone());
+            }
+        }
+        events
+    }
+
+    fn finish(mut self) -> (Vec<BlueprintEntry>, GameStatsSnapshot) {
+        let ctx = FileContext {
+            file_path: self.file_path.to_string_lossy().into_owned(),
+            is_game_build: self.is_game_build,
+        };
+        for ext in self.extractors.iter_mut() {
+            ext.on_file_end(&self.file_path, &ctx);
+        }
+        let snapshot = build_snapshot(&self.extractors);
+        (self.blueprints, snapshot)
+    }
+}
+
+/// Pause avant une ligne : écart réel entre horodatages, plafonné puis divisé par la vitesse
+/// (`speed <= 0` : sans pause).
+fn replay_delay(prev_ts: Option<f64>, ts: Option<f64>, speed: f64, max_gap_sec: f64) -> Duration {
+    let (Some(prev), Some(ts)) = (prev_ts, ts) else {
+        return Duration::ZERO;
+    };
+    if speed <= 0.0 {
+        return Duration::ZERO;
+    }
+    let gap = (ts - prev).clamp(0.0, max_gap_sec.max(0.0));
+    Duration::try_from_secs_f64(gap / speed.max(MIN_REPLAY_SPEED))
+        .map_or(MAX_REPLAY_DELAY, |delay| delay.min(MAX_REPLAY_DELAY))
+}
+
+/// Attend `delay` par tranches ; `false` si l'arrêt a été demandé entre-temps.
+fn sleep_unless_stopped(delay: Duration, stop: &AtomicBool) -> bool {
+    let mut remaining = delay;
+    while !remaining.is_zero() {
+        if stop.load(Ordering::Relaxed) {
+            return false;
+        }
+        let step = remaining.min(STOP_CHECK_INTERVAL);
+        thread::sleep(step);
+        remaining -= step;
+    }
+    !stop.load(Ordering::Relaxed)
+}
+
+fn replay_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = ensure_config_dir(app.path())?.join("gamelog_replay");
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir)
+}
+
+fn save_replay_results(
+    app: &AppHandle,
+    blueprints: &[BlueprintEntry],
+    stats: &GameStatsSnapshot,
+) -> Result<(), String> {
+    let dir = replay_dir(app)?;
+    let (merged, _) = merge_blueprint_entries(&[], blueprints);
+    let store = BlueprintStoreFile {
+        blueprints: merged,
+        ..BlueprintStoreFile::default()
+    };
+    let json = serde_json::to_string_pretty(&store).map_err(|e| e.to_string())?;
+    fs::write(dir.join("blueprints.json"), json).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(stats).map_err(|e| e.to_string())?;
+    fs::write(dir.join("stats.json"), json).map_err(|e| e.to_string())
+}
+
+pub fn load_replay_results_sync(app: &AppHandle) -> Result<GamelogReplayResults, String> {
+    let dir = replay_dir(app)?;
+    let blueprints = fs::read_to_string(dir.join("blueprints.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    let stats = fs::read_to_string(dir.join("stats.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok());
+    Ok(GamelogReplayResults { blueprints, stats })
+}
+
+fn update_status(
+    app: &AppHandle,
+    status: &Mutex<GamelogReplayStatus>,
+    emit: bool,
+    f: impl FnOnce(&mut GamelogReplayStatus),
+) {
+    let Ok(mut guard) = status.lock() else {
+        return;
+    };
+    f(&mut guard);
+    if emit {
+        let _ = app.emit(GAMELOG_REPLAY_PROGRESS_EVENT, &*guard);
+    }
+}
+
+fn run_replay(
+    app: AppHandle,
+    path: PathBuf,
+    speed: f64,
+    max_gap_sec: f64,
+    stop: Arc<AtomicBool>,
+    status: Arc<Mutex<GamelogReplayStatus>>,
+) {
+    let content = match read_log_file_lossy(&path) {
+        Ok(c) => c,
+        Err(e) => {
+            update_status(&app, &status, true, |s| {
+                s.running = false;
+                s.finished = true;
+                s.error = Some(e);
+            });
+            return;
+        }
+    };
+    let lines: Vec<&str> = content.lines().filter(|l| !l.is_empty()).collect();
+    update_status(&app, &status, true, |s| s.lines_total = lines.len());
+
+    let mut session = ReplaySession::new(&path);
+    let mut prev_ts: Option<f64> = None;
+    for (index, line) in lines.iter().enumerate() {
+        let ts = parse_log_timestamp(line);
+        if !sleep_unless_stopped(replay_delay(prev_ts, ts, speed, max_gap_sec), &stop) {
+            break;
+        }
+        prev_ts = ts.or(prev_ts);
+
+        let events = session.feed_line(line);
+        let emitted = events.len();
+        for event in events {
+            publish_watcher_event(Some(&app), event);
+        }
+        let blueprints_found = session.blueprints.len();
+        let emit = emitted > 0 || (index + 1) % PROGRESS_EVERY_LINES == 0;
+        update_status(&app, &status, emit, |s| {
+            s.lines_done = index + 1;
+            s.events_emitted += emitted;
+            s.blueprints_found = blueprints_found;
+            if ts.is_some() {
+                s.current_ts = ts;
+            }
+        });
+    }
+
+    let (blueprints, stats) = session.finish();
+    let saved = save_replay_results(&app, &blueprints, &stats);
+    update_status(&app, &status, true, |s| {
+        s.running = false;
+        s.finished = true;
+        if let Err(e) = saved {
+            s.error = Some(e);
+        }
+    });
+}
+
+fn pick_replay_source(app: &AppHandle) -> Result<Option<PathBuf>, String> {
+    app.dialog()
+        .file()
+        .set_title("Rejouer un Game.log")
+        .add_filter("Logs Star Citizen", &["log"])
+        .blocking_pick_file()
+        .map(|p| p.into_path().map_err(|e| e.to_string()))
+        .transpose()
+}
+
+fn stop_replay_internal(state: &GamelogReplayState) -> Result<(), String> {
+    if let Some(stop) = state.stop_flag.lock().map_err(|e| e.to_string())?.take() {
+        stop.store(true, Ordering::Relaxed);
+    }
+    if let Some(handle) = state
+        .thread_handle
+        .lock()
+        .map_err(|e| e.to_string())?
+        .take()
+    {
+        let _ = handle.join();
+    }
+    Ok(())
+}
+
+pub fn start_replay_internal(
+    state: &GamelogReplayState,
+    app: AppHandle,
+    path: PathBuf,
+    speed: f64,
+    max_gap_sec: f64,
+) -> Result<GamelogReplayStatus, String> {
+    if !path.is_file() {
+        return Err(format!("Fichier introuvable : {}", path.display()));
+    }
+    // Une seule relecture à la fois : la précédente est interrompue.
+    stop_replay_internal(state)?;
+
+    let initial = GamelogReplayStatus {
+        running: true,
+        source: Some(path.display().to_string()),
+        speed,
+        ..GamelogReplayStatus::default()
+    };
+    *state.status.lock().map_err(|e| e.to_string())? = initial.clone();
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_worker = Arc::clone(&stop);
+    let status = Arc::clone(&state.status);
+    let handle = thread::Builder::new()
+        .name("gamelog-replay".into())
+        .spawn(move || run_replay(app, path, speed, max_gap_sec, stop_worker, status))
+        .map_err(|e| e.to_string())?;
+
+    *state.stop_flag.lock().map_err(|e| e.to_string())? = Some(stop);
+    *state.thread_handle.lock().map_err(|e| e.to_string())? = Some(handle);
+    Ok(initial)
+}
+
+/// Lance la relecture de `path` (ou du fichier choisi dans le dialogue). `None` si annulé.
+#[command]
+pub async fn start_gamelog_replay(
+    app: AppHandle,
+    state: State<'_, GamelogReplayState>,
+    path: Option<String>,
+    speed: Option<f64>,
+    max_gap_sec: Option<f64>,
+) -> Result<Option<GamelogReplayStatus>, String> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        let path = match path {
+            Some(p) => PathBuf::from(p),
+            None => match pick_replay_source(&app)? {
+                Some(p) => p,
+                None => return Ok(None),
+            },
+        };
+        start_replay_internal(
+            &state,
+            app,
+            path,
+            speed.unwrap_or(1.0).max(0.0),
+            max_gap_sec.unwrap_or(DEFAULT_MAX_GAP_SEC),
+        )
+        .map(Some)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[command]
+pub async fn stop_gamelog_replay(state: State<'_, GamelogReplayState>) -> Result<(), String> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || stop_replay_internal(&state))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[command]
+pub fn get_gamelog_replay_status(
+    state: State<'_, GamelogReplayState>,
+) -> Result<GamelogReplayStatus, String> {
+    state
+        .status
+        .lock()
+        .map(|s| s.clone())
+        .map_err(|e| e.to_string())
+}
+
+/// Blueprints et stats du bac à sable de la dernière relecture.
+#[command]
+pub fn load_gamelog_replay_results(app: AppHandle) -> Result<GamelogReplayResults, String> {
+    load_replay_results_sync(&app)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replay_delay_caps_gaps_and_applies_speed() {
+        assert_eq!(
+            replay_delay(Some(10.0), Some(12.0), 1.0, 10.0),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            replay_delay(Some(10.0), Some(500.0), 2.0, 10.0),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            replay_delay(Some(10.0), Some(12.0), 0.0, 10.0),
+            Duration::ZERO
+        );
+        assert_eq!(replay_delay(None, Some(12.0), 1.0, 10.0), Duration::ZERO);
+        // Vitesse infime ou écart démesuré : pause bornée, jamais de panique.
+        assert_eq!(
+            replay_delay(Some(10.0), Some(12.0), 1e-300, 10.0),
+            Duration::from_secs(200)
+        );
+        assert_eq!(
+            replay_delay(Some(0.0), Some(f64::MAX), 1.0, f64::INFINITY),
+            MAX_REPLAY_DELAY
+        );
+        assert_eq!(
+            replay_delay(Some(12.0), Some(10.0), 1.0, 10.0),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn replay_session_marks_events_and_collects_stats() {
+        let lines = [
+            r#"<2026-05-16T11:28:35.195Z> [Notice] <Legacy login response> [CIG-net] User Login Success - Handle[Onivoid] - Time[290632136]"#,
+            r#"<2026-05-16T11:56:52.000Z> Added notification "Received Blueprint: Morozov Legs: " [41] to queue"#,
+        ];
+        let mut session = ReplaySession::new(Path::new("Game.log"));
+        let events: Vec<WatcherEvent> = lines.iter().flat_map(|l| session.feed_line(l)).collect();
+        assert_eq!(events.len(), 2);
+        assert!(events
+            .iter()
+            .all(|e| e.replay && e.channel == REPLAY_CHANNEL));
+
+        let (blueprints, stats) = session.finish();
+        assert_eq!(blueprints.len(), 1);
+        assert_eq!(blueprints[0].owner, "Onivoid");
+        assert_eq!(stats.blueprints.total_unlocked, 1);
+    }
+}
@@ -0,0 +1,693 @@
+//! Recherche plein texte dans les logs de jeu : archive locale, `logbackups/` non encore
+//! archivés et `Game.log` de session courante.
+//!
+//! Index compact par fichier, stocké sous `<archive>/search_index/` : un filtre de Bloom des
+//! trigrammes du contenu, dimensionné selon leur nombre ([`BITS_PER_TRIGRAM`] bits chacun), et
+//! ses métadonnées (canal, handle, période). Seuls les fichiers modifiés sont réindexés ; une
+//! requête ne relit que les fichiers dont le filtre contient tous les trigrammes cherchés.
+
+use crate::scripts::game_log::io::{log_fingerprint, read_log_file_lossy};
+use crate::scripts::game_log::parse::parse_log_timestamp;
+use crate::scripts::gamelog_archive::{
+    ensure_archive_dir, file_mtime_ms, list_archived_log_files, list_live_session_game_logs,
+    list_unarchived_logbackup_files, verified_archive_source_paths,
+};
+use crate::scripts::gamelog_watcher::extract_log_owner;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{command, AppHandle, Manager};
+
+const INDEX_SCHEMA_VERSION: u32 = 2;
+/// ~12 bits par trigramme distinct et 3 sondes : moins de 1 % de faux positifs par trigramme.
+const BITS_PER_TRIGRAM: usize = 12;
+const FILTER_HASHES: u32 = 3;
+const MIN_FILTER_BITS: usize = 1 << 10;
+const MAX_FILTER_BITS: usize = 1 << 26;
+const DEFAULT_PAGE_SIZE: u32 = 50;
+const MAX_PAGE_SIZE: u32 = 500;
+const MAX_CONTEXT_LINES: u32 = 10;
+
+/// Une seule mise à jour d'index à la fois (recherches concurrentes depuis l'UI).
+static INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IndexedFile {
+    mtime_ms: u64,
+    size: u64,
+    channel: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    first_ts: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_ts: Option<f64>,
+    /// Nom du fichier bitmap dans `search_index/`.
+    bitmap: String,
+    /// Empreinte de tête d'un `Game.log` en cours : la session ne fait que grandir tant
+    /// qu'elle ne change pas.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    head: Option<String>,
+    /// Le fichier a grandi depuis l'indexation (`Game.log` en cours, réindexé par paliers) :
+    /// le filtre et `last_ts` ne couvrent que le début, il est relu sans préfiltre.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    partial: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchIndexFile {
+    schema_version: u32,
+    files: HashMap<String, IndexedFile>,
+}
+
+impl Default for SearchIndexFile {
+    fn default() -> Self {
+        Self {
+            schema_version: INDEX_SCHEMA_VERSION,
+            files: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogSearchQuery {
+    /// Termes séparés par des espaces : une ligne doit tous les contenir (casse ignorée).
+    pub query: String,
+    /// Bornes en secondes epoch (horodatage des lignes).
+    pub from_ts: Option<f64>,
+    pub to_ts: Option<f64>,
+    pub channel: Option<String>,
+    pub owner: Option<String>,
+    /// Reprise juste après ce résultat (`nextCursor` de la page précédente) : les fichiers et
+    /// lignes déjà parcourus ne sont pas relus. `offset` s'applique ensuite.
+    pub cursor: Option<GamelogSearchCursor>,
+    pub offset: Option<u32>,
+    pub limit: Option<u32>,
+    pub context_lines: Option<u32>,
+}
+
+/// Position d'un résultat, pour reprendre la recherche à la page suivante.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogSearchCursor {
+    pub file_path: String,
+    pub line_number: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogSearchHit {
+    pub file_path: String,
+    pub file_name: String,
+    pub channel: String,
+    /// Numéro de ligne (1-based).
+    pub line_number: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<f64>,
+    pub line: String,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogSearchResponse {
+    pub hits: Vec<GamelogSearchHit>,
+    pub offset: u32,
+    pub has_more: bool,
+    /// À renvoyer dans `cursor` pour la page suivante (présent si `has_more`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<GamelogSearchCursor>,
+    pub files_total: u32,
+    /// Fichiers relus après filtrage par l'index.
+    pub files_scanned: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamelogSearchIndexStatus {
+    pub files_indexed: u32,
+    pub files_reindexed: u32,
+    pub files_removed: u32,
+    pub index_directory: String,
+}
+
+/// Fichier candidat à l'indexation, quelle que soit sa source.
+struct SearchSource {
+    path: PathBuf,
+    channel: String,
+    /// `Game.log` de la session courante, encore en écriture.
+    growing: bool,
+}
+
+fn index_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = ensure_archive_dir(app.path())?.join("search_index");
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir)
+}
+
+fn index_manifest_path(dir: &Path) -> PathBuf {
+    dir.join("index.json")
+}
+
+fn load_index(dir: &Path) -> SearchIndexFile {
+    fs::read_to_string(index_manifest_path(dir))
+        .ok()
+        .and_then(|json| serde_json::from_str::<SearchIndexFile>(&json).ok())
+        .filter(|index| index.schema_version == INDEX_SCHEMA_VERSION)
+        .unwrap_or_default()
+}
+
+fn save_index(dir: &Path, index: &SearchIndexFile) -> Result<(), String> {
+    let json = serde_json::to_string(index).map_err(|e| e.to_string())?;
+    fs::write(index_manifest_path(dir), json).map_err(|e| e.to_string())
+}
+
+/// Trigrammes de `text`, casse ASCII ignorée.
+fn trigrams(text: &str) -> impl Iterator<Item = u32> + '_ {
+    text.as_bytes().windows(3).map(|w| {
+        (u32::from(w[0].to_ascii_lowercase()) << 16)
+            | (u32::from(w[1].to_ascii_lowercase()) << 8)
+            | u32::from(w[2].to_ascii_lowercase())
+    })
+}
+
+/// Positions d'un trigramme dans un filtre de `len_bits` bits (puissance de deux).
+fn filter_positions(trigram: u32, len_bits: usize) -> impl Iterator<Item = usize> {
+    let hash = u64::from(trigram).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    let (h1, h2) = (hash as u32, (hash >> 32) as u32 | 1);
+    (0..FILTER_HASHES).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) as usize & (len_bits - 1))
+}
+
+fn build_filter(content: &str) -> Vec<u8> {
+    let distinct: HashSet<u32> = content.lines().flat_map(trigrams).collect();
+    let len_bits = (distinct.len() * BITS_PER_TRIGRAM)
+        .next_power_of_two()
+        .clamp(MIN_FILTER_BITS, MAX_FILTER_BITS);
+    let mut filter = vec![0u8; len_bits / 8];
+    for trigram in distinct {
+        for bit in filter_positions(trigram, len_bits) {
+            filter[bit / 8] |= 1 << (bit % 8);
+        }
+    }
+    filter
+}
+
+fn filter_contains_all(filter: &[u8], trigrams: &[u32]) -> bool {
+    let len_bits = filter.len() * 8;
+    if !len_bits.is_power_of_two() {
+        return true;
+    }
+    trigrams.iter().all(|&trigram| {
+        filter_positions(trigram, len_bits).all(|bit| filter[bit / 8] & (1 << (bit % 8)) != 0)
+    })
+}
+
+fn bitmap_name(path_key: &str) -> String {
+    let digest = Sha256::digest(path_key.as_bytes());
+    let hex: String = digest[..8].iter().map(|b| format!("{b:02x}")).collect();
+    format!("{hex}.tri")
+}
+
+fn list_search_sources(app: &AppHandle) -> Result<Vec<SearchSource>, String> {
+    let archived_sources = verified_archive_source_paths(app)?;
+    let mut sources: Vec<SearchSource> = list_archived_log_files(app)?
+        .into_iter()
+        .chain(list_unarchived_logbackup_files(app, &archived_sources))
+        .map(|f| SearchSource {
+            path: f.path,
+            channel: f.channel,
+            growing: false,
+        })
+        .collect();
+    sources.extend(
+        list_live_session_game_logs(app)
+            .into_iter()
+            .map(|f| SearchSource {
+                path: f.path,
+                channel: f.channel,
+                growing: true,
+            }),
+    );
+    let mut seen = HashSet::new();
+    sources.retain(|s| seen.insert(s.path.to_string_lossy().to_ascii_lowercase()));
+    Ok(sources)
+}
+
+fn index_file(dir: &Path, source: &SearchSource, key: &str) -> Result<IndexedFile, String> {
+    let content = read_log_file_lossy(&source.path)?;
+    let bitmap = bitmap_name(key);
+    fs::write(dir.join(&bitmap), build_filter(&content)).map_err(|e| e.to_string())?;
+    let (first_ts, last_ts) = content.lines().filter_map(parse_log_timestamp).fold(
+        (None, None),
+        |(first, last): (Option<f64>, Option<f64>), ts| {
+            (
+                Some(first.map_or(ts, |f| f.min(ts))),
+                Some(last.map_or(ts, |l| l.max(ts))),
+            )
+        },
+    );
+    Ok(IndexedFile {
+        mtime_ms: file_mtime_ms(&source.path),
+        size: fs::metadata(&source.path).map(|m| m.len()).unwrap_or(0),
+        channel: source.channel.clone(),
+        owner: extract_log_owner(&content),
+        first_ts,
+        last_ts,
+        bitmap,
+        head: source.growing.then(|| growing_head(&source.path)).flatten(),
+        partial: false,
+    })
+}
+
+fn growing_head(path: &Path) -> Option<String> {
+    log_fingerprint(path).ok().and_then(|f| f.head)
+}
+
+/// Un `Game.log` en cours n'est réindexé qu'une fois grossi de moitié depuis la dernière
+/// indexation : sinon chaque recherche le relirait en entier pendant une session de jeu.
+fn defer_growing_reindex(entry: &IndexedFile, source: &SearchSource, size: u64) -> bool {
+    source.growing
+        && size > entry.size
+        && size - entry.size < entry.size / 2
+        && entry.head.is_some()
+        && entry.head == growing_head(&source.path)
+}
+
+/// Réindexe les fichiers nouveaux ou modifiés et oublie ceux qui ont disparu.
+fn update_index(
+    app: &AppHandle,
+    dir: &Path,
+    index: &mut SearchIndexFile,
+) -> Result<GamelogSearchIndexStatus, String> {
+    let sources = list_search_sources(app)?;
+    let mut status = GamelogSearchIndexStatus {
+        files_indexed: 0,
+        files_reindexed: 0,
+        files_removed: 0,
+        index_directory: dir.display().to_string(),
+    };
+
+    let live_keys: HashSet<String> = sources
+        .iter()
+        .map(|s| s.path.to_string_lossy().into_owned())
+        .collect();
+    let stale: Vec<String> = index
+        .files
+        .keys()
+        .filter(|key| !live_keys.contains(*key))
+        .cloned()
+        .collect();
+    for key in stale {
+        if let Some(entry) = index.files.remove(&key) {
+            let _ = fs::remove_file(dir.join(entry.bitmap));
+            status.files_removed += 1;
+        }
+    }
+
+    for source in &sources {
+        let key = source.path.to_string_lossy().into_owned();
+        let mtime_ms = file_mtime_ms(&source.path);
+        let size = fs::metadata(&source.path).map(|m| m.len()).unwrap_or(0);
+        if let Some(entry) = index.files.get_mut(&key) {
+            if entry.mtime_ms == mtime_ms && entry.size == size {
+                continue;
+            }
+            if defer_growing_reindex(entry, source, size) {
+                entry.partial = true;
+                continue;
+            }
+        }
+        match index_file(dir, source, &key) {
+            Ok(entry) => {
+                index.files.insert(key, entry);
+                status.files_reindexed += 1;
+            }
+            Err(e) => eprintln!(
+                "[gamelog_search] indexation de {}: {e}",
+                source.path.display()
+            ),
+        }
+    }
+    status.files_indexed = index.files.len() as u32;
+    save_index(dir, index)?;
+    Ok(status)
+}
+
+fn query_terms(query: &str) -> Vec<String> {
+    query
+        .split_whitespace()
+        .map(|term| term.to_ascii_lowercase())
+        .collect()
+}
+
+fn file_matches_filters(entry: &IndexedFile, query: &GamelogSearchQuery) -> bool {
+    if let Some(channel) = query.channel.as_deref().filter(|c| !c.is_empty()) {
+        if !entry.channel.eq_ignore_ascii_case(channel) {
+            return false;
+        }
+    }
+    if let Some(owner) = query.owner.as_deref().filter(|o| !o.is_empty()) {
+        if !entry
+            .owner
+            .as_deref()
+            .is_some_and(|o| o.eq_ignore_ascii_case(owner))
+        {
+            return false;
+        }
+    }
+    if let (Some(from), Some(last), false) = (query.from_ts, entry.last_ts, entry.partial) {
+        if last < from {
+            return false;
+        }
+    }
+    if let (Some(to), Some(first)) = (query.to_ts, entry.first_ts) {
+        if first > to {
+            return false;
+        }
+    }
+    true
+}
+
+/// Lignes correspondantes d'un contenu à partir de l'index `first_line` ; les lignes sans
+/// horodatage héritent du précédent.
+/// `on_hit(index, position dans la fenêtre, horodatage, fenêtre de contexte)` ; `false` arrête.
+fn search_content(
+    content: &str,
+    terms: &[String],
+    query: &GamelogSearchQuery,
+    context: usize,
+    first_line: usize,
+    mut on_hit: impl FnMut(usize, usize, Option<f64>, &[&str]) -> bool,
+) {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut current_ts = None;
+    for (index, line) in lines.iter().enumerate() {
+        if let Some(ts) = parse_log_timestamp(line) {
+            current_ts = Some(ts);
+        }
+        if index < first_line {
+            continue;
+        }
+        if query
+            .from_ts
+            .is_some_and(|from| current_ts.is_some_and(|ts| ts < from))
+            || query
+                .to_ts
+                .is_some_and(|to| current_ts.is_some_and(|ts| ts > to))
+        {
+            continue;
+        }
+        let lower = line.to_ascii_lowercase();
+        if !terms.iter().all(|term| lower.contains(term.as_str())) {
+            continue;
+        }
+        let start = index.saturating_sub(context);
+        let end = (index + context + 1).min(lines.len());
+        if !on_hit(index, index - start, current_ts, &lines[start..end]) {
+            return;
+        }
+    }
+}
+
+pub fn search_gamelogs_sync(
+    app: &AppHandle,
+    query: GamelogSearchQuery,
+) -> Result<GamelogSearchResponse, String> {
+    let terms = query_terms(&query.query);
+    if terms.is_empty() {
+        return Err("Requête vide.".to_string());
+    }
+    let offset = query.offset.unwrap_or(0);
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let context = query.context_lines.unwrap_or(2).min(MAX_CONTEXT_LINES) as usize;
+
+    let _guard = INDEX_LOCK.lock().map_err(|e| e.to_string())?;
+    let dir = index_dir(app)?;
+    let mut index = load_index(&dir);
+    update_index(app, &dir, &mut index)?;
+
+    let required: Vec<u32> = terms.iter().flat_map(|t| trigrams(t)).collect();
+    // Les plus récents d'abord : c'est en général ce que l'on débogue.
+    let mut candidates: Vec<(&String, &IndexedFile)> = index
+        .files
+        .iter()
+        .filter(|(_, entry)| file_matches_filters(entry, &query))
+        .collect();
+    candidates.sort_by(|a, b| {
+        b.1.last_ts
+            .unwrap_or(0.0)
+            .total_cmp(&a.1.last_ts.unwrap_or(0.0))
+            .then_with(|| a.0.cmp(b.0))
+    });
+
+    let mut response = GamelogSearchResponse {
+        hits: Vec::new(),
+        offset,
+        has_more: false,
+        next_cursor: None,
+        files_total: index.files.len() as u32,
+        files_scanned: 0,
+    };
+    let mut skipped = 0u32;
+    let mut resume = query.cursor.clone();
+    for (path_key, entry) in candidates {
+        let first_line = match resume.take() {
+            Some(cursor) if cursor.file_path == *path_key => cursor.line_number as usize,
+            Some(cursor) => {
+                resume = Some(cursor);
+                continue;
+            }
+            None => 0,
+        };
+        if !entry.partial {
+            let filter = fs::read(dir.join(&entry.bitmap)).unwrap_or_default();
+            if !filter.is_empty() && !filter_contains_all(&filter, &required) {
+                continue;
+            }
+        }
+        let path = PathBuf::from(path_key);
+        let Ok(content) = read_log_file_lossy(&path) else {
+            continue;
+        };
+        response.files_scanned += 1;
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        search_content(
+            &content,
+            &terms,
+            &query,
+            context,
+            first_line,
+            |index, at, ts, window| {
+                if skipped < offset {
+                    skipped += 1;
+                    return true;
+                }
+                if response.hits.len() as u32 >= limit {
+                    response.has_more = true;
+                    response.next_cursor = response.hits.last().map(|last| GamelogSearchCursor {
+                        file_path: last.file_path.clone(),
+                        line_number: last.line_number,
+                    });
+                    return false;
+                }
+                response.hits.push(GamelogSearchHit {
+                    file_path: path_key.clone(),
+                    file_name: file_name.clone(),
+                    channel: entry.channel.clone(),
+                    line_number: index as u32 + 1,
+                    timestamp: ts,
+                    line: window[at].to_string(),
+                    before: window[..at].iter().map(|l| l.to_string()).collect(),
+                    after: window[at + 1..].iter().map(|l| l.to_string()).collect(),
+                });
+                true
+            },
+        );
+        if response.has_more {
+            break;
+        }
+    }
+    Ok(response)
+}
+
+pub fn rebuild_search_index_sync(app: &AppHandle) -> Result<GamelogSearchIndexStatus, String> {
+    let _guard = INDEX_LOCK.lock().map_err(|e| e.to_string())?;
+    let dir = index_dir(app)?;
+    let _ = fs::remove_dir_all(&dir);
+    let dir = index_dir(app)?;
+    let mut index = SearchIndexFile::default();
+    update_index(app, &dir, &mut index)
+}
+
+#[command]
+pub async fn search_gamelogs(
+    app: AppHandle,
+    query: GamelogSearchQuery,
+) -> Result<GamelogSearchResponse, String> {
+    tokio::task::spawn_blocking(move || search_gamelogs_sync(&app, query))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[command]
+pub async fn rebuild_gamelog_search_index(
+    app: AppHandle,
+) -> Result<GamelogSearchIndexStatus, String> {
+    tokio::task::spawn_blocking(move || rebuild_search_index_sync(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn required(query: &str) -> Vec<u32> {
+        query_terms(query)
+            .iter()
+            .flat_map(|t| trigrams(t))
+            .collect()
+    }
+
+    #[test]
+    fn filter_grows_with_content_and_stays_selective() {
+        // ~4 Mo de lignes aux identifiants variés, comme un long Game.log.
+        let content: String = (0..60_000u32)
+            .map(|i| {
+                format!(
+                    "<2026-05-16T10:00:00.000Z> [Notice] <Entity> id[{:08x}] zone[{i}]\n",
+                    i.wrapping_mul(2_654_435_761)
+                )
+            })
+            .collect();
+        let filter = build_filter(&content);
+        assert!(filter.len() * 8 > MIN_FILTER_BITS);
+        assert!(filter_contains_all(&filter, &required("id[0000")));
+
+        let absent = (0..1_000)
+            .filter(|i| {
+                let term = format!("~{i:03}~");
+                filter_contains_all(&filter, &required(&term))
+            })
+            .count();
+        assert!(absent < 50, "{absent} faux positifs sur 1000");
+    }
+
+    #[test]
+    fn growing_game_log_is_reindexed_by_steps() {
+        let dir = std::env::temp_dir().join(format!("gamelog_search_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("Game.log");
+        let session = format!("<2026-05-16T10:00:00.000Z> start\n{}", "x".repeat(9_000));
+        fs::write(&path, &session).unwrap();
+        let source = SearchSource {
+            path: path.clone(),
+            channel: "LIVE".to_string(),
+            growing: true,
+        };
+        let entry = index_file(&dir, &source, "live").unwrap();
+        let size = |extra: usize| entry.size + extra as u64;
+
+        assert!(defer_growing_reindex(&entry, &source, size(1_000)));
+        assert!(!defer_growing_reindex(&entry, &source, size(6_000)));
+        // Nouvelle session plus longue : la tête a changé.
+        fs::write(
+            &path,
+            format!("<2026-05-17T10:00:00.000Z> other\n{session}"),
+        )
+        .unwrap();
+        assert!(!defer_growing_reindex(&entry, &source, size(1_000)));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn search_resumes_after_cursor_line() {
+        let content = "hit a\nmiss\nhit b\nhit c\n";
+        let query = GamelogSearchQuery {
+            query: "hit".to_string(),
+            ..GamelogSearchQuery::default()
+        };
+        let mut lines = Vec::new();
+        // Curseur sur la ligne 3 (1-based) : reprise à partir de la 4e.
+        search_content(
+            content,
+            &query_terms("hit"),
+            &query,
+            0,
+            3,
+            |index, _, _, _| {
+                lines.push(index + 1);
+                true
+            },
+        );
+        assert_eq!(lines, vec![4]);
+    }
+
+    #[test]
+    fn bitmap_prefilter_and_line_search() {
+        let content = "<2026-05-16T10:00:00.000Z> Loading zone Stanton\n\
+                       continuation without timestamp QuantumDrive\n\
+                       <2026-05-16T12:00:00.000Z> Received Blueprint: P4-AR\n";
+        let filter = build_filter(content);
+        assert!(filter.len() <= 256, "petit log, petit filtre");
+        assert!(filter_contains_all(&filter, &required("blueprint p4-ar")));
+        assert!(!filter_contains_all(&filter, &required("xyzzyq")));
+
+        let query = GamelogSearchQuery {
+            query: "quantumdrive".to_string(),
+            to_ts: Some(1_778_929_300.0),
+            ..GamelogSearchQuery::default()
+        };
+        let mut hits = Vec::new();
+        search_content(
+            content,
+            &query_terms(&query.query),
+            &query,
+            1,
+            0,
+            |_, at, ts, window| {
+                hits.push((window[at].to_string(), ts, window.len()));
+                true
+            },
+        );
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].0.contains("QuantumDrive"));
+        assert_eq!(hits[0].1, parse_log_timestamp(content));
+        assert_eq!(hits[0].2, 3);
+
+        let late = GamelogSearchQuery {
+            query: "blueprint".to_string(),
+            to_ts: Some(1_778_929_300.0),
+            ..GamelogSearchQuery::default()
+        };
+        let mut count = 0;
+        search_content(
+            content,
+            &query_terms(&late.query),
+            &late,
+            0,
+            0,
+            |_, _, _, _| {
+                count += 1;
+                true
+            },
+        );
+        assert_eq!(count, 0);
+    }
+}
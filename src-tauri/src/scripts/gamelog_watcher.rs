@@ -1,12 +1,28 @@
+use crate::scripts::blueprints_catalog::resolve_product_blueprint_id;
+use crate::scripts::blueprints_wiki_extended::{mission_drop_index, MissionDropIndex};
+use crate::scripts::game_log::catalog::list_game_log_files;
+use crate::scripts::game_log::io::read_log_file_lossy;
+use crate::scripts::game_log::locations::detect_location;
 use crate::scripts::game_log::parse::parse_log_timestamp;
-use crate::scripts::gamelog_archive::{list_archived_log_files, sync_logbackups_archive_sync};
-use crate::scripts::gamepath::get_star_citizen_versions_sync;
+use crate::scripts::game_log::patterns::{
+    normalize_shop_display_name, parse_piloting_grant, parse_shop_buy_request,
+    parse_shop_buy_response,
+};
+use crate::scripts::gamelog_archive::sync_logbackups_archive_sync;
+use crate::scripts::gamelog_channels::{
+    detected_game_channels, list_gamelog_channels_sync, load_channels_config_sync,
+    watched_game_channels, GamelogChannelInfo,
+};
+use crate::scripts::gamelog_events::{
+    publish_watcher_event, recent_watcher_events, WatcherEvent, WatcherEventKind,
+};
+use crate::scripts::log_change_notifier::LogChangeNotifier;
 use chrono::Utc;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs::{self, File};
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{BufRead, Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
@@ -18,6 +34,13 @@ use tauri::{command, AppHandle, Emitter, Manager, Runtime};
 use tauri_plugin_dialog::DialogExt;
 
 const BLUEPRINT_CORRELATION_WINDOW_SEC: f64 = 5.0;
+/// Fenêtre élargie, réservée aux missions dont le Wiki confirme qu'elles donnent le blueprint.
+const BLUEPRINT_CORRELATION_EXTENDED_WINDOW_SEC: f64 = 30.0;
+/// Nombre max de missions alternatives conservées sur une entrée.
+const MAX_CORRELATION_ALTERNATIVES: usize = 3;
+/// Confiance minimale par défaut pour l'agrégat missions → blueprints.
+const DEFAULT_DROP_MIN_CONFIDENCE: f64 = 0.5;
+/// Intervalle de la boucle de polling (repli quand les notifications sont indisponibles).
 const TAIL_POLL_INTERVAL: Duration = Duration::from_millis(200);
 const SCHEMA_VERSION: u32 = 3;
 /// Nombre max de lignes parcourues pour trouver le handle au login.
@@ -44,6 +67,46 @@ pub struct BlueprintEntry {
     /// Résolu via le catalogue (ex. `bp_craft_kbar_ballisticcannon_s2`) — absent du Game.log.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub catalog_blueprint_id: Option<String>,
+    /// Confiance (0–1) de la mission retenue ; absente pour les imports sans corrélation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_confidence: Option<f64>,
+    /// Autres missions plausibles, meilleur score d'abord.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub correlation_alternatives: Vec<CorrelationCandidate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CorrelationCandidate {
+    pub mission_guid: String,
+    pub mission_debug_name: String,
+    pub trigger: String,
+    /// Écart entre l'événement mission et la réception du blueprint.
+    pub delta_sec: f64,
+    pub score: f64,
+    /// `Some(true)` si le Wiki liste ce blueprint dans les récompenses de la mission,
+    /// `None` si la mission est inconnue du cache Wiki.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wiki_match: Option<bool>,
+}
+
+/// Produits obtenus par mission, agrégés depuis le store (corrélations assez sûres).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionBlueprintDrops {
+    pub mission_debug_name: String,
+    pub products: Vec<MissionDropProduct>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionDropProduct {
+    pub product_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_blueprint_id: Option<String>,
+    pub count: usize,
+    pub average_confidence: f64,
+    pub last_ts: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -72,12 +135,85 @@ pub struct GamelogWatcherConfig {
     pub enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveMissionInfo {
+    pub mission_guid: String,
+    pub debug_name: String,
+}
+
+/// État d'un thread de tail (un par canal), pour diagnostiquer un schéma non enregistré.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelTailerHealth {
+    pub channel: String,
+    pub log_path: String,
+    /// Octets du Game.log courant déjà lus.
+    pub offset: u64,
+    pub lines_processed: u64,
+    /// Horodatage (epoch s) de la dernière ligne datée lue.
+    pub last_line_ts: Option<f64>,
+    pub rotations: u32,
+    /// Dernière erreur, effacée dès qu'une lecture réussit à nouveau.
+    pub last_error: Option<String>,
+    pub owner: Option<String>,
+    pub active_missions: Vec<ActiveMissionInfo>,
+    /// `false` si le tailer tourne en polling (notifications indisponibles).
+    pub notifications: bool,
+}
+
+type TailerHealthRegistry = Arc<Mutex<HashMap<String, ChannelTailerHealth>>>;
+
+/// Vue du registre réservée au tailer d'un canal.
+struct TailerHealthHandle {
+    channel: String,
+    registry: TailerHealthRegistry,
+}
+
+impl TailerHealthHandle {
+    fn update(&self, f: impl FnOnce(&mut ChannelTailerHealth)) {
+        if let Ok(mut map) = self.registry.lock() {
+            if let Some(health) = map.get_mut(&self.channel) {
+                f(health);
+            }
+        }
+    }
+
+    fn record_error(&self, error: String) {
+        eprintln!("[gamelog_watcher] {}: {error}", self.channel);
+        self.update(|h| h.last_error = Some(error));
+    }
+
+    /// Lecture réussie : position, lignes lues et fin de l'état d'erreur.
+    fn record_progress(&self, offset: u64, lines_read: u64, last_line_ts: Option<f64>) {
+        self.update(|h| {
+            h.offset = offset;
+            h.lines_processed += lines_read;
+            if last_line_ts.is_some() {
+                h.last_line_ts = last_line_ts;
+            }
+            h.last_error = None;
+        });
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GamelogWatcherStatus {
     pub watching: bool,
     pub log_path: Option<String>,
     pub blueprint_count: usize,
+    /// Canaux effectivement suivis par le watcher en cours (vide s'il est arrêté).
+    #[serde(default)]
+    pub active_channels: Vec<String>,
+    #[serde(default)]
+    pub channels: Vec<GamelogChannelInfo>,
+    /// Un état par thread de tail actif.
+    #[serde(default)]
+    pub tailers: Vec<ChannelTailerHealth>,
+    /// Derniers événements reconnus, du plus ancien au plus récent.
+    #[serde(default)]
+    pub recent_events: Vec<WatcherEvent>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -112,18 +248,18 @@ pub struct BlueprintsImportProgress {
     pub percent: u8,
 }
 
-fn emit_import_progress(app: &AppHandle, progress: BlueprintsImportProgress) {
+pub(crate) fn emit_import_progress(app: &AppHandle, progress: BlueprintsImportProgress) {
     let _ = app.emit(BLUEPRINTS_IMPORT_PROGRESS_EVENT, &progress);
 }
 
-fn import_scan_percent(files_done: u32, files_total: u32) -> u8 {
+pub(crate) fn import_scan_percent(files_done: u32, files_total: u32) -> u8 {
     if files_total == 0 {
         return 90;
     }
     (5 + (files_done.saturating_mul(80) / files_total)).min(90) as u8
 }
 
-fn file_basename(path: &Path) -> Option<String> {
+pub(crate) fn file_basename(path: &Path) -> Option<String> {
     path.file_name().map(|s| s.to_string_lossy().into_owned())
 }
 
@@ -131,7 +267,7 @@ fn file_basename(path: &Path) -> Option<String> {
 // Config / store paths
 // ---------------------------------------------------------------------------
 
-fn ensure_config_dir(path: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+pub(crate) fn ensure_config_dir(path: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
     let config_dir = path
         .app_config_dir()
         .map_err(|_| "Impossible d'obtenir le répertoire de configuration".to_string())?;
@@ -225,7 +361,8 @@ pub fn merge_blueprint_entries(
     (merged, added)
 }
 
-/// Garde l’entrée la plus ancienne (ts) et fusionne `catalog_blueprint_id` si présent sur l’autre.
+/// Garde l’entrée la plus ancienne (ts) et fusionne `catalog_blueprint_id` si présent sur l’autre ;
+/// la corrélation mission de l'autre est reprise si la plus ancienne n'en a pas (import historique).
 fn merge_blueprint_entry_pair(
     current: &BlueprintEntry,
     incoming: &BlueprintEntry,
@@ -239,6 +376,13 @@ fn merge_blueprint_entry_pair(
     if merged.catalog_blueprint_id.is_none() {
         merged.catalog_blueprint_id = secondary.catalog_blueprint_id.clone();
     }
+    if merged.mission_guid.is_none() && secondary.mission_guid.is_some() {
+        merged.mission_guid = secondary.mission_guid.clone();
+        merged.mission_debug_name = secondary.mission_debug_name.clone();
+        merged.mission_trigger = secondary.mission_trigger.clone();
+        merged.correlation_confidence = secondary.correlation_confidence;
+        merged.correlation_alternatives = secondary.correlation_alternatives.clone();
+    }
     merged
 }
 
@@ -323,20 +467,92 @@ struct MissionLifecycleEvent {
     ts: f64,
 }
 
-struct WatcherState {
+/// Mission retenue pour un blueprint reçu, avec sa confiance et les autres candidates.
+#[derive(Default)]
+struct BlueprintCorrelation {
+    best: Option<CorrelationCandidate>,
+    confidence: Option<f64>,
+    alternatives: Vec<CorrelationCandidate>,
+}
+
+fn round2(v: f64) -> f64 {
+    (v * 100.0).round() / 100.0
+}
+
+/// Score d'une mission candidate : proximité temporelle × type d'événement × accord Wiki.
+fn correlation_score(delta: f64, trigger: &str, wiki_match: Option<bool>) -> f64 {
+    let time = if delta <= BLUEPRINT_CORRELATION_WINDOW_SEC {
+        1.0 - 0.5 * delta / BLUEPRINT_CORRELATION_WINDOW_SEC
+    } else {
+        0.4
+    };
+    let trigger = if trigger == "complete" { 1.0 } else { 0.7 };
+    let wiki = match wiki_match {
+        Some(true) => 1.0,
+        None => 0.5,
+        Some(false) => 0.1,
+    };
+    time * trigger * wiki
+}
+
+struct PendingPurchase {
+    shop_name: String,
+    item: String,
+    price: f64,
+    qty: u32,
+}
+
+/// Accès au catalogue pour les blueprints reçus ; remplacé par des données fixes en test.
+#[derive(Clone, Copy)]
+pub(crate) struct CatalogLookup {
+    resolve_blueprint_id: fn(&str) -> Option<String>,
+    mission_drops: fn() -> Arc<MissionDropIndex>,
+}
+
+impl Default for CatalogLookup {
+    fn default() -> Self {
+        Self {
+            resolve_blueprint_id: resolve_product_blueprint_id,
+            mission_drops: mission_drop_index,
+        }
+    }
+}
+
+pub(crate) struct WatcherState {
+    channel: String,
+    replay: bool,
+    /// Lignes en cours de rattrapage (cf. [`WatcherEvent::backfill`]).
+    backfill: bool,
     guid_map: HashMap<String, MissionEntry>,
     active: HashMap<String, ActiveMission>,
     recent_lifecycle: VecDeque<MissionLifecycleEvent>,
     owner: Option<String>,
+    pending_purchases: HashMap<(String, String), VecDeque<PendingPurchase>>,
+    current_system: Option<String>,
+    catalog: CatalogLookup,
 }
 
 impl WatcherState {
-    fn new() -> Self {
+    pub(crate) fn new(channel: &str) -> Self {
         Self {
+            channel: channel.to_string(),
+            replay: false,
+            backfill: false,
             guid_map: HashMap::new(),
             active: HashMap::new(),
             recent_lifecycle: VecDeque::with_capacity(32),
             owner: None,
+            pending_purchases: HashMap::new(),
+            current_system: None,
+            catalog: CatalogLookup::default(),
+        }
+    }
+
+    /// État dont les événements sont marqués `replay` (cf. `gamelog_replay`).
+    pub(crate) fn for_replay(channel: &str) -> Self {
+        Self {
+            replay: true,
+            ..Self::new(channel)
         }
     }
 
@@ -345,18 +561,48 @@ impl WatcherState {
         self.active.clear();
         self.recent_lifecycle.clear();
         self.owner = None;
+        self.pending_purchases.clear();
+        self.current_system = None;
+    }
+
+    fn active_missions(&self) -> Vec<ActiveMissionInfo> {
+        let mut missions: Vec<ActiveMissionInfo> = self
+            .active
+            .iter()
+            .map(|(guid, mission)| ActiveMissionInfo {
+                mission_guid: guid.clone(),
+                debug_name: mission.debug_name.clone(),
+            })
+            .collect();
+        missions.sort_by(|a, b| a.debug_name.cmp(&b.debug_name));
+        missions
     }
 
-    fn set_owner_from_content(&mut self, content: &str) {
-        if let Some(handle) = extract_log_owner(content) {
-            self.owner = Some(handle);
+    fn event(&self, ts: f64, kind: WatcherEventKind) -> WatcherEvent {
+        WatcherEvent {
+            channel: self.channel.clone(),
+            ts,
+            replay: self.replay,
+            backfill: self.backfill,
+            kind,
         }
     }
 
-    fn try_update_owner_from_line(&mut self, line: &str) {
-        if let Some(handle) = extract_log_owner_from_line(line) {
-            self.owner = Some(handle);
+    /// Retourne le handle s'il change (pour publier `OwnerDetected` une seule fois).
+    fn set_owner(&mut self, handle: String) -> Option<String> {
+        if self.owner.as_deref() == Some(handle.as_str()) {
+            return None;
         }
+        self.owner = Some(handle.clone());
+        Some(handle)
+    }
+
+    fn set_owner_from_content(&mut self, content: &str) -> Option<String> {
+        self.set_owner(extract_log_owner(content)?)
+    }
+
+    fn try_update_owner_from_line(&mut self, line: &str) -> Option<String> {
+        self.set_owner(extract_log_owner_from_line(line)?)
     }
 
     fn current_owner(&self) -> String {
@@ -374,7 +620,7 @@ impl WatcherState {
         }
     }
 
-    fn record_accepted(&mut self, guid: &str, ts: f64) -> Option<()> {
+    fn record_accepted(&mut self, guid: &str, ts: f64) -> Option<String> {
         let entry = self.guid_map.get(guid)?;
         let debug_name = entry.debug_name.clone();
         self.active.insert(
@@ -386,46 +632,154 @@ impl WatcherState {
         self.recent_lifecycle.push_back(MissionLifecycleEvent {
             trigger: "accept".to_string(),
             guid: guid.to_string(),
-            debug_name,
+            debug_name: debug_name.clone(),
             ts,
         });
         if self.recent_lifecycle.len() > 32 {
             self.recent_lifecycle.pop_front();
         }
-        Some(())
+        Some(debug_name)
     }
 
-    fn record_end(&mut self, guid: &str, completion: &str, ts: f64) {
+    fn record_end(&mut self, guid: &str, completion: &str, ts: f64) -> Option<String> {
         let active = self.active.remove(guid);
+        let debug_name = active
+            .as_ref()
+            .map(|a| a.debug_name.clone())
+            .or_else(|| self.guid_map.get(guid).map(|e| e.debug_name.clone()));
         if completion == "Complete" {
-            let debug_name = active
-                .as_ref()
-                .map(|a| a.debug_name.clone())
-                .or_else(|| self.guid_map.get(guid).map(|e| e.debug_name.clone()))
-                .unwrap_or_else(|| "?".to_string());
             self.recent_lifecycle.push_back(MissionLifecycleEvent {
                 trigger: "complete".to_string(),
                 guid: guid.to_string(),
-                debug_name,
+                debug_name: debug_name.clone().unwrap_or_else(|| "?".to_string()),
                 ts,
             });
             if self.recent_lifecycle.len() > 32 {
                 self.recent_lifecycle.pop_front();
             }
         }
+        debug_name
+    }
+
+    fn record_shop_request(&mut self, line: &str) -> bool {
+        let Some(req) = parse_shop_buy_request(line) else {
+            return false;
+        };
+        self.pending_purchases
+            .entry((req.shop_id, req.kiosk_id))
+            .or_default()
+            .push_back(PendingPurchase {
+                shop_name: req.shop_name,
+                item: req.item,
+                price: req.price,
+                qty: req.qty,
+            });
+        true
     }
 
-    fn correlate_blueprint(&self, ts: f64) -> Option<MissionLifecycleEvent> {
-        let mut best: Option<&MissionLifecycleEvent> = None;
-        let mut best_delta = BLUEPRINT_CORRELATION_WINDOW_SEC + 1.0;
+    /// Achat confirmé : réponse `RmShopFlowResponse` réussie appariée à la requête en attente.
+    fn record_shop_response(&mut self, line: &str) -> Option<WatcherEventKind> {
+        let resp = parse_shop_buy_response(line)?;
+        if !resp.success || !resp.is_buying {
+            return None;
+        }
+        let pending = self
+            .pending_purchases
+            .get_mut(&(resp.shop_id, resp.kiosk_id))?
+            .pop_front()?;
+        let shop = if resp.shop_name.is_empty() {
+            pending.shop_name
+        } else {
+            resp.shop_name
+        };
+        Some(WatcherEventKind::Purchase {
+            shop_name: normalize_shop_display_name(&shop),
+            item: pending.item,
+            price: pending.price,
+            quantity: pending.qty,
+        })
+    }
+
+    fn record_location(&mut self, line: &str) -> Option<WatcherEventKind> {
+        let loc = detect_location(line)?;
+        if self.current_system.as_deref() == Some(loc.system.as_str()) {
+            return None;
+        }
+        self.current_system = Some(loc.system.clone());
+        Some(WatcherEventKind::SystemEntered {
+            system: loc.system,
+            planet: loc.planet,
+            station: loc.station,
+        })
+    }
+
+    /// Classe les missions récentes pouvant expliquer le blueprint. Au-delà de la fenêtre de
+    /// 5 s, seules les missions confirmées par le Wiki restent candidates.
+    fn correlate_blueprint(
+        &self,
+        ts: f64,
+        product_name: &str,
+        blueprint_id: Option<&str>,
+        drops: &MissionDropIndex,
+    ) -> BlueprintCorrelation {
+        let mut by_guid: HashMap<&str, CorrelationCandidate> = HashMap::new();
         for e in &self.recent_lifecycle {
             let delta = ts - e.ts;
-            if (0.0..=BLUEPRINT_CORRELATION_WINDOW_SEC).contains(&delta) && delta < best_delta {
-                best = Some(e);
-                best_delta = delta;
+            if !(0.0..=BLUEPRINT_CORRELATION_EXTENDED_WINDOW_SEC).contains(&delta) {
+                continue;
+            }
+            let wiki_match = drops.mission_drops(&e.debug_name, product_name, blueprint_id);
+            if delta > BLUEPRINT_CORRELATION_WINDOW_SEC && wiki_match != Some(true) {
+                continue;
             }
+            let score = correlation_score(delta, &e.trigger, wiki_match);
+            // Accept + complete d'une même mission : un seul candidat, le meilleur.
+            if by_guid
+                .get(e.guid.as_str())
+                .is_some_and(|c| c.score >= score)
+            {
+                continue;
+            }
+            by_guid.insert(
+                e.guid.as_str(),
+                CorrelationCandidate {
+                    mission_guid: e.guid.clone(),
+                    mission_debug_name: e.debug_name.clone(),
+                    trigger: e.trigger.clone(),
+                    delta_sec: round2(delta),
+                    score: round2(score),
+                    wiki_match,
+                },
+            );
+        }
+        let mut candidates: Vec<CorrelationCandidate> = by_guid.into_values().collect();
+        candidates.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(
+                    a.delta_sec
+                        .partial_cmp(&b.delta_sec)
+                        .unwrap_or(std::cmp::Ordering::Equal),
+                )
+        });
+        if candidates.is_empty() {
+            return BlueprintCorrelation::default();
+        }
+        let total: f64 = candidates.iter().map(|c| c.score).sum();
+        let best = candidates.remove(0);
+        // Score absolu pondéré par la part du meilleur candidat face aux autres.
+        let confidence = if total > 0.0 {
+            round2(best.score * best.score / total)
+        } else {
+            0.0
+        };
+        candidates.truncate(MAX_CORRELATION_ALTERNATIVES);
+        BlueprintCorrelation {
+            best: Some(best),
+            confidence: Some(confidence),
+            alternatives: candidates,
         }
-        best.cloned()
     }
 }
 
@@ -511,77 +865,164 @@ pub fn extract_log_owner(content: &str) -> Option<String> {
     None
 }
 
-fn process_line(line: &str, state: &mut WatcherState) -> Option<BlueprintEntry> {
+/// Analyse une ligne et retourne les événements reconnus (souvent aucun, parfois deux).
+pub(crate) fn process_line(line: &str, state: &mut WatcherState) -> Vec<WatcherEvent> {
     let ts = parse_log_timestamp(line).unwrap_or_else(|| Utc::now().timestamp() as f64);
     let patterns = log_patterns();
+    let mut events = Vec::new();
 
-    state.try_update_owner_from_line(line);
+    if let Some(handle) = state.try_update_owner_from_line(line) {
+        events.push(state.event(ts, WatcherEventKind::OwnerDetected { handle }));
+    }
 
     if let Some(caps) = patterns.marker.captures(line) {
-        state.record_marker(
-            caps.get(1)?.as_str(),
-            caps.get(2)?.as_str(),
-            caps.get(3)?.as_str(),
-        );
-        return None;
+        if let (Some(guid), Some(generator), Some(contract)) =
+            (caps.get(1), caps.get(2), caps.get(3))
+        {
+            state.record_marker(guid.as_str(), generator.as_str(), contract.as_str());
+        }
+        return events;
     }
 
     if let Some(caps) = patterns.accepted.captures(line) {
-        state.record_accepted(caps.get(1)?.as_str(), ts);
-        return None;
+        if let Some(guid) = caps.get(1).map(|m| m.as_str()) {
+            if let Some(debug_name) = state.record_accepted(guid, ts) {
+                let kind = WatcherEventKind::MissionAccepted {
+                    mission_guid: guid.to_string(),
+                    debug_name,
+                };
+                events.push(state.event(ts, kind));
+            }
+        }
+        return events;
     }
 
     if let Some(caps) = patterns.end_mission.captures(line) {
-        state.record_end(caps.get(1)?.as_str(), caps.get(2)?.as_str(), ts);
-        return None;
+        if let (Some(guid), Some(completion)) = (caps.get(1), caps.get(2)) {
+            let debug_name = state.record_end(guid.as_str(), completion.as_str(), ts);
+            let kind = WatcherEventKind::MissionEnded {
+                mission_guid: guid.as_str().to_string(),
+                debug_name,
+                completion_type: completion.as_str().to_string(),
+                reason: caps.get(3).map(|m| m.as_str().to_string()),
+            };
+            events.push(state.event(ts, kind));
+        }
+        return events;
     }
 
     if let Some(product_name) = extract_blueprint_product_name(line, patterns) {
-        let corr = state.correlate_blueprint(ts);
-        return Some(BlueprintEntry {
+        let blueprint_id = (state.catalog.resolve_blueprint_id)(&product_name);
+        let drops = (state.catalog.mission_drops)();
+        let corr = state.correlate_blueprint(ts, &product_name, blueprint_id.as_deref(), &drops);
+        let best = corr.best.as_ref();
+        let entry = BlueprintEntry {
             owner: state.current_owner(),
+            mission_guid: best.map(|c| c.mission_guid.clone()),
+            mission_debug_name: best.map(|c| c.mission_debug_name.clone()),
+            mission_trigger: best.map(|c| c.trigger.clone()),
+            catalog_blueprint_id: blueprint_id,
+            correlation_confidence: corr.confidence,
+            correlation_alternatives: corr.alternatives,
             product_name,
             ts,
-            mission_guid: corr.as_ref().map(|c| c.guid.clone()),
-            mission_debug_name: corr.as_ref().map(|c| c.debug_name.clone()),
-            mission_trigger: corr.as_ref().map(|c| c.trigger.clone()),
-            catalog_blueprint_id: None,
-        });
+        };
+        events.push(state.event(ts, WatcherEventKind::BlueprintReceived { entry }));
+        return events;
     }
 
-    None
+    if state.record_shop_request(line) {
+        return events;
+    }
+    if let Some(kind) = state.record_shop_response(line) {
+        events.push(state.event(ts, kind));
+        return events;
+    }
+
+    if let Some(grant) = parse_piloting_grant(line) {
+        let kind = WatcherEventKind::ShipBoarded {
+            ship_name: grant.ship_name,
+            ship_id: grant.ship_id,
+        };
+        events.push(state.event(grant.ts, kind));
+        return events;
+    }
+
+    if let Some(kind) = state.record_location(line) {
+        events.push(state.event(ts, kind));
+    }
+
+    events
 }
 
-/// Lit un fichier log en UTF-8 permissif (évite les échecs silencieux sur octets invalides).
-fn read_log_file_lossy(path: &Path) -> Result<String, String> {
-    let bytes =
-        fs::read(path).map_err(|e| format!("Impossible de lire {}: {e}", path.display()))?;
-    Ok(String::from_utf8_lossy(&bytes).into_owned())
+/// Publie les événements d'une ligne ; les blueprints sont aussi persistés dans le store.
+fn dispatch_events(app: &AppHandle, events: Vec<WatcherEvent>) {
+    for event in events {
+        if let WatcherEventKind::BlueprintReceived { entry } = &event.kind {
+            if let Err(e) = append_blueprints(app, std::slice::from_ref(entry)) {
+                eprintln!("[gamelog_watcher] failed to save blueprint: {e}");
+            }
+        }
+        publish_watcher_event(Some(app), event);
+    }
 }
 
 /// Import historique : extrait tous les blueprints via le motif communautaire (sans corrélation mission).
-fn scan_file_for_blueprints(path: &Path) -> Result<Vec<BlueprintEntry>, String> {
+pub(crate) fn scan_file_for_blueprints(path: &Path) -> Result<Vec<BlueprintEntry>, String> {
+    let content = read_log_file_lossy(path)?;
+    Ok(scan_log_content_for_blueprints(&content))
+}
+
+/// Variante mémoire de [`scan_file_for_blueprints`].
+/// Le handle est celui détecté dans ce contenu : un log par joueur.
+pub(crate) fn scan_log_content_for_blueprints(content: &str) -> Vec<BlueprintEntry> {
+    scan_log_reader_for_blueprints(content.as_bytes()).unwrap_or_default()
+}
+
+/// Variante en flux (entrées de zip, imports externes) : seules les entrées trouvées sont
+/// gardées en mémoire, pas le log.
+pub(crate) fn scan_log_reader_for_blueprints(
+    mut reader: impl BufRead,
+) -> std::io::Result<Vec<BlueprintEntry>> {
     let patterns = log_patterns();
     let mut blueprints = Vec::new();
-    let content = read_log_file_lossy(path)?;
-    let owner = extract_log_owner(&content).unwrap_or_default();
-    for line in content.lines() {
+    let mut owner: Option<String> = None;
+    let mut raw = Vec::new();
+    let mut index = 0usize;
+    loop {
+        raw.clear();
+        if reader.read_until(b'\n', &mut raw)? == 0 {
+            break;
+        }
+        let text = String::from_utf8_lossy(&raw);
+        let line = text.trim_end_matches('\n').trim_end_matches('\r');
+        if owner.is_none() && index < OWNER_SCAN_MAX_LINES {
+            owner = extract_log_owner_from_line(line);
+        }
+        index += 1;
         if line.is_empty() {
             continue;
         }
         if let Some(product_name) = extract_blueprint_product_name(line, patterns) {
             let ts = parse_log_timestamp(line).unwrap_or(0.0);
             blueprints.push(BlueprintEntry {
-                owner: owner.clone(),
+                owner: String::new(),
                 product_name,
                 ts,
                 mission_guid: None,
                 mission_debug_name: None,
                 mission_trigger: None,
                 catalog_blueprint_id: None,
+                correlation_confidence: None,
+                correlation_alternatives: Vec::new(),
             });
         }
     }
+    // Le handle peut apparaître après les premiers blueprints.
+    let owner = owner.unwrap_or_default();
+    for entry in &mut blueprints {
+        entry.owner = owner.clone();
+    }
     Ok(blueprints)
 }
 
@@ -589,21 +1030,59 @@ fn scan_file_for_blueprints(path: &Path) -> Result<Vec<BlueprintEntry>, String>
 // Log tailer (blocking thread)
 // ---------------------------------------------------------------------------
 
-fn run_log_tailer(app: AppHandle, log_path: PathBuf, stop: Arc<AtomicBool>) {
-    let mut state = WatcherState::new();
+fn run_log_tailer(
+    app: AppHandle,
+    channel: String,
+    log_path: PathBuf,
+    stop: Arc<AtomicBool>,
+    registry: TailerHealthRegistry,
+) {
+    let mut state = WatcherState::new(&channel);
     let mut file: Option<File> = None;
     let mut last_size: u64 = 0;
+    let mut offset: u64 = 0;
     let mut buffer = Vec::new();
     let mut first_open = true;
+    // Contenu présent avant le démarrage du watcher : relu pour l'état, publié en `backfill`.
+    let mut pre_existing = log_path.is_file();
+    let mut catching_up = false;
+    // Notifications du système de fichiers ; polling si le volume ne les remonte pas.
+    let mut notifier = LogChangeNotifier::new(&log_path);
+    if notifier.is_polling() {
+        eprintln!(
+            "[gamelog_watcher] {} : notifications indisponibles, polling",
+            log_path.display()
+        );
+    }
+    if let Ok(mut map) = registry.lock() {
+        map.insert(
+            channel.clone(),
+            ChannelTailerHealth {
+                channel: channel.clone(),
+                log_path: log_path.to_string_lossy().into_owned(),
+                notifications: !notifier.is_polling(),
+                ..Default::default()
+            },
+        );
+    }
+    let health = TailerHealthHandle {
+        channel: channel.clone(),
+        registry,
+    };
 
     while !stop.load(Ordering::Relaxed) {
         let metadata = match fs::metadata(&log_path) {
             Ok(m) => m,
-            Err(_) => {
+            Err(e) => {
+                // Absent tant que le jeu n'a pas été lancé sur ce canal : pas une erreur.
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    health.record_error(format!("metadata: {e}"));
+                }
                 file = None;
                 last_size = 0;
                 buffer.clear();
-                thread::sleep(Duration::from_secs(1));
+                pre_existing = false;
+                notifier.wait(Duration::from_secs(1), &stop);
                 continue;
             }
         };
@@ -614,14 +1093,26 @@ fn run_log_tailer(app: AppHandle, log_path: PathBuf, stop: Arc<AtomicBool>) {
         if rotated {
             if file.is_some() {
                 state.reset();
+                health.update(|h| h.rotations += 1);
                 // Session précédente déplacée vers logbackups — archiver.
                 let app_sync = app.clone();
                 if let Err(e) = sync_logbackups_archive_sync(&app_sync) {
-                    eprintln!("[gamelog_watcher] archive sync after rotation: {e}");
+                    health.record_error(format!("archive sync after rotation: {e}"));
                 }
+                let kind = WatcherEventKind::LogRotated {
+                    log_path: log_path.to_string_lossy().into_owned(),
+                };
+                let now = Utc::now().timestamp() as f64;
+                dispatch_events(&app, vec![state.event(now, kind)]);
             }
+            catching_up = std::mem::take(&mut pre_existing);
+            state.backfill = catching_up;
             if let Ok(content) = read_log_file_lossy(&log_path) {
-                state.set_owner_from_content(&content);
+                if let Some(handle) = state.set_owner_from_content(&content) {
+                    let now = Utc::now().timestamp() as f64;
+                    let kind = WatcherEventKind::OwnerDetected { handle };
+                    dispatch_events(&app, vec![state.event(now, kind)]);
+                }
             }
             match File::open(&log_path) {
                 Ok(mut f) => {
@@ -630,37 +1121,49 @@ fn run_log_tailer(app: AppHandle, log_path: PathBuf, stop: Arc<AtomicBool>) {
                     }
                     file = Some(f);
                     last_size = 0;
+                    offset = 0;
                     buffer.clear();
                     first_open = false;
                 }
-                Err(_) => {
+                Err(e) => {
+                    health.record_error(format!("open: {e}"));
                     file = None;
-                    thread::sleep(Duration::from_secs(1));
+                    notifier.wait(Duration::from_secs(1), &stop);
                     continue;
                 }
             }
         }
 
         let Some(f) = file.as_mut() else {
-            thread::sleep(TAIL_POLL_INTERVAL);
+            notifier.wait(TAIL_POLL_INTERVAL, &stop);
             continue;
         };
 
         let mut chunk = Vec::new();
         match f.read_to_end(&mut chunk) {
             Ok(0) => {
-                thread::sleep(TAIL_POLL_INTERVAL);
+                catching_up = false;
+                notifier.wait(TAIL_POLL_INTERVAL, &stop);
                 continue;
             }
-            Ok(_) => {}
+            Ok(_) => {
+                // La lecture complète qui suit une ouverture ne doit rien à une notification.
+                if !rotated {
+                    notifier.record_data_read();
+                }
+            }
             Err(e) => {
-                eprintln!("[gamelog_watcher] read failed: {e}");
+                health.record_error(format!("read failed: {e}"));
                 thread::sleep(Duration::from_secs(1));
                 continue;
             }
         }
 
+        state.backfill = std::mem::take(&mut catching_up);
+        offset += chunk.len() as u64;
         buffer.extend_from_slice(&chunk);
+        let mut lines_read = 0u64;
+        let mut last_line_ts = None;
         while let Some(pos) = buffer.iter().rposition(|&b| b == b'\n') {
             let block = buffer.drain(..=pos).collect::<Vec<_>>();
             let text = String::from_utf8_lossy(&block);
@@ -668,14 +1171,22 @@ fn run_log_tailer(app: AppHandle, log_path: PathBuf, stop: Arc<AtomicBool>) {
                 if line.is_empty() {
                     continue;
                 }
-                if let Some(entry) = process_line(line, &mut state) {
-                    if let Err(e) = append_blueprints(&app, &[entry]) {
-                        eprintln!("[gamelog_watcher] failed to save blueprint: {e}");
-                    }
-                }
+                lines_read += 1;
+                dispatch_events(&app, process_line(line, &mut state));
             }
+            last_line_ts = text.lines().rev().find_map(parse_log_timestamp);
         }
         last_size = current_size;
+
+        let owner = state.owner.clone();
+        let active_missions = state.active_missions();
+        let notifications = !notifier.is_polling();
+        health.record_progress(offset, lines_read, last_line_ts);
+        health.update(|h| {
+            h.owner = owner;
+            h.active_missions = active_missions;
+            h.notifications = notifications;
+        });
     }
 }
 
@@ -683,80 +1194,88 @@ fn run_log_tailer(app: AppHandle, log_path: PathBuf, stop: Arc<AtomicBool>) {
 // Watcher service state
 // ---------------------------------------------------------------------------
 
+/// Thread de tail d'un canal.
+struct ChannelTailer {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
 #[derive(Clone)]
 pub struct GamelogWatcherState {
     pub is_running: Arc<Mutex<bool>>,
-    stop_flag: Arc<Mutex<Option<Arc<AtomicBool>>>>,
-    thread_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+    tailer_health: TailerHealthRegistry,
+    /// Un thread de tail par canal suivi par le watcher en cours.
+    tailers: Arc<Mutex<HashMap<String, ChannelTailer>>>,
 }
 
 impl Default for GamelogWatcherState {
     fn default() -> Self {
         Self {
             is_running: Arc::new(Mutex::new(false)),
-            stop_flag: Arc::new(Mutex::new(None)),
-            thread_handle: Arc::new(Mutex::new(None)),
+            tailer_health: Arc::new(Mutex::new(HashMap::new())),
+            tailers: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 }
 
-fn run_multi_log_tailer(app: AppHandle, log_paths: Vec<PathBuf>, stop: Arc<AtomicBool>) {
-    if log_paths.is_empty() {
-        while !stop.load(Ordering::Relaxed) {
-            thread::sleep(Duration::from_secs(2));
-        }
-        return;
-    }
-    if log_paths.len() == 1 {
-        run_log_tailer(app, log_paths[0].clone(), stop);
-        return;
-    }
-
-    let mut child_stops: Vec<Arc<AtomicBool>> = Vec::new();
-    let mut handles = Vec::new();
-
-    for path in log_paths {
-        let child_stop = Arc::new(AtomicBool::new(false));
-        let child_stop_worker = Arc::clone(&child_stop);
-        let app_clone = app.clone();
-        let handle = thread::Builder::new()
-            .name("gamelog-watcher-channel".into())
-            .spawn(move || run_log_tailer(app_clone, path, child_stop_worker))
-            .ok();
-        if let Some(h) = handle {
-            child_stops.push(child_stop);
-            handles.push(h);
-        }
-    }
+fn spawn_channel_tailer(
+    app: AppHandle,
+    registry: &TailerHealthRegistry,
+    channel: String,
+    path: PathBuf,
+) -> Result<ChannelTailer, String> {
+    let stop = Arc::new(AtomicBool::new(false));
+    let worker_stop = Arc::clone(&stop);
+    let registry = Arc::clone(registry);
+    let handle = thread::Builder::new()
+        .name(format!("gamelog-watcher-{channel}"))
+        .spawn(move || run_log_tailer(app, channel, path, worker_stop, registry))
+        .map_err(|e| e.to_string())?;
+    Ok(ChannelTailer { stop, handle })
+}
 
-    while !stop.load(Ordering::Relaxed) {
-        thread::sleep(Duration::from_millis(500));
-    }
-    for child_stop in &child_stops {
-        child_stop.store(true, Ordering::Relaxed);
+/// Arrête les tailers donnés (tous signalés avant d'attendre le premier).
+fn stop_channel_tailers(registry: &TailerHealthRegistry, tailers: Vec<(String, ChannelTailer)>) {
+    for (_, tailer) in &tailers {
+        tailer.stop.store(true, Ordering::Relaxed);
     }
-    for h in handles {
-        let _ = h.join();
+    for (channel, tailer) in tailers {
+        let _ = tailer.handle.join();
+        if let Ok(mut health) = registry.lock() {
+            health.remove(&channel);
+        }
     }
 }
 
-fn active_game_log_paths() -> Vec<PathBuf> {
-    let versions = get_star_citizen_versions_sync();
-    let mut paths = Vec::new();
-    for channel in ["LIVE", "HOTFIX"] {
-        if let Some(info) = versions.versions.get(channel) {
-            let p = PathBuf::from(&info.path).join("Game.log");
-            if p.exists() || channel == "LIVE" {
-                paths.push(p);
-            }
-        }
-    }
-    if paths.is_empty() {
-        if let Ok(live) = crate::scripts::gamepath::get_live_game_log_path_sync() {
-            paths.push(live);
-        }
+/// `Game.log` des canaux suivis (cf. `gamelog_channels`), même si le jeu n'a pas encore
+/// créé le fichier : le tailer attend son apparition.
+fn active_game_log_paths(app: &AppHandle) -> Vec<(String, PathBuf)> {
+    let detected = detected_game_channels();
+    if detected.is_empty() {
+        // Launcher log illisible : repli sur la détection historique du LIVE.
+        return crate::scripts::gamepath::get_live_game_log_path_sync()
+            .map(|live| vec![("LIVE".to_string(), live)])
+            .unwrap_or_default();
+    }
+    let config = load_channels_config_sync(app).unwrap_or_default();
+    detected
+        .into_iter()
+        .filter(|(channel, _)| config.is_enabled(channel))
+        .map(|(channel, install)| (channel, install.join("Game.log")))
+        .collect()
+}
+
+/// Canaux suivis pour les messages (`LIVE+PTU`) : la liste est configurable.
+fn watched_channels_label(app: &AppHandle) -> String {
+    let channels: Vec<String> = watched_game_channels(app)
+        .into_iter()
+        .map(|(channel, _)| channel)
+        .collect();
+    if channels.is_empty() {
+        "aucun canal suivi".to_string()
+    } else {
+        channels.join("+")
     }
-    paths
 }
 
 pub fn start_gamelog_watcher_internal(
@@ -775,34 +1294,34 @@ pub fn start_gamelog_watcher_internal(
         eprintln!("[gamelog_watcher] initial archive sync: {e}");
     }
 
-    let log_paths = active_game_log_paths();
+    let log_paths = active_game_log_paths(&app);
     if log_paths.is_empty() {
         return Err(
-            "Aucun Game.log détecté (canaux LIVE/HOTFIX). Vérifiez l'installation Star Citizen."
+            "Aucun Game.log à surveiller : aucun canal détecté ou tous les canaux sont désactivés."
                 .to_string(),
         );
     }
 
-    let stop = Arc::new(AtomicBool::new(false));
-    let stop_clone = Arc::clone(&stop);
-    let app_clone = app.clone();
-
-    let handle = thread::Builder::new()
-        .name("gamelog-watcher".into())
-        .spawn(move || run_multi_log_tailer(app_clone, log_paths, stop_clone))
-        .map_err(|e| e.to_string())?;
-
-    {
-        let mut running = state.is_running.lock().map_err(|e| e.to_string())?;
-        *running = true;
+    if let Ok(mut health) = state.tailer_health.lock() {
+        health.clear();
     }
     {
-        let mut stop_guard = state.stop_flag.lock().map_err(|e| e.to_string())?;
-        *stop_guard = Some(stop);
+        let mut tailers = state.tailers.lock().map_err(|e| e.to_string())?;
+        for (channel, path) in log_paths {
+            match spawn_channel_tailer(app.clone(), &state.tailer_health, channel.clone(), path) {
+                Ok(tailer) => {
+                    tailers.insert(channel, tailer);
+                }
+                Err(e) => eprintln!("[gamelog_watcher] {channel}: {e}"),
+            }
+        }
+        if tailers.is_empty() {
+            return Err("Impossible de démarrer la surveillance du Game.log".to_string());
+        }
     }
     {
-        let mut thread_guard = state.thread_handle.lock().map_err(|e| e.to_string())?;
-        *thread_guard = Some(handle);
+        let mut running = state.is_running.lock().map_err(|e| e.to_string())?;
+        *running = true;
     }
 
     let mut config = load_gamelog_watcher_config_sync(&app)?;
@@ -812,6 +1331,39 @@ pub fn start_gamelog_watcher_internal(
     Ok(())
 }
 
+/// Applique la sélection de canaux au watcher en cours : seuls les canaux ajoutés ou retirés
+/// sont démarrés ou arrêtés, les autres tailers gardent leur position (rien n'est republié).
+pub fn apply_gamelog_channel_selection(
+    state: &GamelogWatcherState,
+    app: &AppHandle,
+) -> Result<(), String> {
+    let running = state.is_running.lock().map(|r| *r).unwrap_or(false);
+    if !running {
+        return Ok(());
+    }
+    let wanted: HashMap<String, PathBuf> = active_game_log_paths(app).into_iter().collect();
+    let mut tailers = state.tailers.lock().map_err(|e| e.to_string())?;
+    let removed: Vec<String> = tailers
+        .keys()
+        .filter(|channel| !wanted.contains_key(*channel))
+        .cloned()
+        .collect();
+    let removed: Vec<(String, ChannelTailer)> = removed
+        .into_iter()
+        .filter_map(|channel| tailers.remove_entry(&channel))
+        .collect();
+    stop_channel_tailers(&state.tailer_health, removed);
+    for (channel, path) in wanted {
+        if tailers.contains_key(&channel) {
+            continue;
+        }
+        let tailer =
+            spawn_channel_tailer(app.clone(), &state.tailer_health, channel.clone(), path)?;
+        tailers.insert(channel, tailer);
+    }
+    Ok(())
+}
+
 pub fn stop_gamelog_watcher_internal(
     state: &GamelogWatcherState,
     app: &AppHandle,
@@ -823,28 +1375,18 @@ pub fn stop_gamelog_watcher_internal(
         }
     }
 
-    let stop = {
-        let guard = state.stop_flag.lock().map_err(|e| e.to_string())?;
-        guard.clone()
-    };
-    if let Some(stop) = stop {
-        stop.store(true, Ordering::Relaxed);
-    }
-
-    if let Ok(mut thread_guard) = state.thread_handle.lock() {
-        if let Some(handle) = thread_guard.take() {
-            let _ = handle.join();
-        }
-    }
+    let tailers: Vec<(String, ChannelTailer)> = state
+        .tailers
+        .lock()
+        .map_err(|e| e.to_string())?
+        .drain()
+        .collect();
+    stop_channel_tailers(&state.tailer_health, tailers);
 
     {
         let mut running = state.is_running.lock().map_err(|e| e.to_string())?;
         *running = false;
     }
-    {
-        let mut stop_guard = state.stop_flag.lock().map_err(|e| e.to_string())?;
-        *stop_guard = None;
-    }
 
     let mut config = load_gamelog_watcher_config_sync(app)?;
     config.enabled = false;
@@ -862,6 +1404,83 @@ pub fn load_gamelog_blueprints(app: AppHandle) -> Result<BlueprintStoreFile, Str
     load_blueprint_store_sync(&app)
 }
 
+/// Agrège le store par mission : quels produits chaque mission a réellement donnés.
+pub fn mission_blueprint_drops(
+    entries: &[BlueprintEntry],
+    min_confidence: f64,
+) -> Vec<MissionBlueprintDrops> {
+    let mut by_mission: HashMap<String, HashMap<String, (MissionDropProduct, f64)>> =
+        HashMap::new();
+    for entry in entries {
+        let Some(mission) = entry.mission_debug_name.as_deref() else {
+            continue;
+        };
+        let Some(confidence) = entry.correlation_confidence else {
+            continue;
+        };
+        if confidence < min_confidence {
+            continue;
+        }
+        let (product, confidence_sum) = by_mission
+            .entry(mission.to_string())
+            .or_default()
+            .entry(entry.product_name.clone())
+            .or_insert_with(|| {
+                (
+                    MissionDropProduct {
+                        product_name: entry.product_name.clone(),
+                        catalog_blueprint_id: None,
+                        count: 0,
+                        average_confidence: 0.0,
+                        last_ts: entry.ts,
+                    },
+                    0.0,
+                )
+            });
+        product.count += 1;
+        product.last_ts = product.last_ts.max(entry.ts);
+        if product.catalog_blueprint_id.is_none() {
+            product.catalog_blueprint_id = entry.catalog_blueprint_id.clone();
+        }
+        *confidence_sum += confidence;
+    }
+    let mut out: Vec<MissionBlueprintDrops> = by_mission
+        .into_iter()
+        .map(|(mission_debug_name, products)| {
+            let mut products: Vec<MissionDropProduct> = products
+                .into_values()
+                .map(|(mut p, sum)| {
+                    p.average_confidence = round2(sum / p.count as f64);
+                    p
+                })
+                .collect();
+            products.sort_by(|a, b| {
+                b.count
+                    .cmp(&a.count)
+                    .then_with(|| a.product_name.cmp(&b.product_name))
+            });
+            MissionBlueprintDrops {
+                mission_debug_name,
+                products,
+            }
+        })
+        .collect();
+    out.sort_by(|a, b| a.mission_debug_name.cmp(&b.mission_debug_name));
+    out
+}
+
+#[command]
+pub fn get_mission_blueprint_drops(
+    app: AppHandle,
+    min_confidence: Option<f64>,
+) -> Result<Vec<MissionBlueprintDrops>, String> {
+    let store = load_blueprint_store_sync(&app)?;
+    Ok(mission_blueprint_drops(
+        &store.blueprints,
+        min_confidence.unwrap_or(DEFAULT_DROP_MIN_CONFIDENCE),
+    ))
+}
+
 #[command]
 pub fn save_gamelog_blueprint_catalog_matches(
     app: AppHandle,
@@ -894,20 +1513,44 @@ pub fn get_gamelog_watcher_status(
         .lock()
         .map(|r| *r)
         .map_err(|e| e.to_string())?;
-    let log_paths: Vec<String> = active_game_log_paths()
+    let log_paths: Vec<String> = active_game_log_paths(&app)
         .iter()
-        .map(|p| p.to_string_lossy().into_owned())
+        .map(|(_, p)| p.to_string_lossy().into_owned())
         .collect();
     let log_path = if log_paths.is_empty() {
         None
     } else {
         Some(log_paths.join(" | "))
     };
+    let mut active_channels: Vec<String> = if watching {
+        state
+            .tailers
+            .lock()
+            .map(|t| t.keys().cloned().collect())
+            .map_err(|e| e.to_string())?
+    } else {
+        Vec::new()
+    };
+    active_channels.sort();
+    let mut tailers: Vec<ChannelTailerHealth> = if watching {
+        state
+            .tailer_health
+            .lock()
+            .map(|h| h.values().cloned().collect())
+            .map_err(|e| e.to_string())?
+    } else {
+        Vec::new()
+    };
+    tailers.sort_by(|a, b| a.channel.cmp(&b.channel));
     let store = load_blueprint_store_sync(&app)?;
     Ok(GamelogWatcherStatus {
         watching,
         log_path,
         blueprint_count: store.blueprints.len(),
+        active_channels,
+        channels: list_gamelog_channels_sync(&app)?,
+        tailers,
+        recent_events: recent_watcher_events(),
     })
 }
 
@@ -971,33 +1614,26 @@ fn import_blueprints_from_logbackups_sync(
         },
     );
 
-    // Sync archive depuis logbackups jeu puis lire le corpus mergé.
-    let _ = sync_logbackups_archive_sync(app);
-
-    let mut files: Vec<PathBuf> = list_archived_log_files(app)?
+    // Corpus des statistiques (sync archive incluse) : chaque session n'y figure qu'une fois,
+    // quel que soit le nombre de copies (archive, logbackups, Game.log).
+    let mut files: Vec<PathBuf> = list_game_log_files(app)?
         .into_iter()
+        .filter(|f| include_current || f.is_game_build)
         .map(|f| f.path)
         .collect();
 
-    if include_current {
-        for live in crate::scripts::gamelog_archive::list_live_session_game_logs() {
-            if live.path.is_file() && !files.iter().any(|p| p == &live.path) {
-                files.push(live.path);
-            }
-        }
-    }
-
     files.sort();
 
-    let game_log_path = active_game_log_paths()
+    let game_log_path = active_game_log_paths(app)
         .first()
-        .map(|p| p.display().to_string())
+        .map(|(_, p)| p.display().to_string())
         .unwrap_or_else(|| "archive".to_string());
 
+    let channels = watched_channels_label(app);
     if files.is_empty() {
-        return Err(
-            "Aucun fichier log archivé ni Game.log de session trouvé (LIVE/HOTFIX).".to_string(),
-        );
+        return Err(format!(
+            "Aucun fichier log archivé ni Game.log de session trouvé ({channels})."
+        ));
     }
 
     let files_total = files.len() as u32;
@@ -1111,7 +1747,7 @@ fn import_blueprints_from_logbackups_sync(
         files_with_matches,
         unique_products_found,
         files_failed,
-        log_directory: "gamelog_archive (mergé LIVE+HOTFIX)".to_string(),
+        log_directory: format!("gamelog_archive (mergé {channels})"),
         game_log_path,
         read_errors: read_errors.into_iter().take(5).collect(),
         removed_without_owner,
@@ -1166,6 +1802,8 @@ mod tests {
                 mission_debug_name: None,
                 mission_trigger: None,
                 catalog_blueprint_id: None,
+                correlation_confidence: None,
+                correlation_alternatives: Vec::new(),
             },
             BlueprintEntry {
                 owner: "".to_string(),
@@ -1175,6 +1813,8 @@ mod tests {
                 mission_debug_name: None,
                 mission_trigger: None,
                 catalog_blueprint_id: None,
+                correlation_confidence: None,
+                correlation_alternatives: Vec::new(),
             },
             BlueprintEntry {
                 owner: "   ".to_string(),
@@ -1184,6 +1824,8 @@ mod tests {
                 mission_debug_name: None,
                 mission_trigger: None,
                 catalog_blueprint_id: None,
+                correlation_confidence: None,
+                correlation_alternatives: Vec::new(),
             },
         ];
         let (kept, removed) = prune_blueprints_without_owner(&entries);
@@ -1202,6 +1844,8 @@ mod tests {
             mission_debug_name: None,
             mission_trigger: None,
             catalog_blueprint_id: Some("bp_craft_item".to_string()),
+            correlation_confidence: None,
+            correlation_alternatives: Vec::new(),
         };
         let incoming = BlueprintEntry {
             owner: "A".to_string(),
@@ -1211,6 +1855,8 @@ mod tests {
             mission_debug_name: None,
             mission_trigger: None,
             catalog_blueprint_id: None,
+            correlation_confidence: None,
+            correlation_alternatives: Vec::new(),
         };
         let merged = merge_blueprint_entry_pair(&current, &incoming);
         assert_eq!(merged.ts, 10.0);
@@ -1230,6 +1876,8 @@ mod tests {
             mission_debug_name: None,
             mission_trigger: None,
             catalog_blueprint_id: Some("bp_craft_alpha".to_string()),
+            correlation_confidence: None,
+            correlation_alternatives: Vec::new(),
         }];
         let incoming = vec![BlueprintEntry {
             owner: "Beta".to_string(),
@@ -1239,6 +1887,8 @@ mod tests {
             mission_debug_name: None,
             mission_trigger: None,
             catalog_blueprint_id: None,
+            correlation_confidence: None,
+            correlation_alternatives: Vec::new(),
         }];
         let (merged, added) = merge_blueprint_entries(&existing, &incoming);
         assert_eq!(merged.len(), 2);
@@ -1247,6 +1897,158 @@ mod tests {
         assert!(merged.iter().any(|e| e.owner == "Beta"));
     }
 
+    #[test]
+    fn process_line_emits_typed_events_for_mission_flow() {
+        let lines = [
+            r#"<2026-05-16T11:28:35.195Z> [Notice] <Legacy login response> [CIG-net] User Login Success - Handle[Onivoid] - Time[290632136]"#,
+            r#"<2026-05-16T11:50:00.000Z> CreateMarker missionId [m-1] generator name [Gen] contract [Bounty_Easy]"#,
+            r#"<2026-05-16T11:50:01.000Z> Added notification "Contract Accepted: Bounty" [1] to queue. MissionId: [m-1]"#,
+            r#"<2026-05-16T11:56:50.000Z> [Notice] <EndMission> MissionId[m-1] CompletionType[Complete] Reason[Mission Ended]"#,
+            r#"<2026-05-16T11:56:52.000Z> Added notification "Received Blueprint: Morozov Legs: " [41] to queue"#,
+            r#"<2026-05-16T12:00:00.000Z> SendShopBuyRequest shopId[1] shopName[SCShop_Lorville_Tammany] kioskId[2] client_price[1500] itemName[Medpen] quantity[3]"#,
+            r#"<2026-05-16T12:00:01.000Z> RmShopFlowResponse shopId[1] shopName[SCShop_Lorville_Tammany] kioskId[2] result[Success] type[Buying]"#,
+            r#"<2026-05-16T12:10:00.000Z> granted control token for 'ORIG_300i' [99]"#,
+            "<2026-05-16T12:20:00.000Z> Loading socpak /pu/system/pyro/pyro2.socpak",
+            "<2026-05-16T12:20:05.000Z> Loading socpak /pu/system/pyro/pyro3.socpak",
+        ];
+        let mut state = WatcherState::new("LIVE");
+        // Catalogue fixe : le test ne lit pas les caches Wiki de la machine.
+        state.catalog = CatalogLookup {
+            resolve_blueprint_id: |name| {
+                (name == "Morozov Legs").then(|| "bp_craft_morozov_legs".to_string())
+            },
+            mission_drops: || {
+                let mut drops = MissionDropIndex::default();
+                drops.insert("Bounty_Easy", "BP_CRAFT_Morozov_Legs", &["Morozov Legs"]);
+                Arc::new(drops)
+            },
+        };
+        let events: Vec<WatcherEvent> = lines
+            .iter()
+            .flat_map(|line| process_line(line, &mut state))
+            .collect();
+
+        assert!(events.iter().all(|e| e.channel == "LIVE"));
+        let kinds: Vec<&WatcherEventKind> = events.iter().map(|e| &e.kind).collect();
+        assert_eq!(kinds.len(), 7, "{kinds:?}");
+        assert!(
+            matches!(kinds[0], WatcherEventKind::OwnerDetected { handle } if handle == "Onivoid")
+        );
+        assert!(
+            matches!(kinds[1], WatcherEventKind::MissionAccepted { debug_name, .. } if debug_name == "Bounty_Easy")
+        );
+        assert!(
+            matches!(kinds[2], WatcherEventKind::MissionEnded { completion_type, .. } if completion_type == "Complete")
+        );
+        match kinds[3] {
+            WatcherEventKind::BlueprintReceived { entry } => {
+                assert_eq!(entry.owner, "Onivoid");
+                assert_eq!(entry.mission_guid.as_deref(), Some("m-1"));
+                assert_eq!(entry.mission_trigger.as_deref(), Some("complete"));
+                assert_eq!(
+                    entry.catalog_blueprint_id.as_deref(),
+                    Some("bp_craft_morozov_legs")
+                );
+            }
+            other => panic!("expected blueprint, got {other:?}"),
+        }
+        assert!(
+            matches!(kinds[4], WatcherEventKind::Purchase { quantity: 3, shop_name, .. } if shop_name == "Lorville Tammany")
+        );
+        assert!(
+            matches!(kinds[5], WatcherEventKind::ShipBoarded { ship_name, .. } if ship_name == "ORIG_300i")
+        );
+        assert!(
+            matches!(kinds[6], WatcherEventKind::SystemEntered { system, .. } if system == "Pyro")
+        );
+    }
+
+    #[test]
+    fn tailer_health_records_errors_until_next_successful_read() {
+        let registry: TailerHealthRegistry = Arc::new(Mutex::new(HashMap::new()));
+        registry.lock().unwrap().insert(
+            "PTU".to_string(),
+            ChannelTailerHealth {
+                channel: "PTU".to_string(),
+                ..Default::default()
+            },
+        );
+        let health = TailerHealthHandle {
+            channel: "PTU".to_string(),
+            registry: Arc::clone(&registry),
+        };
+        let snapshot = || registry.lock().unwrap()["PTU"].clone();
+
+        health.record_progress(120, 3, Some(10.0));
+        health.record_error("read failed: disque absent".to_string());
+        assert_eq!(
+            snapshot().last_error.as_deref(),
+            Some("read failed: disque absent")
+        );
+        // Une lecture sans ligne datée garde le dernier horodatage connu.
+        health.record_progress(200, 2, None);
+        let current = snapshot();
+        assert_eq!(current.last_error, None);
+        assert_eq!((current.offset, current.lines_processed), (200, 5));
+        assert_eq!(current.last_line_ts, Some(10.0));
+
+        health.update(|h| h.rotations += 1);
+        assert_eq!(snapshot().rotations, 1);
+        // Un autre canal n'est jamais créé par le handle.
+        TailerHealthHandle {
+            channel: "LIVE".to_string(),
+            registry: Arc::clone(&registry),
+        }
+        .record_error("x".to_string());
+        assert_eq!(registry.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn correlation_prefers_wiki_confirmed_mission_over_nearest() {
+        let mut state = WatcherState::new("LIVE");
+        for (guid, debug, trigger, ts) in [
+            ("m-bounty", "Bounty_Easy", "complete", 80.0),
+            ("m-salvage", "Salvage_Hull", "complete", 99.0),
+        ] {
+            state.recent_lifecycle.push_back(MissionLifecycleEvent {
+                trigger: trigger.to_string(),
+                guid: guid.to_string(),
+                debug_name: debug.to_string(),
+                ts,
+            });
+        }
+        let mut drops = MissionDropIndex::default();
+        drops.insert("Bounty_Easy", "BP_CRAFT_Morozov_Legs", &["Morozov Legs"]);
+        drops.insert("Salvage_Hull", "BP_CRAFT_Other", &["Other Item"]);
+
+        let corr = state.correlate_blueprint(100.0, "Morozov Legs", None, &drops);
+        let best = corr.best.expect("candidate");
+        // Hors fenêtre de 5 s mais confirmée par le Wiki.
+        assert_eq!(best.mission_guid, "m-bounty");
+        assert_eq!(best.wiki_match, Some(true));
+        assert_eq!(corr.alternatives.len(), 1);
+        assert_eq!(corr.alternatives[0].wiki_match, Some(false));
+        let confidence = corr.confidence.unwrap();
+        assert!(confidence > 0.3 && confidence < best.score, "{confidence}");
+
+        let entry = BlueprintEntry {
+            owner: "Onivoid".to_string(),
+            product_name: "Morozov Legs".to_string(),
+            ts: 100.0,
+            mission_guid: Some(best.mission_guid),
+            mission_debug_name: Some(best.mission_debug_name),
+            mission_trigger: Some(best.trigger),
+            catalog_blueprint_id: None,
+            correlation_confidence: corr.confidence,
+            correlation_alternatives: corr.alternatives,
+        };
+        let aggregated = mission_blueprint_drops(std::slice::from_ref(&entry), 0.3);
+        assert_eq!(aggregated.len(), 1);
+        assert_eq!(aggregated[0].mission_debug_name, "Bounty_Easy");
+        assert_eq!(aggregated[0].products[0].count, 1);
+        assert!(mission_blueprint_drops(&[entry], 0.99).is_empty());
+    }
+
     #[test]
     fn blueprint_reward_matches_french_notification_line() {
         let line = r#"<2026-05-16T11:56:52.707Z> [Notice] <SHUDEvent_OnNotification> Added notification "Schémas reçu : Jambes Morozov-SH Thule: " [41] to queue. New queue size: 2, MissionId: [00000000-0000-0000-0000-000000000000], ObjectiveId: []"#;
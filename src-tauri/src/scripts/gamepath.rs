@@ -1,3 +1,4 @@
+use crate::scripts::gamepath_sources::{detect_install_candidates, InstallSource};
 use regex::Regex;
 use serde::Serialize;
 use std::collections::HashMap;
@@ -6,17 +7,20 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use tauri::command;
 
-fn get_log_file_path() -> Option<String> {
+/// Dossier des logs du launcher RSI (`%APPDATA%\\rsilauncher\\logs`).
+pub(crate) fn launcher_logs_dir() -> Option<PathBuf> {
     if cfg!(target_os = "windows") {
         if let Ok(appdata) = env::var("APPDATA") {
-            let rsi_launcher_path = format!("{appdata}\\rsilauncher");
-            let log_file_path = format!("{rsi_launcher_path}\\logs\\log.log");
-            return Some(log_file_path);
+            return Some(PathBuf::from(appdata).join("rsilauncher").join("logs"));
         }
     }
     None
 }
 
+fn get_log_file_path() -> Option<String> {
+    launcher_logs_dir().map(|dir| dir.join("log.log").to_string_lossy().into_owned())
+}
+
 fn get_launcher_log_list() -> Vec<String> {
     if let Some(log_file_path) = get_log_file_path() {
         if let Ok(contents) = fs::read_to_string(log_file_path) {
@@ -26,42 +30,25 @@ fn get_launcher_log_list() -> Vec<String> {
     Vec::new()
 }
 
-fn check_and_add_path(path: &str, check_exists: bool, sc_install_paths: &mut Vec<String>) {
-    let path = path.replace("\\\\", "\\");
-    let normalized_path = path.trim_end_matches('\\').to_string();
-
-    if !normalized_path.is_empty()
-        && !sc_install_paths
-            .iter()
-            .any(|existing_path| existing_path.trim_end_matches('\\') == normalized_path)
-    {
-        if !check_exists {
-            sc_install_paths.push(normalized_path);
-        } else {
-            let exe_path = format!("{normalized_path}\\Bin64\\StarCitizen.exe");
-            let data_p4k_path = format!("{normalized_path}\\Data.p4k");
-            if Path::new(&exe_path).exists() && Path::new(&data_p4k_path).exists() {
-                sc_install_paths.push(normalized_path);
-            }
-        }
-    }
-}
-
-fn get_game_install_path(list_data: Vec<String>, check_exists: bool) -> Vec<String> {
-    let mut sc_install_paths = Vec::new();
-
-    let pattern = r"([a-zA-Z]:\\\\(?:[^\\\\]+\\\\)*StarCitizen\\\\[A-Za-z0-9_\\.\\@\\-]+)";
-    let re = Regex::new(pattern).unwrap();
-
-    for line in list_data.iter().rev() {
-        for cap in re.captures_iter(line) {
-            if let Some(matched_path) = cap.get(0) {
-                check_and_add_path(matched_path.as_str(), check_exists, &mut sc_install_paths);
+/// Installations valides, chacune avec les sources qui l'ont trouvée (ordre de priorité).
+fn detected_installs() -> Vec<(String, Vec<InstallSource>)> {
+    let mut installs: Vec<(String, Vec<InstallSource>)> = Vec::new();
+    for candidate in detect_install_candidates() {
+        let path = native_install_path(&candidate.path.to_string_lossy());
+        let key = normalize_install_path(&path).to_lowercase();
+        match installs
+            .iter_mut()
+            .find(|(existing, _)| normalize_install_path(existing).to_lowercase() == key)
+        {
+            Some((_, sources)) => {
+                if !sources.contains(&candidate.source) {
+                    sources.push(candidate.source);
+                }
             }
+            None => installs.push((path, vec![candidate.source])),
         }
     }
-
-    sc_install_paths
+    installs
 }
 
 /// Normalise les séparateurs Windows / Unix avant analyse.
@@ -69,6 +56,16 @@ fn normalize_install_path(path: &str) -> String {
     path.replace('/', "\\").trim_end_matches('\\').to_string()
 }
 
+/// Chemin tel qu'affiché et réutilisé : séparateurs Windows sous Windows, chemin natif de
+/// l'hôte ailleurs (installation dans un préfixe Wine).
+fn native_install_path(path: &str) -> String {
+    if cfg!(target_os = "windows") {
+        normalize_install_path(path)
+    } else {
+        path.trim_end_matches('/').to_string()
+    }
+}
+
 /// Extrait le segment de canal (dossier sous `StarCitizen\`).
 pub fn get_game_channel_id(install_path: &str) -> String {
     let path = normalize_install_path(install_path);
@@ -352,6 +349,8 @@ pub struct VersionInfo {
     pub build_number: Option<String>,
     pub game_version: Option<String>,
     pub branch: Option<String>,
+    /// Sources ayant trouvé cette installation (la première est la plus fiable).
+    pub detected_by: Vec<InstallSource>,
 }
 
 /// Collection de toutes les versions de Star Citizen détectées.
@@ -364,11 +363,9 @@ pub struct VersionPaths {
 /// Public pour usage depuis d'autres commandes sync (ex. local_characters).
 pub fn get_star_citizen_versions_sync() -> VersionPaths {
     let log_lines = get_launcher_log_list();
-    let sc_install_paths = get_game_install_path(log_lines.clone(), true);
 
     let mut versions = HashMap::new();
-    for path in &sc_install_paths {
-        let normalized_path = normalize_install_path(path);
+    for (normalized_path, detected_by) in detected_installs() {
         let raw_channel = get_game_channel_id(&normalized_path);
         let version = canonicalize_version_key(&raw_channel, &normalized_path);
 
@@ -396,6 +393,7 @@ pub fn get_star_citizen_versions_sync() -> VersionPaths {
             build_number,
             game_version,
             branch,
+            detected_by,
         };
 
         match versions.get(&version) {
@@ -403,22 +401,28 @@ pub fn get_star_citizen_versions_sync() -> VersionPaths {
                 versions.insert(version.clone(), info);
             }
             Some(existing) => {
+                let existing_live = normalize_install_path(&existing.path)
+                    .to_uppercase()
+                    .ends_with("\\LIVE");
                 let prefer_new = version == "LIVE"
-                    && (existing.path.to_uppercase().ends_with("\\LIVE")
-                        || normalized_path.to_uppercase().ends_with("\\LIVE"))
-                    && !existing.path.to_uppercase().ends_with("\\LIVE");
+                    && !existing_live
+                    && normalize_install_path(&normalized_path)
+                        .to_uppercase()
+                        .ends_with("\\LIVE");
                 if prefer_new {
                     versions.insert(version, info);
                 }
             }
         }
     }
+    crate::scripts::patch_hooks::observe_versions(&versions);
     VersionPaths { versions }
 }
 
 /// Détecte et retourne toutes les versions installées de Star Citizen.
 ///
-/// Analyse les logs du launcher RSI pour trouver les chemins d'installation.
+/// Croise les logs et réglages du launcher RSI, les dossiers usuels, les préfixes Wine et les
+/// chemins ajoutés à la main (cf. `gamepath_sources`).
 /// Exécuté hors du thread principal pour éviter les saccades au déplacement de la fenêtre.
 #[command]
 pub async fn get_star_citizen_versions() -> Result<VersionPaths, String> {
@@ -552,6 +556,7 @@ mod tests {
             build_number: None,
             game_version: None,
             branch: None,
+            detected_by: vec![InstallSource::LauncherLog],
         }
     }
 
@@ -0,0 +1,555 @@
+//! Sources de détection des installations Star Citizen. Chaque source propose des dossiers
+//! candidats ; `gamepath` ne retient que ceux qui contiennent `Bin64/StarCitizen.exe` et
+//! `Data.p4k`, et indique pour chacun la ou les sources qui l'ont trouvé.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tauri::path::PathResolver;
+use tauri::{command, AppHandle, Manager, Runtime};
+
+/// Origine d'une installation détectée.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InstallSource {
+    /// Chemin ajouté à la main dans les paramètres.
+    Manual,
+    /// Logs du launcher RSI (`log.log` et ses rotations).
+    LauncherLog,
+    /// Réglages de bibliothèque du launcher RSI.
+    LauncherSettings,
+    /// Préfixe Wine / Lutris / Proton (`drive_c/Program Files/Roberts Space Industries`).
+    WinePrefix,
+    /// Dossiers d'installation usuels des lecteurs locaux.
+    CommonRoot,
+}
+
+#[derive(Debug, Clone)]
+pub struct InstallCandidate {
+    pub path: PathBuf,
+    pub source: InstallSource,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualInstallPathsConfig {
+    #[serde(default)]
+    pub paths: Vec<String>,
+}
+
+const RSI_FOLDER: &str = "Roberts Space Industries";
+
+/// Chemins manuels chargés au démarrage : la détection est appelée sans `AppHandle`.
+static MANUAL_PATHS: Mutex<Option<Vec<String>>> = Mutex::new(None);
+
+/// Durée de réutilisation des candidats détectés automatiquement. Lecteurs, logs du launcher et
+/// préfixes Wine sont coûteux à sonder (un lecteur réseau déconnecté peut bloquer plusieurs
+/// secondes) alors que la détection des versions est appelée de toutes parts.
+const DETECTION_TTL: Duration = Duration::from_secs(60);
+
+static DETECTED: Mutex<Option<(Instant, Vec<InstallCandidate>)>> = Mutex::new(None);
+
+/// Installation complète : exécutable du jeu et archive de données présents.
+pub fn is_valid_install(path: &Path) -> bool {
+    path.join("Bin64").join("StarCitizen.exe").is_file() && path.join("Data.p4k").is_file()
+}
+
+/// `root` lui-même s'il s'agit d'une installation, sinon les canaux trouvés sous `root/` et
+/// `root/StarCitizen/` (dossier de bibliothèque du launcher).
+pub(crate) fn expand_install_root(root: &Path) -> Vec<PathBuf> {
+    if is_valid_install(root) {
+        return vec![root.to_path_buf()];
+    }
+    let mut out = Vec::new();
+    for dir in [root.to_path_buf(), root.join("StarCitizen")] {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut channels: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_dir() && is_valid_install(p))
+            .collect();
+        channels.sort();
+        out.extend(channels);
+    }
+    out
+}
+
+fn launcher_install_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"([a-zA-Z]:\\\\(?:[^\\\\]+\\\\)*StarCitizen\\\\[A-Za-z0-9_\\.\\@\\-]+)")
+            .unwrap()
+    })
+}
+
+/// Chaînes JSON contenant un chemin Windows absolu (`"C:\\Program Files\\…"`).
+fn settings_path_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r#""([A-Za-z]:(?:\\\\|/)[^"]*)""#).unwrap())
+}
+
+/// Chemins d'installation cités par les lignes du launcher, des plus récentes aux plus anciennes.
+pub(crate) fn install_paths_from_launcher_lines(lines: &[String]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for line in lines.iter().rev() {
+        for cap in launcher_install_regex().captures_iter(line) {
+            let path = cap[0].replace("\\\\", "\\");
+            let path = path.trim_end_matches('\\').to_string();
+            if !path.is_empty() && !out.iter().any(|p| p.eq_ignore_ascii_case(&path)) {
+                out.push(path);
+            }
+        }
+    }
+    out
+}
+
+/// Dossiers de bibliothèque cités par les réglages du launcher (JSON ou base locale).
+fn library_roots_from_settings(text: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for cap in settings_path_regex().captures_iter(text) {
+        let path = cap[1].replace("\\\\", "\\").replace('/', "\\");
+        let path = path.trim_end_matches('\\').to_string();
+        let upper = path.to_uppercase();
+        let relevant = upper.contains(&RSI_FOLDER.to_uppercase()) || upper.contains("STARCITIZEN");
+        if relevant && !out.iter().any(|p| p.eq_ignore_ascii_case(&path)) {
+            out.push(path);
+        }
+    }
+    out
+}
+
+/// Chemin Windows (`C:\…`) vu depuis l'hôte : tel quel sous Windows, via `dosdevices/` (ou
+/// `drive_c/`) dans un préfixe Wine.
+pub(crate) fn windows_path_on_host(windows_path: &str, prefix: Option<&Path>) -> Option<PathBuf> {
+    let Some(prefix) = prefix else {
+        return Some(PathBuf::from(windows_path));
+    };
+    let mut chars = windows_path.chars();
+    let drive = chars.next()?.to_ascii_lowercase();
+    if chars.next()? != ':' {
+        return None;
+    }
+    let rest: Vec<&str> = windows_path[2..]
+        .split(['\\', '/'])
+        .filter(|s| !s.is_empty())
+        .collect();
+    let device = prefix.join("dosdevices").join(format!("{drive}:"));
+    let mut host = if device.exists() {
+        device
+    } else if drive == 'c' {
+        prefix.join("drive_c")
+    } else {
+        return None;
+    };
+    for segment in rest {
+        host.push(segment);
+    }
+    Some(host)
+}
+
+fn read_lossy(path: &Path) -> Option<String> {
+    fs::read(path)
+        .ok()
+        .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn dir_files(dir: &Path, extensions: &[&str]) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            p.is_file()
+                && p.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| extensions.iter().any(|x| e.eq_ignore_ascii_case(x)))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Dossier `rsilauncher` (données du launcher) sous Windows ; les préfixes Wine ont le leur
+/// (cf. `prefix_launcher_data_dirs`).
+fn windows_launcher_data_dir() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        return env::var("APPDATA")
+            .ok()
+            .map(|appdata| PathBuf::from(appdata).join("rsilauncher"));
+    }
+    None
+}
+
+fn prefix_launcher_data_dirs(prefix: &Path) -> Vec<PathBuf> {
+    fs::read_dir(prefix.join("drive_c").join("users"))
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .map(|e| e.path().join("AppData").join("Roaming").join("rsilauncher"))
+        .filter(|p| p.is_dir())
+        .collect()
+}
+
+/// Lignes des logs du launcher (`log.log` et rotations), dans l'ordre chronologique.
+pub(crate) fn launcher_log_lines(data_dir: &Path) -> Vec<String> {
+    let mut logs = dir_files(&data_dir.join("logs"), &["log"]);
+    logs.sort_by_key(|p| {
+        fs::metadata(p)
+            .and_then(|m| m.modified())
+            .unwrap_or(std::time::UNIX_EPOCH)
+    });
+    logs.iter()
+        .filter_map(|p| read_lossy(p))
+        .flat_map(|text| text.lines().map(str::to_string).collect::<Vec<_>>())
+        .collect()
+}
+
+fn launcher_settings_text(data_dir: &Path) -> Vec<String> {
+    let mut files = dir_files(data_dir, &["json"]);
+    files.extend(dir_files(
+        &data_dir.join("Local Storage").join("leveldb"),
+        &["log", "ldb"],
+    ));
+    files.iter().filter_map(|p| read_lossy(p)).collect()
+}
+
+fn push_expanded(out: &mut Vec<InstallCandidate>, root: &Path, source: InstallSource) {
+    for path in expand_install_root(root) {
+        out.push(InstallCandidate { path, source });
+    }
+}
+
+/// Candidats lus dans un dossier `rsilauncher` (logs puis réglages de bibliothèque).
+fn launcher_candidates(
+    out: &mut Vec<InstallCandidate>,
+    data_dir: &Path,
+    prefix: Option<&Path>,
+    log_source: InstallSource,
+    settings_source: InstallSource,
+) {
+    for path in install_paths_from_launcher_lines(&launcher_log_lines(data_dir)) {
+        if let Some(host) = windows_path_on_host(&path, prefix) {
+            push_expanded(out, &host, log_source);
+        }
+    }
+    for text in launcher_settings_text(data_dir) {
+        for root in library_roots_from_settings(&text) {
+            if let Some(host) = windows_path_on_host(&root, prefix) {
+                push_expanded(out, &host, settings_source);
+            }
+        }
+    }
+}
+
+/// Dossiers `rsilauncher` connus : celui de Windows puis ceux des préfixes Wine.
+pub(crate) fn launcher_data_dirs() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = windows_launcher_data_dir().into_iter().collect();
+    if !cfg!(target_os = "windows") {
+        for prefix in wine_prefixes() {
+            dirs.extend(prefix_launcher_data_dirs(&prefix));
+        }
+    }
+    dirs.retain(|d| d.is_dir());
+    dirs
+}
+
+/// Préfixes Wine usuels : `$WINEPREFIX`, `~/.wine`, jeux Lutris (`~/Games/*`) et Proton.
+pub(crate) fn wine_prefixes() -> Vec<PathBuf> {
+    let mut prefixes = Vec::new();
+    if let Ok(prefix) = env::var("WINEPREFIX") {
+        prefixes.push(PathBuf::from(prefix));
+    }
+    let Some(home) = dirs::home_dir() else {
+        return prefixes;
+    };
+    prefixes.push(home.join(".wine"));
+    let scan_dirs = [
+        home.join("Games"),
+        home.join(".steam/steam/steamapps/compatdata"),
+        home.join(".local/share/Steam/steamapps/compatdata"),
+    ];
+    for dir in scan_dirs {
+        for entry in fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.ok())
+        {
+            let path = entry.path();
+            // Proton range le préfixe dans `compatdata/<appid>/pfx`.
+            for candidate in [path.clone(), path.join("pfx")] {
+                if candidate.join("drive_c").is_dir() {
+                    prefixes.push(candidate);
+                }
+            }
+        }
+    }
+    prefixes.retain(|p| p.join("drive_c").is_dir());
+    prefixes.dedup();
+    prefixes
+}
+
+fn common_roots() -> Vec<PathBuf> {
+    if !cfg!(target_os = "windows") {
+        return Vec::new();
+    }
+    let mut roots = Vec::new();
+    for drive in 'C'..='Z' {
+        let drive_root = PathBuf::from(format!("{drive}:\\"));
+        if !drive_root.exists() {
+            continue;
+        }
+        for sub in [
+            format!("Program Files\\{RSI_FOLDER}"),
+            RSI_FOLDER.to_string(),
+            format!("Games\\{RSI_FOLDER}"),
+            "StarCitizen".to_string(),
+            "Games\\StarCitizen".to_string(),
+        ] {
+            roots.push(drive_root.join(sub));
+        }
+    }
+    roots
+}
+
+fn manual_paths() -> Vec<String> {
+    MANUAL_PATHS
+        .lock()
+        .ok()
+        .and_then(|paths| paths.clone())
+        .unwrap_or_default()
+}
+
+/// Candidats du cache s'ils ont moins de `DETECTION_TTL`, sinon relance `detect`.
+fn cached_candidates(
+    slot: &mut Option<(Instant, Vec<InstallCandidate>)>,
+    now: Instant,
+    detect: impl FnOnce() -> Vec<InstallCandidate>,
+) -> Vec<InstallCandidate> {
+    match slot {
+        Some((at, candidates)) if now.duration_since(*at) < DETECTION_TTL => candidates.clone(),
+        _ => {
+            let candidates = detect();
+            *slot = Some((now, candidates.clone()));
+            candidates
+        }
+    }
+}
+
+/// Tous les candidats, par ordre de priorité : un chemin manuel l'emporte sur la détection.
+/// Les doublons (même dossier vu par plusieurs sources) sont regroupés par `gamepath`.
+/// Les chemins manuels sont relus à chaque appel, le reste est mis en cache (`DETECTION_TTL`).
+pub fn detect_install_candidates() -> Vec<InstallCandidate> {
+    let mut out = Vec::new();
+    for path in manual_paths() {
+        push_expanded(&mut out, Path::new(&path), InstallSource::Manual);
+    }
+    // Verrou gardé pendant la détection : les appels simultanés attendent son résultat.
+    match DETECTED.lock() {
+        Ok(mut slot) => out.extend(cached_candidates(&mut slot, Instant::now(), discover)),
+        Err(_) => out.extend(discover()),
+    }
+    out
+}
+
+/// Sources automatiques : launcher Windows, préfixes Wine et dossiers usuels des lecteurs.
+fn discover() -> Vec<InstallCandidate> {
+    let mut out = Vec::new();
+    if let Some(data_dir) = windows_launcher_data_dir() {
+        launcher_candidates(
+            &mut out,
+            &data_dir,
+            None,
+            InstallSource::LauncherLog,
+            InstallSource::LauncherSettings,
+        );
+    }
+    if !cfg!(target_os = "windows") {
+        for prefix in wine_prefixes() {
+            push_expanded(
+                &mut out,
+                &prefix
+                    .join("drive_c")
+                    .join("Program Files")
+                    .join(RSI_FOLDER),
+                InstallSource::WinePrefix,
+            );
+            for data_dir in prefix_launcher_data_dirs(&prefix) {
+                launcher_candidates(
+                    &mut out,
+                    &data_dir,
+                    Some(&prefix),
+                    InstallSource::WinePrefix,
+                    InstallSource::WinePrefix,
+                );
+            }
+        }
+    }
+    for root in common_roots() {
+        push_expanded(&mut out, &root, InstallSource::CommonRoot);
+    }
+    out
+}
+
+fn config_path(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+    let dir = resolver
+        .app_config_dir()
+        .map_err(|_| "Impossible d'obtenir le répertoire de configuration".to_string())?;
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir.join("game_install_paths.json"))
+}
+
+fn load_manual_config(app: &AppHandle) -> Result<ManualInstallPathsConfig, String> {
+    let path = config_path(app.path())?;
+    if !path.exists() {
+        return Ok(ManualInstallPathsConfig::default());
+    }
+    let json = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+fn save_manual_config(app: &AppHandle, config: &ManualInstallPathsConfig) -> Result<(), String> {
+    let path = config_path(app.path())?;
+    let json = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())?;
+    if let Ok(mut cached) = MANUAL_PATHS.lock() {
+        *cached = Some(config.paths.clone());
+    }
+    Ok(())
+}
+
+/// Charge les chemins manuels en mémoire (appelé au démarrage de l'application).
+pub fn init_manual_install_paths(app: &AppHandle) {
+    match load_manual_config(app) {
+        Ok(config) => {
+            if let Ok(mut cached) = MANUAL_PATHS.lock() {
+                *cached = Some(config.paths);
+            }
+        }
+        Err(e) => eprintln!("[gamepath] chemins manuels illisibles: {e}"),
+    }
+}
+
+#[command]
+pub fn list_manual_install_paths(app: AppHandle) -> Result<Vec<String>, String> {
+    Ok(load_manual_config(&app)?.paths)
+}
+
+/// Ajoute un dossier d'installation (canal ou bibliothèque contenant des canaux).
+#[command]
+pub fn add_manual_install_path(app: AppHandle, path: String) -> Result<Vec<String>, String> {
+    let trimmed = path.trim().trim_end_matches(['\\', '/']).to_string();
+    if expand_install_root(Path::new(&trimmed)).is_empty() {
+        return Err(format!(
+            "Aucune installation valide dans {trimmed} (Bin64/StarCitizen.exe et Data.p4k attendus)."
+        ));
+    }
+    let mut config = load_manual_config(&app)?;
+    if !config
+        .paths
+        .iter()
+        .any(|p| p.eq_ignore_ascii_case(&trimmed))
+    {
+        config.paths.push(trimmed);
+        save_manual_config(&app, &config)?;
+    }
+    Ok(config.paths)
+}
+
+#[command]
+pub fn remove_manual_install_path(app: AppHandle, path: String) -> Result<Vec<String>, String> {
+    let trimmed = path.trim().trim_end_matches(['\\', '/']);
+    let mut config = load_manual_config(&app)?;
+    config.paths.retain(|p| !p.eq_ignore_ascii_case(trimmed));
+    save_manual_config(&app, &config)?;
+    Ok(config.paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_installs_in_wine_prefix_and_launcher_text() {
+        let prefix =
+            std::env::temp_dir().join(format!("gamepath_sources_test_{}", std::process::id()));
+        let live = prefix
+            .join("drive_c/Program Files")
+            .join(RSI_FOLDER)
+            .join("StarCitizen/LIVE");
+        fs::create_dir_all(live.join("Bin64")).unwrap();
+        fs::write(live.join("Bin64/StarCitizen.exe"), b"").unwrap();
+        fs::write(live.join("Data.p4k"), b"").unwrap();
+        fs::create_dir_all(
+            prefix
+                .join("drive_c/Program Files")
+                .join(RSI_FOLDER)
+                .join("StarCitizen/PTU"),
+        )
+        .unwrap();
+
+        let found = expand_install_root(&prefix.join("drive_c/Program Files").join(RSI_FOLDER));
+        assert_eq!(found, vec![live.clone()]);
+
+        let settings =
+            r#"{"libraryFolder":"C:\\Program Files\\Roberts Space Industries","x":"D:\\Music"}"#;
+        let roots = library_roots_from_settings(settings);
+        assert_eq!(
+            roots,
+            vec![r"C:\Program Files\Roberts Space Industries".to_string()]
+        );
+        let host = windows_path_on_host(&roots[0], Some(&prefix)).unwrap();
+        assert_eq!(expand_install_root(&host), vec![live]);
+
+        let lines = vec![
+            r#"Launching C:\\Games\\StarCitizen\\PTU"#.to_string(),
+            r#"Launching C:\\Games\\StarCitizen\\LIVE"#.to_string(),
+        ];
+        assert_eq!(
+            install_paths_from_launcher_lines(&lines),
+            vec![
+                r"C:\Games\StarCitizen\LIVE".to_string(),
+                r"C:\Games\StarCitizen\PTU".to_string()
+            ]
+        );
+
+        fs::remove_dir_all(&prefix).unwrap();
+    }
+
+    #[test]
+    fn detection_is_reused_until_the_ttl_expires() {
+        let candidate = |path: &str| InstallCandidate {
+            path: PathBuf::from(path),
+            source: InstallSource::CommonRoot,
+        };
+        let start = Instant::now();
+        let mut slot = None;
+        let mut runs = 0;
+        let mut detect_at = |now: Instant, path: &str| {
+            cached_candidates(&mut slot, now, || {
+                runs += 1;
+                vec![candidate(path)]
+            })
+        };
+
+        assert_eq!(detect_at(start, "a")[0].path, PathBuf::from("a"));
+        // Sonde suivante dans le délai : le résultat précédent est servi sans relancer.
+        assert_eq!(
+            detect_at(start + DETECTION_TTL / 2, "b")[0].path,
+            PathBuf::from("a")
+        );
+        assert_eq!(
+            detect_at(start + DETECTION_TTL, "c")[0].path,
+            PathBuf::from("c")
+        );
+        assert_eq!(runs, 2);
+    }
+}
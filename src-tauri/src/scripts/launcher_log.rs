@@ -0,0 +1,616 @@
+//! Parseur des logs du launcher RSI (`rsilauncher/logs/*.log`) : chaque ligne utile devient un
+//! événement typé (connexion, canal, téléchargement / vérification, patch installé, erreur),
+//! d'où l'on déduit l'état courant de chaque canal (« LIVE en cours de patch, 62 % »).
+
+use crate::scripts::gamepath_sources::{launcher_data_dirs, launcher_log_lines};
+use chrono::{Local, NaiveDateTime, TimeZone};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use tauri::command;
+
+const DEFAULT_EVENT_LIMIT: usize = 200;
+const CHANNELS: &str = r"LIVE|PTU|EPTU|HOTFIX|TECH-PREVIEW";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LauncherOperation {
+    Download,
+    Verify,
+    Install,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum LauncherEventKind {
+    Login,
+    ChannelSelected {
+        channel: String,
+    },
+    OperationStarted {
+        operation: LauncherOperation,
+        channel: Option<String>,
+    },
+    OperationProgress {
+        operation: LauncherOperation,
+        channel: Option<String>,
+        percent: f32,
+    },
+    OperationFinished {
+        operation: LauncherOperation,
+        channel: Option<String>,
+        success: bool,
+    },
+    PatchInstalled {
+        channel: String,
+        version: String,
+    },
+    GameLaunched {
+        channel: String,
+    },
+    Error {
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts_ms: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    #[serde(flatten)]
+    pub kind: LauncherEventKind,
+}
+
+/// État d'un canal déduit des derniers événements.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherChannelStatus {
+    pub channel: String,
+    /// Opération en cours (téléchargement, vérification…), absente si le canal est au repos.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_operation: Option<LauncherOperation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installed_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherEventQuery {
+    /// Types retenus (`login`, `operationProgress`, `error`…) ; tous si vide.
+    #[serde(default)]
+    pub kinds: Vec<String>,
+    pub channel: Option<String>,
+    pub since_ms: Option<i64>,
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherLogReport {
+    /// Événements correspondant à la requête, du plus récent au plus ancien.
+    pub events: Vec<LauncherEvent>,
+    pub total_matched: u32,
+    pub channels: Vec<LauncherChannelStatus>,
+}
+
+struct RawLine {
+    ts_ms: Option<i64>,
+    level: Option<String>,
+    message: String,
+}
+
+/// Ligne des launchers 2.x : un objet JSON `{ "t":"…", "[main][info] ": "message" },`.
+fn json_object_line(line: &str) -> Option<RawLine> {
+    let trimmed = line.trim().trim_end_matches(',');
+    if !trimmed.starts_with('{') {
+        return None;
+    }
+    let object: serde_json::Map<String, serde_json::Value> = serde_json::from_str(trimmed).ok()?;
+    let (tag, value) = object.iter().find(|(key, _)| key.as_str() != "t")?;
+    let message = match value {
+        serde_json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    Some(RawLine {
+        ts_ms: object
+            .get("t")
+            .and_then(|t| t.as_str())
+            .and_then(parse_launcher_ts),
+        level: json_body_regex()
+            .captures(tag)
+            .map(|c| c[1].to_ascii_lowercase()),
+        message,
+    })
+}
+
+fn json_line_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"^\s*\{\s*"t"\s*:\s*"([^"]+)"\s*,\s*"(.*)"\s*\},?\s*$"#).unwrap()
+    })
+}
+
+fn json_body_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^\[[^\]]*\]\[(\w+)\]\s*:?\s*(.*)$").unwrap())
+}
+
+fn bracket_line_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^\[(\d{4}-\d{2}-\d{2}[ T][\d:.]+)Z?\]\s*\[(\w+)\]\s*(.*)$").unwrap()
+    })
+}
+
+/// Horodatage du launcher, sans fuseau : interprété dans le fuseau local de la machine qui lit
+/// le log (celui du launcher, sauf préfixe Wine configuré avec un autre `TZ`). À l'heure
+/// ambiguë d'un passage à l'heure d'hiver, la première occurrence est retenue.
+fn parse_launcher_ts(raw: &str) -> Option<i64> {
+    let raw = raw.trim().replace('T', " ");
+    let naive = NaiveDateTime::parse_from_str(&raw, "%Y-%m-%d %H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(&raw, "%Y-%m-%d %H:%M:%S"))
+        .ok()?;
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|dt| dt.timestamp_millis())
+}
+
+/// Découpe une ligne brute : objet JSON des launchers 2.x, variante JSON sans clé
+/// (`{ "t":"…", "[main][info] : …" },`) ou format `[date] [niveau] message`.
+fn split_line(line: &str) -> Option<RawLine> {
+    if let Some(raw) = json_object_line(line) {
+        return Some(raw);
+    }
+    if let Some(caps) = json_line_regex().captures(line) {
+        let body = caps[2].replace("\\\\", "\\").replace("\\\"", "\"");
+        let (level, message) = match json_body_regex().captures(&body) {
+            Some(b) => (Some(b[1].to_ascii_lowercase()), b[2].to_string()),
+            None => (None, body.clone()),
+        };
+        return Some(RawLine {
+            ts_ms: parse_launcher_ts(&caps[1]),
+            level,
+            message,
+        });
+    }
+    if let Some(caps) = bracket_line_regex().captures(line) {
+        return Some(RawLine {
+            ts_ms: parse_launcher_ts(&caps[1]),
+            level: Some(caps[2].to_ascii_lowercase()),
+            message: caps[3].to_string(),
+        });
+    }
+    let message = line.trim();
+    (!message.is_empty()).then(|| RawLine {
+        ts_ms: None,
+        level: None,
+        message: message.to_string(),
+    })
+}
+
+struct Patterns {
+    login: Regex,
+    channel_selected: Regex,
+    launched: Regex,
+    installed: Regex,
+    progress: Regex,
+    started: Regex,
+    finished: Regex,
+    failed: Regex,
+    channel: Regex,
+    error: Regex,
+    tags: Regex,
+}
+
+fn patterns() -> &'static Patterns {
+    static RE: OnceLock<Patterns> = OnceLock::new();
+    RE.get_or_init(|| Patterns {
+        login: Regex::new(r"(?i)\b(?:logged\s*in|signed\s*in|login\s+(?:success\w*|complete\w*)|authenticat(?:ed|ion\s+succe\w*))\b").unwrap(),
+        channel_selected: Regex::new(&format!(
+            r"(?i)\b(?:select\w*|switch\w*|chang\w*)\b[^\n]*?\bchannel\b[^\n]*?\b({CHANNELS})\b"
+        ))
+        .unwrap(),
+        launched: Regex::new(&format!(r"(?i)\blaunching\s+(?:star\s+citizen|sc)\s+({CHANNELS})\b")).unwrap(),
+        installed: Regex::new(&format!(
+            r"(?i)\b(?:install(?:ed|ation\s+complete\w*)|up\s*to\s*date|updated\s+to)\b[^\n]*?\b(?:SC|Star Citizen)\s+({CHANNELS})\s+([0-9]+(?:\.[0-9]+)+(?:[-.][A-Za-z0-9]+)*)"
+        ))
+        .unwrap(),
+        progress: Regex::new(r"(?i)\b(download\w*|verif\w*|install\w*|patch\w*|updat\w*)\b[^\n]*?(\d{1,3}(?:\.\d+)?)\s*%").unwrap(),
+        started: Regex::new(r"(?i)\b(download\w*|verif\w*|install\w*|patch\w*|updat\w*)\b[^\n]*?\b(?:start\w*|begin\w*|began)\b").unwrap(),
+        finished: Regex::new(r"(?i)\b(download\w*|verif\w*|install\w*|patch\w*|updat\w*)\b[^\n]*?\b(?:complete\w*|finish\w*|done|succe\w*)\b").unwrap(),
+        failed: Regex::new(r"(?i)\b(download\w*|verif\w*|install\w*|patch\w*|updat\w*)\b[^\n]*?\b(?:fail\w*|abort\w*|cancel\w*)\b").unwrap(),
+        channel: Regex::new(&format!(r"\b({CHANNELS})\b")).unwrap(),
+        error: Regex::new(r"(?i)^\s*(?:error|exception|fatal)\b\s*:?\s*(.*)$").unwrap(),
+        tags: Regex::new(r"^(?:\s*\[[^\]]*\])+\s*").unwrap(),
+    })
+}
+
+fn operation_from_word(word: &str) -> LauncherOperation {
+    let lower = word.to_ascii_lowercase();
+    if lower.starts_with("verif") {
+        LauncherOperation::Verify
+    } else if lower.starts_with("download") {
+        LauncherOperation::Download
+    } else {
+        LauncherOperation::Install
+    }
+}
+
+/// Événement porté par un message (`current_channel` : dernier canal sélectionné).
+fn classify(
+    message: &str,
+    level: Option<&str>,
+    current_channel: Option<&str>,
+) -> Option<LauncherEventKind> {
+    let p = patterns();
+    // Les balises (`[Patcher]`, `[Launcher::launch]`…) ne doivent pas être prises pour l'action.
+    let message = p.tags.replace(message, "");
+    let message = message.as_ref();
+    let channel = p
+        .channel
+        .captures(message)
+        .map(|c| c[1].to_ascii_uppercase())
+        .or_else(|| current_channel.map(str::to_string));
+
+    if let Some(c) = p.installed.captures(message) {
+        return Some(LauncherEventKind::PatchInstalled {
+            channel: c[1].to_ascii_uppercase(),
+            version: c[2].to_string(),
+        });
+    }
+    if let Some(c) = p.launched.captures(message) {
+        return Some(LauncherEventKind::GameLaunched {
+            channel: c[1].to_ascii_uppercase(),
+        });
+    }
+    if let Some(c) = p.channel_selected.captures(message) {
+        return Some(LauncherEventKind::ChannelSelected {
+            channel: c[1].to_ascii_uppercase(),
+        });
+    }
+    let is_error_level = matches!(level, Some("error" | "fatal"));
+    if let Some(c) = p.error.captures(message) {
+        let detail = c[1].trim();
+        return Some(LauncherEventKind::Error {
+            message: if detail.is_empty() {
+                message.trim()
+            } else {
+                detail
+            }
+            .to_string(),
+        });
+    }
+    if is_error_level {
+        return Some(LauncherEventKind::Error {
+            message: message.trim().to_string(),
+        });
+    }
+    if let Some(c) = p.failed.captures(message) {
+        return Some(LauncherEventKind::OperationFinished {
+            operation: operation_from_word(&c[1]),
+            channel,
+            success: false,
+        });
+    }
+    if let Some(c) = p.progress.captures(message) {
+        let percent: f32 = c[2].parse().ok()?;
+        return Some(LauncherEventKind::OperationProgress {
+            operation: operation_from_word(&c[1]),
+            channel,
+            percent: percent.min(100.0),
+        });
+    }
+    if let Some(c) = p.finished.captures(message) {
+        return Some(LauncherEventKind::OperationFinished {
+            operation: operation_from_word(&c[1]),
+            channel,
+            success: true,
+        });
+    }
+    if let Some(c) = p.started.captures(message) {
+        return Some(LauncherEventKind::OperationStarted {
+            operation: operation_from_word(&c[1]),
+            channel,
+        });
+    }
+    p.login
+        .is_match(message)
+        .then_some(LauncherEventKind::Login)
+}
+
+/// Événements des lignes, dans l'ordre chronologique du log.
+pub fn parse_launcher_lines(lines: &[String]) -> Vec<LauncherEvent> {
+    let mut events = Vec::new();
+    let mut current_channel: Option<String> = None;
+    for line in lines {
+        let Some(raw) = split_line(line) else {
+            continue;
+        };
+        let Some(kind) = classify(
+            &raw.message,
+            raw.level.as_deref(),
+            current_channel.as_deref(),
+        ) else {
+            continue;
+        };
+        if let LauncherEventKind::ChannelSelected { channel } = &kind {
+            current_channel = Some(channel.clone());
+        }
+        events.push(LauncherEvent {
+            ts_ms: raw.ts_ms,
+            level: raw.level,
+            kind,
+        });
+    }
+    events
+}
+
+fn event_channel(kind: &LauncherEventKind) -> Option<&str> {
+    match kind {
+        LauncherEventKind::ChannelSelected { channel }
+        | LauncherEventKind::PatchInstalled { channel, .. }
+        | LauncherEventKind::GameLaunched { channel } => Some(channel),
+        LauncherEventKind::OperationStarted { channel, .. }
+        | LauncherEventKind::OperationProgress { channel, .. }
+        | LauncherEventKind::OperationFinished { channel, .. } => channel.as_deref(),
+        LauncherEventKind::Login | LauncherEventKind::Error { .. } => None,
+    }
+}
+
+fn kind_name(kind: &LauncherEventKind) -> &'static str {
+    match kind {
+        LauncherEventKind::Login => "login",
+        LauncherEventKind::ChannelSelected { .. } => "channelSelected",
+        LauncherEventKind::OperationStarted { .. } => "operationStarted",
+        LauncherEventKind::OperationProgress { .. } => "operationProgress",
+        LauncherEventKind::OperationFinished { .. } => "operationFinished",
+        LauncherEventKind::PatchInstalled { .. } => "patchInstalled",
+        LauncherEventKind::GameLaunched { .. } => "gameLaunched",
+        LauncherEventKind::Error { .. } => "error",
+    }
+}
+
+/// Rejoue les événements pour obtenir l'état de chaque canal. Une erreur sans canal est
+/// rattachée au dernier canal actif.
+pub fn channel_statuses(events: &[LauncherEvent]) -> Vec<LauncherChannelStatus> {
+    let mut by_channel: BTreeMap<String, LauncherChannelStatus> = BTreeMap::new();
+    let mut last_channel: Option<String> = None;
+    for event in events {
+        let channel = match event_channel(&event.kind) {
+            Some(c) => c.to_string(),
+            None => match (&event.kind, &last_channel) {
+                (LauncherEventKind::Error { .. }, Some(c)) => c.clone(),
+                _ => continue,
+            },
+        };
+        last_channel = Some(channel.clone());
+        let status = by_channel
+            .entry(channel.clone())
+            .or_insert_with(|| LauncherChannelStatus {
+                channel,
+                ..LauncherChannelStatus::default()
+            });
+        status.updated_at_ms = event.ts_ms.or(status.updated_at_ms);
+        match &event.kind {
+            LauncherEventKind::OperationStarted { operation, .. } => {
+                status.active_operation = Some(*operation);
+                status.percent = Some(0.0);
+                status.last_error = None;
+            }
+            LauncherEventKind::OperationProgress {
+                operation, percent, ..
+            } => {
+                status.active_operation = Some(*operation);
+                status.percent = Some(*percent);
+            }
+            LauncherEventKind::OperationFinished { .. } => {
+                status.active_operation = None;
+                status.percent = None;
+            }
+            LauncherEventKind::PatchInstalled { version, .. } => {
+                status.installed_version = Some(version.clone());
+                status.active_operation = None;
+                status.percent = None;
+            }
+            LauncherEventKind::Error { message } => status.last_error = Some(message.clone()),
+            _ => {}
+        }
+    }
+    by_channel.into_values().collect()
+}
+
+fn matches_query(event: &LauncherEvent, query: &LauncherEventQuery) -> bool {
+    if !query.kinds.is_empty() && !query.kinds.iter().any(|k| k == kind_name(&event.kind)) {
+        return false;
+    }
+    if let Some(channel) = &query.channel {
+        if !event_channel(&event.kind).is_some_and(|c| c.eq_ignore_ascii_case(channel)) {
+            return false;
+        }
+    }
+    match (query.since_ms, event.ts_ms) {
+        (Some(since), Some(ts)) => ts >= since,
+        (Some(_), None) => false,
+        (None, _) => true,
+    }
+}
+
+/// Fusionne les événements de plusieurs dossiers du launcher (Windows, préfixes Wine) dans
+/// l'ordre chronologique. Un événement sans horodatage reste derrière le précédent de son log.
+fn merge_by_time(sources: Vec<Vec<LauncherEvent>>) -> Vec<LauncherEvent> {
+    let mut keyed: Vec<(i64, usize, usize, LauncherEvent)> = Vec::new();
+    for (source, events) in sources.into_iter().enumerate() {
+        let mut last_ts = i64::MIN;
+        for (seq, event) in events.into_iter().enumerate() {
+            last_ts = event.ts_ms.unwrap_or(last_ts);
+            keyed.push((last_ts, source, seq, event));
+        }
+    }
+    keyed.sort_by_key(|(ts, source, seq, _)| (*ts, *source, *seq));
+    keyed.into_iter().map(|(_, _, _, event)| event).collect()
+}
+
+pub fn query_launcher_events_sync(query: &LauncherEventQuery) -> LauncherLogReport {
+    let events = merge_by_time(
+        launcher_data_dirs()
+            .iter()
+            .map(|dir| parse_launcher_lines(&launcher_log_lines(dir)))
+            .collect(),
+    );
+    let channels = channel_statuses(&events);
+    let limit = query
+        .limit
+        .map(|l| l as usize)
+        .unwrap_or(DEFAULT_EVENT_LIMIT);
+    let matched: Vec<LauncherEvent> = events
+        .into_iter()
+        .rev()
+        .filter(|e| matches_query(e, query))
+        .collect();
+    LauncherLogReport {
+        total_matched: matched.len() as u32,
+        events: matched.into_iter().take(limit).collect(),
+        channels,
+    }
+}
+
+#[command]
+pub async fn query_launcher_events(
+    query: Option<LauncherEventQuery>,
+) -> Result<LauncherLogReport, String> {
+    tokio::task::spawn_blocking(move || query_launcher_events_sync(&query.unwrap_or_default()))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_launcher_lines_into_events_and_status() {
+        let lines: Vec<String> = [
+            r#"{ "t":"2026-03-01 20:00:00.000", "[main][info] : [Auth] User logged in" },"#,
+            r#"{ "t":"2026-03-01 20:00:05.000", "[browser][info] : Selected game channel LIVE" },"#,
+            r#"{ "t":"2026-03-01 20:00:06.000", "[main][info] : [Patcher] Download started" },"#,
+            r#"{ "t":"2026-03-01 20:03:00.000", "[main][info] : [Patcher] Downloading 62% (12.4 GB)" },"#,
+            r#"{ "t":"2026-03-01 20:04:00.000", "[main][error] : [Patcher] Error: disk write failed for C:\\Games\\x" },"#,
+            r#"{ "t":"2026-03-01 20:05:00.000", "[main][info] : Launching Star Citizen PTU from (C:\\Games\\StarCitizen\\PTU)" },"#,
+            r#"{ "t":"2026-03-01 20:06:00.000", "[main][info] : Installed SC PTU 4.1.0-ptu" },"#,
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+        let events = parse_launcher_lines(&lines);
+        let kinds: Vec<&str> = events.iter().map(|e| kind_name(&e.kind)).collect();
+        assert_eq!(
+            kinds,
+            [
+                "login",
+                "channelSelected",
+                "operationStarted",
+                "operationProgress",
+                "error",
+                "gameLaunched",
+                "patchInstalled"
+            ]
+        );
+        assert_eq!(
+            events[3].kind,
+            LauncherEventKind::OperationProgress {
+                operation: LauncherOperation::Download,
+                channel: Some("LIVE".to_string()),
+                percent: 62.0,
+            }
+        );
+        assert_eq!(events[4].level.as_deref(), Some("error"));
+
+        let statuses = channel_statuses(&events);
+        let live = statuses.iter().find(|s| s.channel == "LIVE").unwrap();
+        assert_eq!(live.active_operation, Some(LauncherOperation::Download));
+        assert_eq!(live.percent, Some(62.0));
+        assert!(live
+            .last_error
+            .as_deref()
+            .unwrap()
+            .starts_with("disk write failed"));
+        let ptu = statuses.iter().find(|s| s.channel == "PTU").unwrap();
+        assert_eq!(ptu.installed_version.as_deref(), Some("4.1.0-ptu"));
+    }
+
+    /// Extraits d'un `log.log` de launcher 2.x (chemins et horodatages anonymisés).
+    const LAUNCHER_2_EXCERPT: &str = r#"{ "t":"2025-02-08 18:46:55.104", "[main][info] ": "Using SC launcher version 2.3.1" },
+{ "t":"2025-02-08 18:47:03.215", "[main][info] ": "[Launcher::launch] Launching Star Citizen LIVE from (C:\\Program Files\\Roberts Space Industries\\StarCitizen\\LIVE)" },
+{ "t":"2025-02-08 18:47:03.281", "[main][info] ": "[Launcher::launch] Launch process started" },
+{ "t":"2025-02-08 18:47:04.012", "[main][info] ": "[Launcher::launch] Game process started with PID 13284" },
+{ "t":"2025-02-08 20:12:41.530", "[main][info] ": "[Launcher::launch] Game process exited with code 0" },
+{ "t":"2025-02-09 09:02:17.876", "[main][error] ": "[Launcher::launch] Error: the game process exited with code 3" },"#;
+
+    #[test]
+    fn parses_real_launcher_2_lines() {
+        let lines: Vec<String> = LAUNCHER_2_EXCERPT.lines().map(str::to_string).collect();
+        let raw = split_line(&lines[1]).unwrap();
+        assert_eq!(raw.level.as_deref(), Some("info"));
+        assert!(raw.message.starts_with("[Launcher::launch] Launching"));
+        assert!(raw.message.ends_with("StarCitizen\\LIVE)"));
+        assert_eq!(
+            raw.ts_ms,
+            parse_launcher_ts("2025-02-08 18:47:03.215"),
+            "heure locale, sans fuseau"
+        );
+
+        let events = parse_launcher_lines(&lines);
+        let kinds: Vec<&str> = events.iter().map(|e| kind_name(&e.kind)).collect();
+        assert_eq!(kinds, ["gameLaunched", "error"]);
+        assert_eq!(
+            events[0].kind,
+            LauncherEventKind::GameLaunched {
+                channel: "LIVE".to_string()
+            }
+        );
+        assert_eq!(
+            events[1].kind,
+            LauncherEventKind::Error {
+                message: "the game process exited with code 3".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn events_from_several_launcher_dirs_are_merged_by_time() {
+        let event = |ts: Option<i64>, channel: &str| LauncherEvent {
+            ts_ms: ts,
+            level: None,
+            kind: LauncherEventKind::GameLaunched {
+                channel: channel.to_string(),
+            },
+        };
+        let windows = vec![event(Some(10), "LIVE"), event(Some(30), "LIVE")];
+        let wine = vec![
+            event(Some(20), "PTU"),
+            event(None, "EPTU"),
+            event(Some(40), "PTU"),
+        ];
+        let merged: Vec<Option<i64>> = merge_by_time(vec![windows, wine])
+            .iter()
+            .map(|e| e.ts_ms)
+            .collect();
+        assert_eq!(merged, [Some(10), Some(20), None, Some(30), Some(40)]);
+    }
+}
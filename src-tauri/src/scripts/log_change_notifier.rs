@@ -0,0 +1,626 @@
+//! Attente des modifications d'un fichier de log via les notifications du système de fichiers
+//! (inotify sous Linux, `ReadDirectoryChangesW` sous Windows).
+//!
+//! Le dossier parent est surveillé plutôt que le fichier lui-même : le jeu déplace le Game.log
+//! vers `logbackups` puis en recrée un nouveau au lancement suivant.
+//! Si ce dossier est supprimé ou déplacé, la surveillance est réarmée dès qu'il réapparaît.
+//! Les lecteurs réseau (SMB/NFS, 9p sous WSL…) ne remontent pas ces notifications de façon fiable :
+//! ils sont détectés à l'avance et le tailer reste alors sur l'ancienne boucle de polling.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Nombre de croissances consécutives du fichier sans notification avant de basculer en polling.
+const MISSED_NOTIFICATIONS_BEFORE_POLLING: u32 = 3;
+/// Attente maximale sans notification avant que le tailer ne relise le fichier (filet de
+/// sécurité si une notification est perdue).
+const NOTIFY_IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Fréquence de contrôle du drapeau d'arrêt pendant une attente de notification.
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitOutcome {
+    /// Le fichier (ou son dossier) a changé.
+    Changed,
+    /// Délai écoulé sans notification.
+    TimedOut,
+}
+
+pub struct LogChangeNotifier {
+    dir: PathBuf,
+    file_name: String,
+    backend: Option<platform::DirWatch>,
+    polling: bool,
+    missed: u32,
+    last_outcome: WaitOutcome,
+    idle_timeout: Duration,
+}
+
+impl LogChangeNotifier {
+    pub fn new(log_path: &Path) -> Self {
+        let dir = log_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let file_name = log_path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let polling = !platform::SUPPORTED || platform::is_network_path(&dir);
+        Self {
+            dir,
+            file_name,
+            backend: None,
+            polling,
+            missed: 0,
+            last_outcome: WaitOutcome::TimedOut,
+            idle_timeout: NOTIFY_IDLE_TIMEOUT,
+        }
+    }
+
+    pub fn is_polling(&self) -> bool {
+        self.polling
+    }
+
+    /// Attend un changement. Avec les notifications, bloque jusqu'à l'une d'elles, l'arrêt
+    /// demandé par `stop` ou `NOTIFY_IDLE_TIMEOUT` ; le thread ne fait aucun accès disque
+    /// pendant l'attente. En mode polling (ou si le dossier n'existe pas encore), se contente
+    /// de dormir `poll_interval`, comme l'ancienne boucle.
+    pub fn wait(&mut self, poll_interval: Duration, stop: &AtomicBool) -> WaitOutcome {
+        self.last_outcome = self.wait_inner(poll_interval, stop);
+        self.last_outcome
+    }
+
+    fn wait_inner(&mut self, poll_interval: Duration, stop: &AtomicBool) -> WaitOutcome {
+        if self.polling {
+            thread::sleep(poll_interval);
+            return WaitOutcome::TimedOut;
+        }
+        if self.backend.is_none() {
+            self.backend = platform::DirWatch::new(&self.dir, &self.file_name).ok();
+        }
+        let Some(watch) = self.backend.as_mut() else {
+            thread::sleep(poll_interval);
+            return WaitOutcome::TimedOut;
+        };
+        let deadline = Instant::now() + self.idle_timeout;
+        let result = loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() || stop.load(Ordering::Relaxed) {
+                break Ok(false);
+            }
+            match watch.wait(remaining.min(STOP_CHECK_INTERVAL)) {
+                Ok(false) if !watch.is_stale() => continue,
+                other => break other,
+            }
+        };
+        // Dossier supprimé ou déplacé : la surveillance pointe vers un inode mort, elle sera
+        // réarmée à un prochain appel, une fois le dossier revenu.
+        if watch.is_stale() || !self.dir.is_dir() {
+            self.backend = None;
+        }
+        match result {
+            Ok(true) => WaitOutcome::Changed,
+            Ok(false) => WaitOutcome::TimedOut,
+            Err(_) if self.backend.is_none() => {
+                thread::sleep(poll_interval);
+                WaitOutcome::TimedOut
+            }
+            Err(e) => {
+                eprintln!(
+                    "[gamelog_watcher] notifications indisponibles pour {}: {e}, retour au polling",
+                    self.dir.display()
+                );
+                self.fall_back_to_polling();
+                thread::sleep(poll_interval);
+                WaitOutcome::TimedOut
+            }
+        }
+    }
+
+    /// À appeler quand de nouvelles données ont été lues : si elles sont arrivées sans
+    /// notification plusieurs fois de suite, le système de fichiers ne les remonte pas.
+    pub fn record_data_read(&mut self) {
+        if self.polling {
+            return;
+        }
+        if self.last_outcome == WaitOutcome::Changed {
+            self.missed = 0;
+            return;
+        }
+        self.missed += 1;
+        if self.missed >= MISSED_NOTIFICATIONS_BEFORE_POLLING {
+            eprintln!(
+                "[gamelog_watcher] aucune notification reçue pour {}, retour au polling",
+                self.dir.display()
+            );
+            self.fall_back_to_polling();
+        }
+    }
+
+    fn fall_back_to_polling(&mut self) {
+        self.polling = true;
+        self.backend = None;
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::ffi::CString;
+    use std::io;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+    use std::time::Duration;
+
+    pub const SUPPORTED: bool = true;
+
+    const NFS_SUPER_MAGIC: i64 = 0x6969;
+    const SMB_SUPER_MAGIC: i64 = 0x517B;
+    const CIFS_MAGIC_NUMBER: i64 = 0xFF53_4D42;
+    const SMB2_MAGIC_NUMBER: i64 = 0xFE53_4D42;
+    const V9FS_MAGIC: i64 = 0x0102_1997;
+    const FUSE_SUPER_MAGIC: i64 = 0x6573_5546;
+
+    pub fn is_network_path(dir: &Path) -> bool {
+        let Ok(c_path) = CString::new(dir.as_os_str().as_bytes()) else {
+            return false;
+        };
+        let mut stat: libc::statfs = unsafe { std::mem::zeroed() };
+        if unsafe { libc::statfs(c_path.as_ptr(), &mut stat) } != 0 {
+            return false;
+        }
+        matches!(
+            stat.f_type as i64,
+            NFS_SUPER_MAGIC
+                | SMB_SUPER_MAGIC
+                | CIFS_MAGIC_NUMBER
+                | SMB2_MAGIC_NUMBER
+                | V9FS_MAGIC
+                | FUSE_SUPER_MAGIC
+        )
+    }
+
+    pub struct DirWatch {
+        fd: libc::c_int,
+        file_name: Vec<u8>,
+        stale: bool,
+    }
+
+    impl DirWatch {
+        pub fn new(dir: &Path, file_name: &str) -> io::Result<Self> {
+            let c_dir = CString::new(dir.as_os_str().as_bytes())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            let fd = unsafe { libc::inotify_init1(libc::IN_NONBLOCK | libc::IN_CLOEXEC) };
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let mask = libc::IN_MODIFY
+                | libc::IN_CLOSE_WRITE
+                | libc::IN_CREATE
+                | libc::IN_DELETE
+                | libc::IN_MOVED_FROM
+                | libc::IN_MOVED_TO
+                | libc::IN_DELETE_SELF
+                | libc::IN_MOVE_SELF;
+            if unsafe { libc::inotify_add_watch(fd, c_dir.as_ptr(), mask) } < 0 {
+                let err = io::Error::last_os_error();
+                unsafe { libc::close(fd) };
+                return Err(err);
+            }
+            Ok(Self {
+                fd,
+                file_name: file_name.as_bytes().to_vec(),
+                stale: false,
+            })
+        }
+
+        /// Le dossier surveillé a été supprimé ou déplacé : plus aucun événement n'arrivera.
+        pub fn is_stale(&self) -> bool {
+            self.stale
+        }
+
+        /// `Ok(true)` si un événement concerne le fichier suivi (ou le dossier lui-même).
+        pub fn wait(&mut self, timeout: Duration) -> io::Result<bool> {
+            let mut pfd = libc::pollfd {
+                fd: self.fd,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            let ready = unsafe { libc::poll(&mut pfd, 1, timeout.as_millis() as libc::c_int) };
+            if ready < 0 {
+                let err = io::Error::last_os_error();
+                return match err.kind() {
+                    io::ErrorKind::Interrupted => Ok(false),
+                    _ => Err(err),
+                };
+            }
+            if ready == 0 {
+                return Ok(false);
+            }
+            self.drain()
+        }
+
+        fn drain(&mut self) -> io::Result<bool> {
+            let header_len = std::mem::size_of::<libc::inotify_event>();
+            let mut buf = [0u8; 4096];
+            let mut relevant = false;
+            loop {
+                let n = unsafe { libc::read(self.fd, buf.as_mut_ptr().cast(), buf.len()) };
+                if n < 0 {
+                    let err = io::Error::last_os_error();
+                    return match err.kind() {
+                        io::ErrorKind::WouldBlock | io::ErrorKind::Interrupted => Ok(relevant),
+                        _ => Err(err),
+                    };
+                }
+                let n = n as usize;
+                let mut offset = 0;
+                while offset + header_len <= n {
+                    let event: libc::inotify_event =
+                        unsafe { std::ptr::read_unaligned(buf[offset..].as_ptr().cast()) };
+                    let name_start = offset + header_len;
+                    let name_end = (name_start + event.len as usize).min(n);
+                    let name = &buf[name_start..name_end];
+                    let name = &name[..name.iter().position(|&b| b == 0).unwrap_or(name.len())];
+                    let gone = libc::IN_DELETE_SELF | libc::IN_MOVE_SELF | libc::IN_IGNORED;
+                    if event.mask & gone != 0 {
+                        self.stale = true;
+                    }
+                    if event.mask & libc::IN_Q_OVERFLOW != 0
+                        || event.mask & gone != 0
+                        || name == self.file_name.as_slice()
+                    {
+                        relevant = true;
+                    }
+                    offset = name_end;
+                }
+            }
+        }
+    }
+
+    impl Drop for DirWatch {
+        fn drop(&mut self) {
+            unsafe { libc::close(self.fd) };
+        }
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::io;
+    use std::os::windows::ffi::OsStrExt;
+    use std::path::{Component, Path, Prefix};
+    use std::time::Duration;
+    use windows_sys::Win32::Foundation::{
+        CloseHandle, GetLastError, ERROR_IO_PENDING, HANDLE, INVALID_HANDLE_VALUE, WAIT_OBJECT_0,
+        WAIT_TIMEOUT,
+    };
+    use windows_sys::Win32::Storage::FileSystem::{
+        CreateFileW, GetDriveTypeW, ReadDirectoryChangesW, FILE_FLAG_BACKUP_SEMANTICS,
+        FILE_FLAG_OVERLAPPED, FILE_LIST_DIRECTORY, FILE_NOTIFY_CHANGE_FILE_NAME,
+        FILE_NOTIFY_CHANGE_LAST_WRITE, FILE_NOTIFY_CHANGE_SIZE, FILE_NOTIFY_INFORMATION,
+        FILE_SHARE_DELETE, FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING,
+    };
+    use windows_sys::Win32::System::Threading::{CreateEventW, WaitForSingleObject};
+    use windows_sys::Win32::System::WindowsProgramming::DRIVE_REMOTE;
+    use windows_sys::Win32::System::IO::{CancelIoEx, GetOverlappedResult, OVERLAPPED};
+
+    pub const SUPPORTED: bool = true;
+
+    /// Taille du tampon de notifications (en `u32` pour l'alignement exigé par l'API).
+    const BUFFER_WORDS: usize = 16 * 1024;
+
+    fn wide(path: &Path) -> Vec<u16> {
+        path.as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect()
+    }
+
+    pub fn is_network_path(dir: &Path) -> bool {
+        match dir.components().next() {
+            Some(Component::Prefix(prefix)) => match prefix.kind() {
+                Prefix::UNC(..) | Prefix::VerbatimUNC(..) => true,
+                Prefix::Disk(letter) | Prefix::VerbatimDisk(letter) => {
+                    let root = format!("{}:\\", letter as char);
+                    let root: Vec<u16> = root.encode_utf16().chain(std::iter::once(0)).collect();
+                    unsafe { GetDriveTypeW(root.as_ptr()) == DRIVE_REMOTE }
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Lecture asynchrone (`OVERLAPPED`) des changements du dossier : les événements nomment
+    /// le fichier concerné, seuls ceux du fichier suivi réveillent le tailer.
+    pub struct DirWatch {
+        dir: HANDLE,
+        event: HANDLE,
+        // En boîte : l'adresse reste stable tant qu'une lecture est en cours.
+        overlapped: Box<OVERLAPPED>,
+        buffer: Box<[u32]>,
+        file_name: String,
+        pending: bool,
+        stale: bool,
+    }
+
+    // Les handles ne sont utilisés que par le thread du tailer qui les possède.
+    unsafe impl Send for DirWatch {}
+
+    impl DirWatch {
+        pub fn new(dir: &Path, file_name: &str) -> io::Result<Self> {
+            let path = wide(dir);
+            let dir_handle = unsafe {
+                CreateFileW(
+                    path.as_ptr(),
+                    FILE_LIST_DIRECTORY,
+                    FILE_SHARE_READ | FILE_SHARE_WRITE | FILE_SHARE_DELETE,
+                    std::ptr::null(),
+                    OPEN_EXISTING,
+                    FILE_FLAG_BACKUP_SEMANTICS | FILE_FLAG_OVERLAPPED,
+                    std::ptr::null_mut(),
+                )
+            };
+            if dir_handle == INVALID_HANDLE_VALUE {
+                return Err(io::Error::last_os_error());
+            }
+            let event = unsafe { CreateEventW(std::ptr::null(), 1, 0, std::ptr::null()) };
+            if event.is_null() {
+                let err = io::Error::last_os_error();
+                unsafe { CloseHandle(dir_handle) };
+                return Err(err);
+            }
+            let mut overlapped: Box<OVERLAPPED> = Box::new(unsafe { std::mem::zeroed() });
+            overlapped.hEvent = event;
+            let mut watch = Self {
+                dir: dir_handle,
+                event,
+                overlapped,
+                buffer: vec![0u32; BUFFER_WORDS].into_boxed_slice(),
+                file_name: file_name.to_string(),
+                pending: false,
+                stale: false,
+            };
+            watch.arm()?;
+            Ok(watch)
+        }
+
+        fn arm(&mut self) -> io::Result<()> {
+            let ok = unsafe {
+                ReadDirectoryChangesW(
+                    self.dir,
+                    self.buffer.as_mut_ptr().cast(),
+                    (self.buffer.len() * 4) as u32,
+                    0,
+                    FILE_NOTIFY_CHANGE_FILE_NAME
+                        | FILE_NOTIFY_CHANGE_SIZE
+                        | FILE_NOTIFY_CHANGE_LAST_WRITE,
+                    std::ptr::null_mut(),
+                    &mut *self.overlapped,
+                    None,
+                )
+            };
+            if ok == 0 && unsafe { GetLastError() } != ERROR_IO_PENDING {
+                return Err(io::Error::last_os_error());
+            }
+            self.pending = true;
+            Ok(())
+        }
+
+        /// Le dossier surveillé a été supprimé ou déplacé : la lecture en cours a échoué.
+        pub fn is_stale(&self) -> bool {
+            self.stale
+        }
+
+        /// `Ok(true)` si un événement concerne le fichier suivi (ou si des événements ont été
+        /// perdus, tampon plein).
+        pub fn wait(&mut self, timeout: Duration) -> io::Result<bool> {
+            if !self.pending {
+                self.arm()?;
+            }
+            match unsafe { WaitForSingleObject(self.event, timeout.as_millis() as u32) } {
+                WAIT_OBJECT_0 => {}
+                WAIT_TIMEOUT => return Ok(false),
+                _ => return Err(io::Error::last_os_error()),
+            }
+            self.pending = false;
+            let mut bytes = 0u32;
+            if unsafe { GetOverlappedResult(self.dir, &*self.overlapped, &mut bytes, 0) } == 0 {
+                // Dossier supprimé ou inaccessible : réarmé par l'appelant quand il revient.
+                self.stale = true;
+                return Ok(true);
+            }
+            let relevant = bytes == 0 || self.mentions_file(bytes as usize);
+            self.arm()?;
+            Ok(relevant)
+        }
+
+        fn mentions_file(&self, len: usize) -> bool {
+            let base = self.buffer.as_ptr().cast::<u8>();
+            let name_offset = std::mem::offset_of!(FILE_NOTIFY_INFORMATION, FileName);
+            let mut offset = 0usize;
+            while offset + name_offset <= len {
+                let info = unsafe { &*base.add(offset).cast::<FILE_NOTIFY_INFORMATION>() };
+                let name_len = info.FileNameLength as usize / 2;
+                let name = unsafe {
+                    std::slice::from_raw_parts(
+                        base.add(offset + name_offset).cast::<u16>(),
+                        name_len,
+                    )
+                };
+                if String::from_utf16_lossy(name).eq_ignore_ascii_case(&self.file_name) {
+                    return true;
+                }
+                if info.NextEntryOffset == 0 {
+                    break;
+                }
+                offset += info.NextEntryOffset as usize;
+            }
+            false
+        }
+    }
+
+    impl Drop for DirWatch {
+        fn drop(&mut self) {
+            unsafe {
+                if self.pending {
+                    // Le tampon ne doit pas être libéré tant que le noyau peut y écrire.
+                    CancelIoEx(self.dir, &*self.overlapped);
+                    let mut bytes = 0u32;
+                    GetOverlappedResult(self.dir, &*self.overlapped, &mut bytes, 1);
+                }
+                CloseHandle(self.event);
+                CloseHandle(self.dir);
+            }
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+mod platform {
+    use std::io;
+    use std::path::Path;
+    use std::time::Duration;
+
+    pub const SUPPORTED: bool = false;
+
+    pub fn is_network_path(_dir: &Path) -> bool {
+        false
+    }
+
+    pub struct DirWatch;
+
+    impl DirWatch {
+        pub fn new(_dir: &Path, _file_name: &str) -> io::Result<Self> {
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "notifications non supportées",
+            ))
+        }
+
+        pub fn wait(&mut self, _timeout: Duration) -> io::Result<bool> {
+            Ok(false)
+        }
+
+        pub fn is_stale(&self) -> bool {
+            false
+        }
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const TAIL_TEST_POLL: Duration = Duration::from_millis(200);
+
+    fn wait_for(notifier: &mut LogChangeNotifier, timeout: Duration) -> WaitOutcome {
+        notifier.idle_timeout = timeout;
+        notifier.wait(timeout, &AtomicBool::new(false))
+    }
+
+    #[test]
+    fn appending_to_log_wakes_the_notifier() {
+        let dir = std::env::temp_dir().join(format!("multitool_notify_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let log = dir.join("Game.log");
+        std::fs::write(&log, "start\n").unwrap();
+
+        let mut notifier = LogChangeNotifier::new(&log);
+        if notifier.is_polling() {
+            // tmp sur un montage réseau : rien à vérifier ici.
+            let _ = std::fs::remove_dir_all(&dir);
+            return;
+        }
+        // Premier appel : arme la surveillance (aucun changement en attente).
+        assert_eq!(
+            wait_for(&mut notifier, Duration::from_millis(10)),
+            WaitOutcome::TimedOut
+        );
+
+        let writer_log = log.clone();
+        let writer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            let mut f = std::fs::OpenOptions::new()
+                .append(true)
+                .open(writer_log)
+                .unwrap();
+            writeln!(f, "next line").unwrap();
+        });
+        // Délai plus long que celui de l'écrivain : c'est la notification qui réveille.
+        assert_eq!(
+            wait_for(&mut notifier, Duration::from_secs(2)),
+            WaitOutcome::Changed
+        );
+        writer.join().unwrap();
+
+        // Un autre fichier du dossier ne réveille pas le tailer.
+        std::fs::write(dir.join("other.txt"), "x").unwrap();
+        assert_eq!(
+            wait_for(&mut notifier, Duration::from_millis(10)),
+            WaitOutcome::TimedOut
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn watch_is_rearmed_when_directory_comes_back() {
+        let dir =
+            std::env::temp_dir().join(format!("multitool_notify_rearm_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let log = dir.join("Game.log");
+        std::fs::write(&log, "start\n").unwrap();
+
+        let mut notifier = LogChangeNotifier::new(&log);
+        if notifier.is_polling() {
+            let _ = std::fs::remove_dir_all(&dir);
+            return;
+        }
+        wait_for(&mut notifier, Duration::from_millis(10));
+        assert!(notifier.backend.is_some());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        wait_for(&mut notifier, Duration::from_millis(50));
+        assert!(notifier.backend.is_none());
+        assert!(!notifier.is_polling());
+
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&log, "new session\n").unwrap();
+        // Réarme la surveillance sur le nouveau dossier.
+        wait_for(&mut notifier, Duration::from_millis(10));
+        assert!(notifier.backend.is_some());
+
+        let mut f = std::fs::OpenOptions::new().append(true).open(&log).unwrap();
+        writeln!(f, "next line").unwrap();
+        assert_eq!(
+            wait_for(&mut notifier, Duration::from_millis(500)),
+            WaitOutcome::Changed
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn stop_flag_interrupts_an_idle_wait() {
+        let dir =
+            std::env::temp_dir().join(format!("multitool_notify_stop_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let log = dir.join("Game.log");
+        std::fs::write(&log, "start\n").unwrap();
+
+        let mut notifier = LogChangeNotifier::new(&log);
+        if notifier.is_polling() {
+            let _ = std::fs::remove_dir_all(&dir);
+            return;
+        }
+        let stop = AtomicBool::new(true);
+        let started = Instant::now();
+        assert_eq!(notifier.wait(TAIL_TEST_POLL, &stop), WaitOutcome::TimedOut);
+        // Bien avant `NOTIFY_IDLE_TIMEOUT` : l'arrêt du tailer n'attend pas la fin du délai.
+        assert!(started.elapsed() < STOP_CHECK_INTERVAL * 2);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -3,19 +3,35 @@ pub mod blueprint_family;
 pub mod blueprint_manual_owned;
 pub mod blueprint_wishlist;
 pub mod blueprints_catalog;
+pub mod blueprints_import;
 pub mod blueprints_item_profile;
 pub mod blueprints_wiki_extended;
 pub mod cache_functions;
+pub mod disk_usage;
 pub mod game_config_backup;
 pub mod game_log;
+pub mod game_process;
 pub mod gamelog_archive;
+pub mod gamelog_archive_bundle;
+pub mod gamelog_archive_verify;
+pub mod gamelog_channels;
+pub mod gamelog_crash_index;
+pub mod gamelog_events;
+pub mod gamelog_replay;
+pub mod gamelog_search;
 pub mod gamelog_watcher;
 pub mod gamepath;
+pub mod gamepath_sources;
 pub mod hangar_exec;
 pub mod home_dashboard;
+pub mod launcher_log;
 pub mod local_characters_functions;
+pub mod log_change_notifier;
+pub mod notification_rules;
 pub mod onboarding;
+pub mod p4k;
 pub mod paints_catalog;
+pub mod patch_hooks;
 pub mod patchnote;
 pub mod presets_list_functions;
 pub mod recent_routes;
@@ -25,7 +41,10 @@ pub mod startup_manager;
 pub mod system_metrics;
 pub mod system_tray;
 pub mod theme_preferences;
+pub mod translation_diff;
 pub mod translation_functions;
+pub mod translation_languages;
+pub mod translation_overrides;
 pub mod translation_preferences;
 pub mod translations_links;
 pub mod vehicles_catalog;
@@ -0,0 +1,481 @@
+//! Règles de notifications bureau sur les événements du gamelog watcher (blueprint de la
+//! wishlist reçu, mission échouée, gros achat…), avec heures calmes par règle.
+//!
+//! Le moteur s'abonne au bus in-process de `gamelog_events` ; pour un événement, seule la
+//! première règle active qui correspond envoie une notification. Les événements de rattrapage
+//! (`backfill`, relus au démarrage du watcher) ne notifient jamais.
+
+use crate::scripts::blueprint_manual_owned::manual_owned_blueprint_ids;
+use crate::scripts::blueprint_wishlist::wishlist_blueprint_ids;
+use crate::scripts::gamelog_events::{subscribe_watcher_events, WatcherEvent, WatcherEventKind};
+use crate::scripts::gamelog_watcher::{load_blueprint_store_sync, BlueprintEntry};
+use chrono::{Local, Timelike};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::path::PathResolver;
+use tauri::{command, AppHandle, Manager, Runtime, State};
+use tauri_plugin_notification::NotificationExt;
+use tokio::sync::broadcast::error::RecvError;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum NotificationTrigger {
+    /// Blueprint reçu dont l'ID catalogue est dans la wishlist.
+    WishlistBlueprint,
+    AnyBlueprint,
+    /// Mission terminée sans `CompletionType[Complete]`.
+    MissionFailed,
+    #[serde(rename_all = "camelCase")]
+    PurchaseAbove {
+        min_price: f64,
+    },
+}
+
+/// Plage `HH:MM` locale ; peut passer minuit (ex. 23:00 → 08:00).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuietHours {
+    pub start: String,
+    pub end: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationRule {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub enabled: bool,
+    pub trigger: NotificationTrigger,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quiet_hours: Option<QuietHours>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationRulesConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub rules: Vec<NotificationRule>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Default for NotificationRulesConfig {
+    fn default() -> Self {
+        let rule = |id: &str, name: &str, enabled: bool, trigger| NotificationRule {
+            id: id.to_string(),
+            name: name.to_string(),
+            enabled,
+            trigger,
+            quiet_hours: None,
+        };
+        Self {
+            enabled: true,
+            rules: vec![
+                rule(
+                    "wishlist-blueprint",
+                    "Blueprint de la wishlist reçu",
+                    true,
+                    NotificationTrigger::WishlistBlueprint,
+                ),
+                rule(
+                    "any-blueprint",
+                    "Nouveau blueprint",
+                    false,
+                    NotificationTrigger::AnyBlueprint,
+                ),
+                rule(
+                    "mission-failed",
+                    "Mission échouée",
+                    false,
+                    NotificationTrigger::MissionFailed,
+                ),
+                rule(
+                    "big-purchase",
+                    "Achat important",
+                    false,
+                    NotificationTrigger::PurchaseAbove {
+                        min_price: 100_000.0,
+                    },
+                ),
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct NotificationRulesState {
+    config: Arc<Mutex<NotificationRulesConfig>>,
+}
+
+struct PendingNotification {
+    title: String,
+    body: String,
+}
+
+fn config_path(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+    let dir = resolver
+        .app_config_dir()
+        .map_err(|_| "Impossible d'obtenir le répertoire de configuration".to_string())?;
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir.join("notification_rules.json"))
+}
+
+pub fn load_notification_rules_sync(app: &AppHandle) -> Result<NotificationRulesConfig, String> {
+    let path = config_path(app.path())?;
+    if !path.exists() {
+        return Ok(NotificationRulesConfig::default());
+    }
+    let json = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+fn parse_hhmm(value: &str) -> Result<u32, String> {
+    let (h, m) = value
+        .trim()
+        .split_once(':')
+        .ok_or_else(|| format!("Heure invalide : {value} (attendu HH:MM)"))?;
+    let h: u32 = h.parse().map_err(|_| format!("Heure invalide : {value}"))?;
+    let m: u32 = m.parse().map_err(|_| format!("Heure invalide : {value}"))?;
+    if h > 23 || m > 59 {
+        return Err(format!("Heure invalide : {value}"));
+    }
+    Ok(h * 60 + m)
+}
+
+/// `minute_of_day` dans la plage ; `start == end` désactive les heures calmes.
+fn in_quiet_hours(quiet: &QuietHours, minute_of_day: u32) -> bool {
+    let (Ok(start), Ok(end)) = (parse_hhmm(&quiet.start), parse_hhmm(&quiet.end)) else {
+        return false;
+    };
+    if start <= end {
+        (start..end).contains(&minute_of_day)
+    } else {
+        minute_of_day >= start || minute_of_day < end
+    }
+}
+
+fn validate_config(config: &NotificationRulesConfig) -> Result<(), String> {
+    let mut ids = HashSet::new();
+    for rule in &config.rules {
+        if rule.id.trim().is_empty() || !ids.insert(rule.id.as_str()) {
+            return Err(format!(
+                "Identifiant de règle invalide ou dupliqué : {}",
+                rule.id
+            ));
+        }
+        if let Some(quiet) = &rule.quiet_hours {
+            parse_hhmm(&quiet.start)?;
+            parse_hhmm(&quiet.end)?;
+        }
+    }
+    Ok(())
+}
+
+fn notification_for_rule(
+    rule: &NotificationRule,
+    event: &WatcherEvent,
+    wishlist: &HashSet<String>,
+) -> Option<PendingNotification> {
+    let (title, body) = match (&rule.trigger, &event.kind) {
+        (NotificationTrigger::WishlistBlueprint, WatcherEventKind::BlueprintReceived { entry }) => {
+            let id = entry.catalog_blueprint_id.as_deref()?.to_ascii_lowercase();
+            if !wishlist.contains(&id) {
+                return None;
+            }
+            (
+                "Blueprint de la wishlist reçu".to_string(),
+                entry.product_name.clone(),
+            )
+        }
+        (NotificationTrigger::AnyBlueprint, WatcherEventKind::BlueprintReceived { entry }) => {
+            ("Nouveau blueprint".to_string(), entry.product_name.clone())
+        }
+        (
+            NotificationTrigger::MissionFailed,
+            WatcherEventKind::MissionEnded {
+                debug_name,
+                completion_type,
+                reason,
+                ..
+            },
+        ) => {
+            if completion_type.eq_ignore_ascii_case("Complete") {
+                return None;
+            }
+            let mission = debug_name.as_deref().unwrap_or("Mission inconnue");
+            let body = match reason {
+                Some(reason) => format!("{mission} — {reason}"),
+                None => mission.to_string(),
+            };
+            (format!("Mission {completion_type}"), body)
+        }
+        (
+            NotificationTrigger::PurchaseAbove { min_price },
+            WatcherEventKind::Purchase {
+                shop_name,
+                item,
+                price,
+                quantity,
+            },
+        ) => {
+            if *price < *min_price {
+                return None;
+            }
+            (
+                "Achat important".to_string(),
+                format!("{quantity} × {item} — {price:.0} aUEC ({shop_name})"),
+            )
+        }
+        _ => return None,
+    };
+    let title = if event.replay {
+        format!("[Replay] {title}")
+    } else {
+        title
+    };
+    Some(PendingNotification { title, body })
+}
+
+/// Première règle active qui correspond, hors heures calmes.
+fn notification_for_event(
+    config: &NotificationRulesConfig,
+    event: &WatcherEvent,
+    wishlist: &HashSet<String>,
+    minute_of_day: u32,
+) -> Option<PendingNotification> {
+    if !config.enabled || event.backfill {
+        return None;
+    }
+    config
+        .rules
+        .iter()
+        .filter(|rule| rule.enabled)
+        .filter(|rule| {
+            rule.quiet_hours
+                .as_ref()
+                .is_none_or(|q| !in_quiet_hours(q, minute_of_day))
+        })
+        .find_map(|rule| notification_for_rule(rule, event, wishlist))
+}
+
+/// Blueprint déjà possédé avant cet événement : reçu plus tôt par le même joueur (le store
+/// garde la première réception) ou marqué possédé à la main.
+fn already_owned(
+    entry: &BlueprintEntry,
+    stored: &[BlueprintEntry],
+    manual: &HashSet<String>,
+) -> bool {
+    let id = entry.catalog_blueprint_id.as_deref();
+    if id.is_some_and(|id| manual.contains(&id.to_ascii_lowercase())) {
+        return true;
+    }
+    stored.iter().any(|e| {
+        e.owner == entry.owner
+            && e.ts < entry.ts
+            && (e.product_name.eq_ignore_ascii_case(&entry.product_name)
+                || id.is_some_and(|id| {
+                    e.catalog_blueprint_id
+                        .as_deref()
+                        .is_some_and(|other| other.eq_ignore_ascii_case(id))
+                }))
+    })
+}
+
+fn handle_event(app: &AppHandle, state: &NotificationRulesState, event: &WatcherEvent) {
+    let config = state.config.lock().map(|c| c.clone()).unwrap_or_default();
+    // Wishlist vide si le blueprint était déjà possédé : la règle wishlist ne se déclenche pas.
+    let wishlist = match &event.kind {
+        WatcherEventKind::BlueprintReceived { entry } => {
+            let stored = load_blueprint_store_sync(app)
+                .map(|s| s.blueprints)
+                .unwrap_or_default();
+            if already_owned(entry, &stored, &manual_owned_blueprint_ids(app.path())) {
+                HashSet::new()
+            } else {
+                wishlist_blueprint_ids(app.path())
+            }
+        }
+        _ => HashSet::new(),
+    };
+    let now = Local::now();
+    let minute_of_day = now.hour() * 60 + now.minute();
+    let Some(notification) = notification_for_event(&config, event, &wishlist, minute_of_day)
+    else {
+        return;
+    };
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title(notification.title)
+        .body(notification.body)
+        .show()
+    {
+        eprintln!("[notification_rules] failed to show notification: {e}");
+    }
+}
+
+/// Abonne le moteur de règles au bus des événements du watcher (avant tout démarrage du watcher,
+/// pour ne manquer aucun événement).
+pub fn start_notification_rules_engine(app: &AppHandle, state: &NotificationRulesState) {
+    match load_notification_rules_sync(app) {
+        Ok(config) => {
+            if let Ok(mut guard) = state.config.lock() {
+                *guard = config;
+            }
+        }
+        Err(e) => eprintln!("[notification_rules] failed to load rules: {e}"),
+    }
+    let app = app.clone();
+    let state = state.clone();
+    let mut rx = subscribe_watcher_events();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(event) => handle_event(&app, &state, &event),
+                Err(RecvError::Lagged(skipped)) => {
+                    eprintln!("[notification_rules] {skipped} events skipped");
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+#[command]
+pub fn get_notification_rules(
+    state: State<'_, NotificationRulesState>,
+) -> Result<NotificationRulesConfig, String> {
+    state
+        .config
+        .lock()
+        .map(|c| c.clone())
+        .map_err(|e| e.to_string())
+}
+
+#[command]
+pub fn save_notification_rules(
+    app: AppHandle,
+    state: State<'_, NotificationRulesState>,
+    config: NotificationRulesConfig,
+) -> Result<(), String> {
+    validate_config(&config)?;
+    let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    fs::write(config_path(app.path())?, json).map_err(|e| e.to_string())?;
+    *state.config.lock().map_err(|e| e.to_string())? = config;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blueprint_event(catalog_id: Option<&str>) -> WatcherEvent {
+        WatcherEvent {
+            channel: "LIVE".to_string(),
+            ts: 0.0,
+            replay: false,
+            backfill: false,
+            kind: WatcherEventKind::BlueprintReceived {
+                entry: BlueprintEntry {
+                    owner: "Onivoid".to_string(),
+                    product_name: "Morozov Legs".to_string(),
+                    ts: 0.0,
+                    mission_guid: None,
+                    mission_debug_name: None,
+                    mission_trigger: None,
+                    catalog_blueprint_id: catalog_id.map(str::to_string),
+                    correlation_confidence: None,
+                    correlation_alternatives: Vec::new(),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn quiet_hours_wrap_around_midnight() {
+        let quiet = QuietHours {
+            start: "23:00".to_string(),
+            end: "08:00".to_string(),
+        };
+        assert!(in_quiet_hours(&quiet, 23 * 60 + 30));
+        assert!(in_quiet_hours(&quiet, 7 * 60));
+        assert!(!in_quiet_hours(&quiet, 12 * 60));
+        assert!(parse_hhmm("24:00").is_err());
+    }
+
+    #[test]
+    fn wishlist_rule_wins_and_respects_quiet_hours() {
+        let mut config = NotificationRulesConfig::default();
+        config.rules[1].enabled = true;
+        let wishlist: HashSet<String> = ["bp_craft_morozov_legs".to_string()].into();
+
+        let hit = notification_for_event(
+            &config,
+            &blueprint_event(Some("BP_CRAFT_Morozov_Legs")),
+            &wishlist,
+            12 * 60,
+        )
+        .unwrap();
+        assert_eq!(hit.title, "Blueprint de la wishlist reçu");
+
+        let other =
+            notification_for_event(&config, &blueprint_event(None), &wishlist, 12 * 60).unwrap();
+        assert_eq!(other.title, "Nouveau blueprint");
+
+        let backfilled = WatcherEvent {
+            backfill: true,
+            ..blueprint_event(Some("bp_craft_morozov_legs"))
+        };
+        assert!(notification_for_event(&config, &backfilled, &wishlist, 12 * 60).is_none());
+
+        for rule in &mut config.rules {
+            rule.quiet_hours = Some(QuietHours {
+                start: "11:00".to_string(),
+                end: "13:00".to_string(),
+            });
+        }
+        assert!(notification_for_event(
+            &config,
+            &blueprint_event(Some("bp_craft_morozov_legs")),
+            &wishlist,
+            12 * 60
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn blueprints_received_before_count_as_owned() {
+        let WatcherEventKind::BlueprintReceived { entry } =
+            blueprint_event(Some("bp_craft_morozov_legs")).kind
+        else {
+            unreachable!()
+        };
+        let earlier = BlueprintEntry {
+            ts: -10.0,
+            catalog_blueprint_id: None,
+            ..entry.clone()
+        };
+        let none = HashSet::new();
+
+        // Le store contient déjà l'événement courant (persisté avant publication).
+        assert!(!already_owned(&entry, std::slice::from_ref(&entry), &none));
+        assert!(already_owned(&entry, std::slice::from_ref(&earlier), &none));
+        let other_player = BlueprintEntry {
+            owner: "Someone".to_string(),
+            ..earlier
+        };
+        assert!(!already_owned(&entry, &[other_player], &none));
+        let manual: HashSet<String> = ["bp_craft_morozov_legs".to_string()].into();
+        assert!(already_owned(&entry, &[], &manual));
+    }
+}
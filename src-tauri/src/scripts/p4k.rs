@@ -0,0 +1,544 @@
+//! Lecture seule de `Data.p4k` (conteneur ZIP64 du jeu) : listing des entrées, métadonnées et
+//! extraction d'un fichier isolé. Le répertoire central est parcouru en flux (plus d'un million
+//! d'entrées) et le contenu n'est jamais chargé en entier ; l'archive n'est ouverte qu'en lecture.
+
+use chrono::NaiveDate;
+use flate2::read::DeflateDecoder;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use tauri::command;
+
+const EOCD_SIG: u32 = 0x0605_4b50;
+const ZIP64_LOCATOR_SIG: u32 = 0x0706_4b50;
+const ZIP64_EOCD_SIG: u32 = 0x0606_4b50;
+const CENTRAL_SIG: u32 = 0x0201_4b50;
+const LOCAL_SIG: u32 = 0x0403_4b50;
+/// En-tête local propre aux `Data.p4k` de CIG (`PK\x03\x14`), même disposition que le standard.
+const CIG_LOCAL_SIG: u32 = 0x1403_4b50;
+const EOCD_LEN: usize = 22;
+const ZIP64_LOCATOR_LEN: usize = 20;
+const CENTRAL_HEADER_LEN: usize = 46;
+const LOCAL_HEADER_LEN: usize = 30;
+/// Valeur signalant qu'un champ 32 bits est déporté dans l'extra ZIP64.
+const ZIP64_MARKER: u32 = u32::MAX;
+
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATE: u16 = 8;
+const METHOD_ZSTD: u16 = 93;
+/// Zstandard sous l'identifiant propre à CIG.
+const METHOD_CIG_ZSTD: u16 = 100;
+
+/// `global.ini` anglais embarqué dans le jeu.
+pub const P4K_GLOBAL_INI_EN: &str = "Data\\Localization\\english\\global.ini";
+const DEFAULT_LIST_LIMIT: usize = 500;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P4kEntry {
+    /// Chemin tel que stocké (séparateurs `\`).
+    pub name: String,
+    pub compressed_size: u64,
+    pub uncompressed_size: u64,
+    pub method: u16,
+    pub crc32: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modified_ms: Option<i64>,
+    /// Entrée chiffrée : listée mais pas extractible.
+    pub encrypted: bool,
+    #[serde(skip)]
+    local_header_offset: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct P4kListing {
+    pub total_entries: u64,
+    pub matched: u64,
+    pub entries: Vec<P4kEntry>,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CentralDirectory {
+    offset: u64,
+    entries: u64,
+}
+
+/// Archive `Data.p4k` ouverte en lecture.
+pub struct P4kArchive {
+    path: PathBuf,
+    directory: CentralDirectory,
+}
+
+fn le_u16(b: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([b[at], b[at + 1]])
+}
+
+fn le_u32(b: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([b[at], b[at + 1], b[at + 2], b[at + 3]])
+}
+
+fn le_u64(b: &[u8], at: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&b[at..at + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Horodatage MS-DOS (heure locale du packaging, traitée comme UTC).
+fn dos_datetime_ms(date: u16, time: u16) -> Option<i64> {
+    let day = NaiveDate::from_ymd_opt(
+        1980 + (date >> 9) as i32,
+        ((date >> 5) & 0x0f) as u32,
+        (date & 0x1f) as u32,
+    )?;
+    let dt = day.and_hms_opt(
+        (time >> 11) as u32,
+        ((time >> 5) & 0x3f) as u32,
+        ((time & 0x1f) * 2) as u32,
+    )?;
+    Some(dt.and_utc().timestamp_millis())
+}
+
+/// Clé de comparaison : séparateurs `\`, sans séparateur initial, insensible à la casse.
+fn entry_key(name: &str) -> String {
+    name.replace('/', "\\")
+        .trim_start_matches('\\')
+        .to_ascii_lowercase()
+}
+
+/// `entry_key(name) == key` sans allocation : appelé sur chaque entrée du répertoire central.
+fn entry_matches(name: &str, key: &str) -> bool {
+    let name = name.trim_start_matches(['\\', '/']);
+    name.len() == key.len()
+        && name.bytes().zip(key.bytes()).all(|(a, b)| {
+            let a = if a == b'/' { b'\\' } else { a };
+            a.to_ascii_lowercase() == b
+        })
+}
+
+fn read_central_directory(file: &mut File) -> Result<CentralDirectory, String> {
+    let len = file.metadata().map_err(|e| e.to_string())?.len();
+    let tail_len = len.min((EOCD_LEN + u16::MAX as usize + ZIP64_LOCATOR_LEN) as u64);
+    file.seek(SeekFrom::Start(len - tail_len))
+        .map_err(|e| e.to_string())?;
+    let mut tail = vec![0u8; tail_len as usize];
+    file.read_exact(&mut tail).map_err(|e| e.to_string())?;
+    if tail.len() < EOCD_LEN {
+        return Err("Data.p4k invalide : fichier trop court.".to_string());
+    }
+
+    let eocd = (0..=tail.len().saturating_sub(EOCD_LEN))
+        .rev()
+        .find(|&i| le_u32(&tail, i) == EOCD_SIG)
+        .ok_or_else(|| "Data.p4k invalide : fin de répertoire central introuvable.".to_string())?;
+
+    if eocd >= ZIP64_LOCATOR_LEN && le_u32(&tail, eocd - ZIP64_LOCATOR_LEN) == ZIP64_LOCATOR_SIG {
+        let record_offset = le_u64(&tail, eocd - ZIP64_LOCATOR_LEN + 8);
+        file.seek(SeekFrom::Start(record_offset))
+            .map_err(|e| e.to_string())?;
+        let mut record = [0u8; 56];
+        file.read_exact(&mut record).map_err(|e| e.to_string())?;
+        if le_u32(&record, 0) != ZIP64_EOCD_SIG {
+            return Err("Data.p4k invalide : enregistrement ZIP64 corrompu.".to_string());
+        }
+        return Ok(CentralDirectory {
+            entries: le_u64(&record, 32),
+            offset: le_u64(&record, 48),
+        });
+    }
+
+    Ok(CentralDirectory {
+        entries: le_u16(&tail, eocd + 10) as u64,
+        offset: le_u32(&tail, eocd + 16) as u64,
+    })
+}
+
+/// Itérateur en flux sur le répertoire central.
+pub struct P4kEntries {
+    reader: BufReader<File>,
+    remaining: u64,
+}
+
+impl P4kEntries {
+    fn read_entry(&mut self) -> Result<P4kEntry, String> {
+        let mut header = [0u8; CENTRAL_HEADER_LEN];
+        self.reader
+            .read_exact(&mut header)
+            .map_err(|e| e.to_string())?;
+        if le_u32(&header, 0) != CENTRAL_SIG {
+            return Err("Data.p4k invalide : entrée du répertoire central corrompue.".to_string());
+        }
+        let flags = le_u16(&header, 8);
+        let method = le_u16(&header, 10);
+        let time = le_u16(&header, 12);
+        let date = le_u16(&header, 14);
+        let crc32 = le_u32(&header, 16);
+        let mut compressed_size = le_u32(&header, 20) as u64;
+        let mut uncompressed_size = le_u32(&header, 24) as u64;
+        let name_len = le_u16(&header, 28) as usize;
+        let extra_len = le_u16(&header, 30) as usize;
+        let comment_len = le_u16(&header, 32) as usize;
+        let mut local_header_offset = le_u32(&header, 42) as u64;
+
+        let mut variable = vec![0u8; name_len + extra_len + comment_len];
+        self.reader
+            .read_exact(&mut variable)
+            .map_err(|e| e.to_string())?;
+        let name = String::from_utf8_lossy(&variable[..name_len]).into_owned();
+
+        // Extra ZIP64 (id 0x0001) : seuls les champs marqués 0xFFFFFFFF y figurent, dans l'ordre.
+        let extra = &variable[name_len..name_len + extra_len];
+        let mut at = 0;
+        while at + 4 <= extra.len() {
+            let id = le_u16(extra, at);
+            let size = le_u16(extra, at + 2) as usize;
+            let data = &extra[at + 4..(at + 4 + size).min(extra.len())];
+            if id == 0x0001 {
+                let mut cursor = 0;
+                let mut take = |value: &mut u64| {
+                    if *value == ZIP64_MARKER as u64 && cursor + 8 <= data.len() {
+                        *value = le_u64(data, cursor);
+                        cursor += 8;
+                    }
+                };
+                take(&mut uncompressed_size);
+                take(&mut compressed_size);
+                take(&mut local_header_offset);
+            }
+            at += 4 + size;
+        }
+
+        Ok(P4kEntry {
+            name,
+            compressed_size,
+            uncompressed_size,
+            method,
+            crc32,
+            modified_ms: dos_datetime_ms(date, time),
+            encrypted: flags & 0x0001 != 0,
+            local_header_offset,
+        })
+    }
+}
+
+impl Iterator for P4kEntries {
+    type Item = Result<P4kEntry, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let entry = self.read_entry();
+        if entry.is_err() {
+            self.remaining = 0;
+        }
+        Some(entry)
+    }
+}
+
+impl P4kArchive {
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let mut file =
+            File::open(path).map_err(|e| format!("Impossible d'ouvrir {}: {e}", path.display()))?;
+        let directory = read_central_directory(&mut file)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            directory,
+        })
+    }
+
+    /// `Data.p4k` d'une installation (dossier de canal).
+    pub fn open_install(install_path: &Path) -> Result<Self, String> {
+        Self::open(&install_path.join("Data.p4k"))
+    }
+
+    pub fn entry_count(&self) -> u64 {
+        self.directory.entries
+    }
+
+    pub fn entries(&self) -> Result<P4kEntries, String> {
+        let mut file = File::open(&self.path).map_err(|e| e.to_string())?;
+        file.seek(SeekFrom::Start(self.directory.offset))
+            .map_err(|e| e.to_string())?;
+        Ok(P4kEntries {
+            reader: BufReader::with_capacity(1 << 20, file),
+            remaining: self.directory.entries,
+        })
+    }
+
+    /// Entrée par chemin (`/` ou `\`, casse ignorée).
+    pub fn find(&self, name: &str) -> Result<Option<P4kEntry>, String> {
+        let wanted = entry_key(name);
+        for entry in self.entries()? {
+            let entry = entry?;
+            if entry_matches(&entry.name, &wanted) {
+                return Ok(Some(entry));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Contenu décompressé de l'entrée, lu en flux.
+    pub fn open_entry(&self, entry: &P4kEntry) -> Result<Box<dyn Read>, String> {
+        if entry.encrypted {
+            return Err(format!(
+                "{} est chiffré : extraction impossible.",
+                entry.name
+            ));
+        }
+        let mut file = File::open(&self.path).map_err(|e| e.to_string())?;
+        file.seek(SeekFrom::Start(entry.local_header_offset))
+            .map_err(|e| e.to_string())?;
+        let mut header = [0u8; LOCAL_HEADER_LEN];
+        file.read_exact(&mut header).map_err(|e| e.to_string())?;
+        if !matches!(le_u32(&header, 0), LOCAL_SIG | CIG_LOCAL_SIG) {
+            return Err(format!("En-tête local corrompu pour {}.", entry.name));
+        }
+        let skip = le_u16(&header, 26) as i64 + le_u16(&header, 28) as i64;
+        file.seek(SeekFrom::Current(skip))
+            .map_err(|e| e.to_string())?;
+        let data = BufReader::new(file).take(entry.compressed_size);
+
+        let reader: Box<dyn Read> = match entry.method {
+            METHOD_STORED => Box::new(data),
+            METHOD_DEFLATE => Box::new(DeflateDecoder::new(data)),
+            METHOD_ZSTD | METHOD_CIG_ZSTD => {
+                Box::new(zstd::stream::read::Decoder::new(data).map_err(|e| e.to_string())?)
+            }
+            other => {
+                return Err(format!(
+                    "Méthode de compression {other} non gérée ({}).",
+                    entry.name
+                ))
+            }
+        };
+        Ok(Box::new(reader.take(entry.uncompressed_size)))
+    }
+
+    pub fn read_to_string(&self, entry: &P4kEntry) -> Result<String, String> {
+        let mut bytes = Vec::with_capacity(entry.uncompressed_size.min(64 << 20) as usize);
+        self.open_entry(entry)?
+            .read_to_end(&mut bytes)
+            .map_err(|e| e.to_string())?;
+        let text = String::from_utf8_lossy(&bytes);
+        Ok(text.trim_start_matches('\u{feff}').to_string())
+    }
+
+    /// Extrait l'entrée vers `dest` (fichier temporaire puis renommage). Renvoie la taille écrite.
+    pub fn extract_to(&self, entry: &P4kEntry, dest: &Path) -> Result<u64, String> {
+        if dest == self.path {
+            return Err("La destination ne peut pas être Data.p4k.".to_string());
+        }
+        if let Some(parent) = dest.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+        }
+        let tmp = dest.with_extension("p4k-extract.tmp");
+        let written = (|| {
+            let mut out = File::create(&tmp).map_err(|e| e.to_string())?;
+            let written =
+                io::copy(&mut self.open_entry(entry)?, &mut out).map_err(|e| e.to_string())?;
+            if written != entry.uncompressed_size {
+                return Err(format!(
+                    "{} : {written} octets extraits, {} attendus.",
+                    entry.name, entry.uncompressed_size
+                ));
+            }
+            Ok(written)
+        })();
+        match written {
+            Ok(written) => {
+                fs::rename(&tmp, dest).map_err(|e| e.to_string())?;
+                Ok(written)
+            }
+            Err(e) => {
+                let _ = fs::remove_file(&tmp);
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Texte d'un fichier embarqué (`None` si l'archive ou l'entrée est absente).
+pub fn read_p4k_text(install_path: &Path, name: &str) -> Result<Option<String>, String> {
+    let archive = P4kArchive::open_install(install_path)?;
+    match archive.find(name)? {
+        Some(entry) => archive.read_to_string(&entry).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn list_entries(
+    archive: &P4kArchive,
+    prefix: Option<&str>,
+    limit: usize,
+) -> Result<P4kListing, String> {
+    let prefix = prefix.map(entry_key).unwrap_or_default();
+    let mut listing = P4kListing {
+        total_entries: archive.entry_count(),
+        matched: 0,
+        entries: Vec::new(),
+        truncated: false,
+    };
+    for entry in archive.entries()? {
+        let entry = entry?;
+        if !entry_key(&entry.name).starts_with(&prefix) {
+            continue;
+        }
+        listing.matched += 1;
+        if listing.entries.len() < limit {
+            listing.entries.push(entry);
+        } else {
+            listing.truncated = true;
+        }
+    }
+    Ok(listing)
+}
+
+#[command]
+pub async fn list_p4k_entries(
+    install_path: String,
+    prefix: Option<String>,
+    limit: Option<u32>,
+) -> Result<P4kListing, String> {
+    tokio::task::spawn_blocking(move || {
+        let archive = P4kArchive::open_install(Path::new(&install_path))?;
+        list_entries(
+            &archive,
+            prefix.as_deref(),
+            limit.map(|l| l as usize).unwrap_or(DEFAULT_LIST_LIMIT),
+        )
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[command]
+pub async fn get_p4k_entry(install_path: String, name: String) -> Result<Option<P4kEntry>, String> {
+    tokio::task::spawn_blocking(move || {
+        P4kArchive::open_install(Path::new(&install_path))?.find(&name)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[command]
+pub async fn extract_p4k_file(
+    install_path: String,
+    name: String,
+    dest_path: String,
+) -> Result<u64, String> {
+    tokio::task::spawn_blocking(move || {
+        let archive = P4kArchive::open_install(Path::new(&install_path))?;
+        let entry = archive
+            .find(&name)?
+            .ok_or_else(|| format!("{name} introuvable dans Data.p4k."))?;
+        archive.extract_to(&entry, &PathBuf::from(dest_path.trim()))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+    use zip::{CompressionMethod, ZipWriter};
+
+    #[test]
+    fn lists_and_extracts_stored_deflated_and_zstd_entries() {
+        let dir = std::env::temp_dir().join(format!("p4k_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let p4k = dir.join("Data.p4k");
+        let ini = "\u{feff}item_NameA=Alpha\nitem_NameB=Beta\n".repeat(200);
+
+        let mut zip = ZipWriter::new(File::create(&p4k).unwrap());
+        for (name, method, body) in [
+            ("Data\\build.txt", CompressionMethod::Stored, "build 123"),
+            (P4K_GLOBAL_INI_EN, CompressionMethod::Zstd, ini.as_str()),
+            ("Data\\Scripts\\a.xml", CompressionMethod::Deflated, "<a/>"),
+        ] {
+            let options = SimpleFileOptions::default()
+                .compression_method(method)
+                .large_file(true);
+            zip.start_file(name, options).unwrap();
+            zip.write_all(body.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+
+        let archive = P4kArchive::open(&p4k).unwrap();
+        assert_eq!(archive.entry_count(), 3);
+        let listing = list_entries(&archive, Some("data/"), 2).unwrap();
+        assert_eq!((listing.matched, listing.entries.len()), (3, 2));
+        assert!(listing.truncated);
+
+        let text = read_p4k_text(&dir, "data/localization/ENGLISH/global.ini")
+            .unwrap()
+            .unwrap();
+        assert!(text.starts_with("item_NameA=Alpha"));
+
+        let xml = archive.find("Data/Scripts/a.xml").unwrap().unwrap();
+        let dest = dir.join("out").join("a.xml");
+        assert_eq!(archive.extract_to(&xml, &dest).unwrap(), 4);
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "<a/>");
+        assert!(archive.find("Data\\missing.txt").unwrap().is_none());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_truncated_archives_and_reads_cig_local_headers() {
+        let dir = std::env::temp_dir().join(format!("p4k_cig_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let tiny = dir.join("tiny.p4k");
+        fs::write(&tiny, b"PK").unwrap();
+        assert!(P4kArchive::open(&tiny).is_err());
+
+        let p4k = dir.join("Data.p4k");
+        let mut zip = ZipWriter::new(File::create(&p4k).unwrap());
+        zip.start_file(
+            "Data\\build.txt",
+            SimpleFileOptions::default().compression_method(CompressionMethod::Stored),
+        )
+        .unwrap();
+        zip.write_all(b"build 123").unwrap();
+        zip.finish().unwrap();
+        // Signature locale réécrite comme dans les archives de CIG.
+        let mut bytes = fs::read(&p4k).unwrap();
+        assert_eq!(le_u32(&bytes, 0), LOCAL_SIG);
+        bytes[..4].copy_from_slice(&CIG_LOCAL_SIG.to_le_bytes());
+        fs::write(&p4k, bytes).unwrap();
+
+        let text = read_p4k_text(&dir, "/data/BUILD.txt").unwrap().unwrap();
+        assert_eq!(text, "build 123");
+        assert!(entry_matches(
+            "Data/Build.txt",
+            &entry_key("\\data\\build.txt")
+        ));
+        assert!(!entry_matches("Data\\build.txt2", "data\\build.txt"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Contrôle sur une vraie installation : `SC_DATA_P4K=<…>/Data.p4k cargo test -- --ignored`.
+    #[test]
+    #[ignore = "nécessite un Data.p4k réel (variable SC_DATA_P4K)"]
+    fn reads_english_global_ini_from_real_data_p4k() {
+        let path = PathBuf::from(std::env::var("SC_DATA_P4K").expect("SC_DATA_P4K"));
+        let archive = P4kArchive::open(&path).unwrap();
+        assert!(archive.entry_count() > 100_000);
+        let entry = archive
+            .find(P4K_GLOBAL_INI_EN)
+            .unwrap()
+            .expect("global.ini");
+        let text = archive.read_to_string(&entry).unwrap();
+        assert!(text
+            .lines()
+            .any(|l| l.to_ascii_lowercase().starts_with("item_name")));
+    }
+}
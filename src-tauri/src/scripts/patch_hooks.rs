@@ -0,0 +1,498 @@
+//! Détection des changements de patch par canal et actions post-patch.
+//!
+//! Chaque appel à `get_star_citizen_versions_sync` compare le build lu dans le manifeste de
+//! chaque installation au dernier build vu (`patch_history.json`). Un changement déclenche, en
+//! tâche de fond, les actions activées dans `patch_hooks.json` : réinstallation de la
+//! traduction (un patch peut écraser `user.cfg`), rafraîchissement forcé des caches Wiki,
+//! sauvegarde de la configuration et notification. Chaque patch est consigné par canal.
+
+use crate::scripts::background_service::load_background_service_config;
+use crate::scripts::game_config_backup::export_game_config_backup_sync;
+use crate::scripts::gamepath::VersionInfo;
+use crate::scripts::translation_functions::{
+    init_translation_files, is_game_translated_sync, is_translation_up_to_date_async,
+    update_translation,
+};
+use crate::scripts::translation_preferences::load_translations_selected;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::path::PathResolver;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tauri_plugin_notification::NotificationExt;
+
+pub const PATCH_DETECTED_EVENT: &str = "patch-detected";
+
+/// Entrées conservées par canal dans l'historique.
+const MAX_HISTORY_PER_CHANNEL: usize = 100;
+
+/// Renseigné au démarrage : sans lui (tests, appels très précoces), rien n'est détecté.
+static PATCH_WATCH_APP: OnceLock<AppHandle> = OnceLock::new();
+/// Sérialise lecture/écriture de `patch_history.json` (appels de versions concurrents).
+static PATCH_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PatchHooksConfig {
+    pub reinstall_translation: bool,
+    pub refresh_wiki_caches: bool,
+    pub backup_config: bool,
+    pub notify: bool,
+}
+
+impl Default for PatchHooksConfig {
+    fn default() -> Self {
+        Self {
+            reinstall_translation: true,
+            refresh_wiki_caches: true,
+            backup_config: true,
+            notify: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeenBuild {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PatchHookAction {
+    ReinstallTranslation,
+    RefreshWikiCaches,
+    BackupConfig,
+    Notify,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchHookOutcome {
+    pub action: PatchHookAction,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchChange {
+    pub channel: String,
+    pub install_path: String,
+    pub previous: SeenBuild,
+    pub current: SeenBuild,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchHistoryEntry {
+    pub channel: String,
+    pub detected_at_ms: u64,
+    pub previous: SeenBuild,
+    pub current: SeenBuild,
+    pub outcomes: Vec<PatchHookOutcome>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct PatchHistoryFile {
+    last_seen: BTreeMap<String, SeenBuild>,
+    /// Du plus ancien au plus récent, par canal.
+    history: BTreeMap<String, Vec<PatchHistoryEntry>>,
+}
+
+fn config_dir(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+    let dir = resolver
+        .app_config_dir()
+        .map_err(|_| "Impossible d'obtenir le répertoire de configuration".to_string())?;
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir)
+}
+
+fn config_path(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+    Ok(config_dir(resolver)?.join("patch_hooks.json"))
+}
+
+fn history_path(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+    Ok(config_dir(resolver)?.join("patch_history.json"))
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+pub fn load_patch_hooks_config_sync(app: &AppHandle) -> Result<PatchHooksConfig, String> {
+    let path = config_path(app.path())?;
+    if !path.exists() {
+        return Ok(PatchHooksConfig::default());
+    }
+    let json = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+fn load_history(app: &AppHandle) -> Result<PatchHistoryFile, String> {
+    let path = history_path(app.path())?;
+    if !path.exists() {
+        return Ok(PatchHistoryFile::default());
+    }
+    let json = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| format!("Historique des patchs illisible : {e}"))
+}
+
+fn save_history(app: &AppHandle, history: &PatchHistoryFile) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(history).map_err(|e| e.to_string())?;
+    fs::write(history_path(app.path())?, json).map_err(|e| e.to_string())
+}
+
+/// Active la détection des patchs (appelé au démarrage de l'application).
+pub fn init_patch_watch(app: &AppHandle) {
+    let _ = PATCH_WATCH_APP.set(app.clone());
+}
+
+/// Compare les builds courants aux derniers vus et met `last_seen` à jour.
+///
+/// Un canal vu pour la première fois est simplement enregistré ; un build illisible
+/// (manifeste absent) ne remplace pas le dernier connu.
+fn detect_patch_changes(
+    last_seen: &mut BTreeMap<String, SeenBuild>,
+    versions: &HashMap<String, VersionInfo>,
+) -> Vec<PatchChange> {
+    let mut changes = Vec::new();
+    for (channel, info) in versions {
+        let current = SeenBuild {
+            build_number: info.build_number.clone(),
+            game_version: info.game_version.clone(),
+        };
+        if current.build_number.is_none() && current.game_version.is_none() {
+            continue;
+        }
+        match last_seen.get(channel) {
+            Some(previous) if *previous == current => continue,
+            Some(previous) => changes.push(PatchChange {
+                channel: channel.clone(),
+                install_path: info.path.clone(),
+                previous: previous.clone(),
+                current: current.clone(),
+            }),
+            None => {}
+        }
+        last_seen.insert(channel.clone(), current);
+    }
+    changes.sort_by(|a, b| a.channel.cmp(&b.channel));
+    changes
+}
+
+/// Point d'entrée appelé à chaque détection des versions installées.
+pub fn observe_versions(versions: &HashMap<String, VersionInfo>) {
+    let Some(app) = PATCH_WATCH_APP.get() else {
+        return;
+    };
+    let changes = {
+        let Ok(_guard) = PATCH_LOCK.lock() else {
+            return;
+        };
+        let mut history = match load_history(app) {
+            Ok(history) => history,
+            Err(e) => {
+                eprintln!("[patch_hooks] {e}");
+                return;
+            }
+        };
+        let seen_before = history.last_seen.clone();
+        let changes = detect_patch_changes(&mut history.last_seen, versions);
+        // Appelé à chaque détection des versions : le fichier n'est réécrit que si un build
+        // a changé ou qu'un canal est apparu.
+        if history.last_seen == seen_before {
+            return;
+        }
+        if let Err(e) = save_history(app, &history) {
+            eprintln!("[patch_hooks] enregistrement des builds: {e}");
+            return;
+        }
+        changes
+    };
+    for change in changes {
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            run_patch_hooks(app, change).await;
+        });
+    }
+}
+
+fn version_label(build: &SeenBuild) -> String {
+    match (&build.game_version, &build.build_number) {
+        (Some(v), Some(b)) => format!("{v} ({b})"),
+        (Some(v), None) => v.clone(),
+        (None, Some(b)) => b.clone(),
+        (None, None) => "?".to_string(),
+    }
+}
+
+fn outcome(action: PatchHookAction, result: Result<Option<String>, String>) -> PatchHookOutcome {
+    match result {
+        Ok(detail) => PatchHookOutcome {
+            action,
+            ok: true,
+            detail,
+        },
+        Err(e) => PatchHookOutcome {
+            action,
+            ok: false,
+            detail: Some(e),
+        },
+    }
+}
+
+/// Réinstalle la traduction choisie pour le canal si le patch l'a retirée ou périmée.
+async fn reinstall_translation(
+    app: &AppHandle,
+    change: &PatchChange,
+) -> Result<Option<String>, String> {
+    let selected = load_translations_selected(app.clone())?;
+    let Some(link) = selected
+        .as_value()
+        .get(&change.channel)
+        .and_then(|setting| setting.get("link"))
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+    else {
+        return Ok(Some("Aucune traduction choisie pour ce canal".to_string()));
+    };
+    let default_lang = load_background_service_config(app.clone())
+        .map(|c| c.language)
+        .unwrap_or_else(|_| "fr".to_string());
+    let lang = selected.channel_language(&change.channel, &default_lang);
+    let path = change.install_path.clone();
+
+    let translated = {
+        let (path, lang) = (path.clone(), lang.clone());
+        tokio::task::spawn_blocking(move || is_game_translated_sync(path, lang))
+            .await
+            .map_err(|e| e.to_string())?
+    };
+    if !translated {
+        tokio::task::spawn_blocking(move || init_translation_files(path, lang, link))
+            .await
+            .map_err(|e| e.to_string())??;
+        return Ok(Some("Traduction réinstallée".to_string()));
+    }
+    if is_translation_up_to_date_async(path.clone(), link.clone(), lang.clone()).await {
+        return Ok(Some("Traduction intacte et à jour".to_string()));
+    }
+    let app = app.clone();
+    tokio::task::spawn_blocking(move || update_translation(app, path, lang, link))
+        .await
+        .map_err(|e| e.to_string())??;
+    Ok(Some("Traduction mise à jour".to_string()))
+}
+
+async fn refresh_wiki_caches() -> Result<Option<String>, String> {
+    let blueprints = crate::scripts::blueprints_catalog::force_refresh_wiki_catalog().await?;
+    let vehicles = crate::scripts::vehicles_catalog::force_refresh_vehicles_catalog().await?;
+    Ok(Some(format!(
+        "{blueprints} blueprints, {vehicles} vaisseaux"
+    )))
+}
+
+fn backup_config(app: &AppHandle, change: &PatchChange) -> Result<Option<String>, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|_| "Impossible d'obtenir le répertoire de données".to_string())?
+        .join("patch_backups");
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let label: String = version_label(&change.current)
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let dest = dir.join(format!("{}_{label}_{}.zip", change.channel, now_ms()));
+    let result = export_game_config_backup_sync(
+        change.install_path.clone(),
+        dest.to_string_lossy().into_owned(),
+        false,
+        false,
+    )?;
+    Ok(Some(format!(
+        "{} fichiers → {}",
+        result.files_packed,
+        dest.display()
+    )))
+}
+
+fn notify(app: &AppHandle, change: &PatchChange) -> Result<Option<String>, String> {
+    app.notification()
+        .builder()
+        .title(format!("Nouveau patch {}", change.channel))
+        .body(format!(
+            "{} → {}",
+            version_label(&change.previous),
+            version_label(&change.current)
+        ))
+        .show()
+        .map_err(|e| e.to_string())?;
+    Ok(None)
+}
+
+async fn run_patch_hooks(app: AppHandle, change: PatchChange) {
+    eprintln!(
+        "[patch_hooks] {} : {} → {}",
+        change.channel,
+        version_label(&change.previous),
+        version_label(&change.current)
+    );
+    let config = load_patch_hooks_config_sync(&app).unwrap_or_else(|e| {
+        eprintln!("[patch_hooks] configuration illisible, valeurs par défaut: {e}");
+        PatchHooksConfig::default()
+    });
+
+    let mut outcomes = Vec::new();
+    if config.reinstall_translation {
+        let result = reinstall_translation(&app, &change).await;
+        outcomes.push(outcome(PatchHookAction::ReinstallTranslation, result));
+    }
+    if config.refresh_wiki_caches {
+        outcomes.push(outcome(
+            PatchHookAction::RefreshWikiCaches,
+            refresh_wiki_caches().await,
+        ));
+    }
+    if config.backup_config {
+        let (app_clone, change_clone) = (app.clone(), change.clone());
+        let result = tokio::task::spawn_blocking(move || backup_config(&app_clone, &change_clone))
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()));
+        outcomes.push(outcome(PatchHookAction::BackupConfig, result));
+    }
+    if config.notify {
+        outcomes.push(outcome(PatchHookAction::Notify, notify(&app, &change)));
+    }
+    for failed in outcomes.iter().filter(|o| !o.ok) {
+        eprintln!(
+            "[patch_hooks] {} : {:?} en échec: {}",
+            change.channel,
+            failed.action,
+            failed.detail.as_deref().unwrap_or_default()
+        );
+    }
+
+    let entry = PatchHistoryEntry {
+        channel: change.channel.clone(),
+        detected_at_ms: now_ms(),
+        previous: change.previous,
+        current: change.current,
+        outcomes,
+    };
+    let app_clone = app.clone();
+    let entry_clone = entry.clone();
+    let saved = tokio::task::spawn_blocking(move || append_history(&app_clone, entry_clone))
+        .await
+        .unwrap_or_else(|e| Err(e.to_string()));
+    if let Err(e) = saved {
+        eprintln!("[patch_hooks] historique: {e}");
+    }
+    let _ = app.emit(PATCH_DETECTED_EVENT, &entry);
+}
+
+fn append_history(app: &AppHandle, entry: PatchHistoryEntry) -> Result<(), String> {
+    let _guard = PATCH_LOCK.lock().map_err(|e| e.to_string())?;
+    let mut history = load_history(app)?;
+    let channel = history.history.entry(entry.channel.clone()).or_default();
+    channel.push(entry);
+    if channel.len() > MAX_HISTORY_PER_CHANNEL {
+        let excess = channel.len() - MAX_HISTORY_PER_CHANNEL;
+        channel.drain(..excess);
+    }
+    save_history(app, &history)
+}
+
+#[command]
+pub fn get_patch_hooks_config(app: AppHandle) -> Result<PatchHooksConfig, String> {
+    load_patch_hooks_config_sync(&app)
+}
+
+#[command]
+pub fn save_patch_hooks_config(app: AppHandle, config: PatchHooksConfig) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    fs::write(config_path(app.path())?, json).map_err(|e| e.to_string())
+}
+
+/// Historique des patchs, du plus récent au plus ancien (un canal ou tous).
+#[command]
+pub fn get_patch_history(
+    app: AppHandle,
+    channel: Option<String>,
+) -> Result<Vec<PatchHistoryEntry>, String> {
+    let history = {
+        let _guard = PATCH_LOCK.lock().map_err(|e| e.to_string())?;
+        load_history(&app)?
+    };
+    let mut entries: Vec<PatchHistoryEntry> = history
+        .history
+        .into_iter()
+        .filter(|(c, _)| channel.as_ref().is_none_or(|wanted| wanted == c))
+        .flat_map(|(_, entries)| entries)
+        .collect();
+    entries.sort_by_key(|e| std::cmp::Reverse(e.detected_at_ms));
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scripts::gamepath::GameUpdateStatus;
+
+    fn info(build: Option<&str>, version: Option<&str>) -> VersionInfo {
+        VersionInfo {
+            path: "C:\\Games\\StarCitizen\\LIVE".to_string(),
+            translated: false,
+            up_to_date: false,
+            game_update_status: GameUpdateStatus::Unknown,
+            release_version: None,
+            build_number: build.map(str::to_string),
+            game_version: version.map(str::to_string),
+            branch: None,
+            detected_by: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn detects_build_changes_per_channel() {
+        let mut last_seen = BTreeMap::new();
+        let mut versions = HashMap::new();
+        versions.insert("LIVE".to_string(), info(Some("9612345"), Some("4.1.0")));
+
+        // Premier passage : enregistré sans déclencher.
+        assert!(detect_patch_changes(&mut last_seen, &versions).is_empty());
+        assert!(detect_patch_changes(&mut last_seen, &versions).is_empty());
+
+        // Manifeste illisible : le dernier build connu est conservé.
+        versions.insert("LIVE".to_string(), info(None, None));
+        assert!(detect_patch_changes(&mut last_seen, &versions).is_empty());
+
+        versions.insert("LIVE".to_string(), info(Some("9700001"), Some("4.1.1")));
+        let changes = detect_patch_changes(&mut last_seen, &versions);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].channel, "LIVE");
+        assert_eq!(changes[0].previous.build_number.as_deref(), Some("9612345"));
+        assert_eq!(version_label(&changes[0].current), "4.1.1 (9700001)");
+        assert!(detect_patch_changes(&mut last_seen, &versions).is_empty());
+    }
+}
@@ -0,0 +1,250 @@
+//! Différence clé par clé entre le `global.ini` installé et celui d'une traduction distante.
+//! Affichée avant une mise à jour, puis conservée comme journal des mises à jour appliquées.
+
+use crate::scripts::translation_functions::{get_language_folder, read_installed_global_ini};
+use crate::scripts::translation_overrides::apply_translation_overrides;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::path::PathResolver;
+use tauri::{command, AppHandle, Manager, Runtime};
+
+/// Exemples de clés et de textes renvoyés par catégorie.
+const DEFAULT_SAMPLE_LIMIT: usize = 50;
+/// Mises à jour conservées dans le journal (toutes installations confondues).
+const MAX_CHANGELOG_ENTRIES: usize = 200;
+
+static CHANGELOG_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedString {
+    pub key: String,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlobalIniDiff {
+    pub added_count: usize,
+    pub removed_count: usize,
+    pub changed_count: usize,
+    pub unchanged_count: usize,
+    /// Échantillons (limités) ; les compteurs portent sur tout le fichier.
+    pub added: Vec<ChangedString>,
+    pub removed: Vec<ChangedString>,
+    pub changed: Vec<ChangedString>,
+}
+
+impl GlobalIniDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_count == 0 && self.removed_count == 0 && self.changed_count == 0
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationChangelogEntry {
+    pub path: String,
+    pub lang: String,
+    pub link: String,
+    pub applied_at_ms: u64,
+    pub diff: GlobalIniDiff,
+}
+
+/// `clé=valeur` par ligne ; commentaires et lignes sans `=` ignorés, première occurrence gardée.
+pub fn parse_global_ini(raw: &str) -> BTreeMap<String, String> {
+    let mut map = BTreeMap::new();
+    for line in raw.trim_start_matches('\u{feff}').lines() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() || line.starts_with(';') || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        if key.is_empty() {
+            continue;
+        }
+        map.entry(key.to_string())
+            .or_insert_with(|| value.to_string());
+    }
+    map
+}
+
+pub fn diff_global_ini(local: &str, remote: &str, sample_limit: usize) -> GlobalIniDiff {
+    let local = parse_global_ini(local);
+    let remote = parse_global_ini(remote);
+    let mut diff = GlobalIniDiff::default();
+    let sample = |list: &mut Vec<ChangedString>, key: &str, old: &str, new: &str| {
+        if list.len() < sample_limit {
+            list.push(ChangedString {
+                key: key.to_string(),
+                old_value: old.to_string(),
+                new_value: new.to_string(),
+            });
+        }
+    };
+
+    for (key, new_value) in &remote {
+        match local.get(key) {
+            None => {
+                diff.added_count += 1;
+                sample(&mut diff.added, key, "", new_value);
+            }
+            Some(old_value) if old_value != new_value => {
+                diff.changed_count += 1;
+                sample(&mut diff.changed, key, old_value, new_value);
+            }
+            Some(_) => diff.unchanged_count += 1,
+        }
+    }
+    for (key, old_value) in &local {
+        if !remote.contains_key(key) {
+            diff.removed_count += 1;
+            sample(&mut diff.removed, key, old_value, "");
+        }
+    }
+    diff
+}
+
+fn changelog_path(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+    let dir = resolver
+        .app_config_dir()
+        .map_err(|_| "Impossible d'obtenir le répertoire de configuration".to_string())?;
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir.join("translation_changelog.json"))
+}
+
+fn load_changelog(app: &AppHandle) -> Result<Vec<TranslationChangelogEntry>, String> {
+    let path = changelog_path(app.path())?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let json = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| format!("Journal des traductions illisible : {e}"))
+}
+
+/// Consigne une mise à jour appliquée (`previous` : contenu installé avant l'écriture).
+pub fn record_translation_update(
+    app: &AppHandle,
+    path: &str,
+    lang: &str,
+    link: &str,
+    previous: &str,
+    applied: &str,
+) -> Result<(), String> {
+    let entry = TranslationChangelogEntry {
+        path: path.to_string(),
+        lang: lang.to_string(),
+        link: link.to_string(),
+        applied_at_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0),
+        diff: diff_global_ini(previous, applied, DEFAULT_SAMPLE_LIMIT),
+    };
+    let _guard = CHANGELOG_LOCK.lock().map_err(|e| e.to_string())?;
+    let mut changelog = load_changelog(app)?;
+    changelog.push(entry);
+    if changelog.len() > MAX_CHANGELOG_ENTRIES {
+        let excess = changelog.len() - MAX_CHANGELOG_ENTRIES;
+        changelog.drain(..excess);
+    }
+    let json = serde_json::to_string_pretty(&changelog).map_err(|e| e.to_string())?;
+    fs::write(changelog_path(app.path())?, json).map_err(|e| e.to_string())
+}
+
+fn preview_translation_update_sync(
+    path: &str,
+    translation_link: &str,
+    lang: &str,
+    sample_limit: usize,
+) -> Result<GlobalIniDiff, String> {
+    if get_language_folder(lang).is_none() {
+        return Err("Langue non prise en charge".to_string());
+    }
+    let local = read_installed_global_ini(Path::new(path), lang).unwrap_or_default();
+    let remote = reqwest::blocking::Client::new()
+        .get(translation_link)
+        .send()
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Erreur lors du téléchargement: {e}"))?
+        .text()
+        .map_err(|e| format!("Erreur lors de la lecture de la réponse: {e}"))?;
+    let remote = apply_translation_overrides(&remote, lang)?;
+    Ok(diff_global_ini(&local, &remote, sample_limit))
+}
+
+/// Ce que changerait la mise à jour de la traduction, clé par clé, sans rien écrire.
+#[command]
+pub async fn preview_translation_update(
+    path: String,
+    translation_link: String,
+    lang: String,
+    sample_limit: Option<usize>,
+) -> Result<GlobalIniDiff, String> {
+    let sample_limit = sample_limit.unwrap_or(DEFAULT_SAMPLE_LIMIT);
+    tokio::task::spawn_blocking(move || {
+        preview_translation_update_sync(&path, &translation_link, &lang, sample_limit)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Journal des mises à jour appliquées, du plus récent au plus ancien (une installation ou toutes).
+#[command]
+pub fn get_translation_changelog(
+    app: AppHandle,
+    path: Option<String>,
+) -> Result<Vec<TranslationChangelogEntry>, String> {
+    let changelog = {
+        let _guard = CHANGELOG_LOCK.lock().map_err(|e| e.to_string())?;
+        load_changelog(&app)?
+    };
+    Ok(changelog
+        .into_iter()
+        .rev()
+        .filter(|e| path.as_ref().is_none_or(|p| *p == e.path))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs_global_ini_by_key() {
+        let local = "\u{feff}; commentaire\r\nui_ok=OK\r\nui_cancel=Annuler\r\nui_old=Ancien\r\n";
+        let remote = "ui_ok=OK\nui_cancel=Abandonner\nui_new=Nouveau\nui_new2,P=Autre\n";
+        let diff = diff_global_ini(local, remote, 1);
+
+        assert_eq!(
+            (
+                diff.added_count,
+                diff.removed_count,
+                diff.changed_count,
+                diff.unchanged_count
+            ),
+            (2, 1, 1, 1)
+        );
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(
+            diff.changed[0],
+            ChangedString {
+                key: "ui_cancel".to_string(),
+                old_value: "Annuler".to_string(),
+                new_value: "Abandonner".to_string(),
+            }
+        );
+        assert_eq!(diff.removed[0].key, "ui_old");
+        assert!(diff_global_ini(remote, remote, 10).is_empty());
+    }
+}
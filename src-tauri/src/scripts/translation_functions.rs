@@ -3,18 +3,33 @@ use std::io::Write;
 use std::path::Path;
 
 use reqwest::blocking::Client;
-use tauri::command;
+use tauri::{command, AppHandle};
 
-/// Convertit un code de langue en nom de dossier de localisation.
-pub fn get_language_folder(lang: &str) -> Option<&str> {
-    match lang.to_lowercase().as_str() {
-        "fr" => Some("french_(france)"),
-        _ => None,
-    }
+use crate::scripts::translation_diff::{diff_global_ini, record_translation_update};
+use crate::scripts::translation_languages::{
+    find_language, language_from_user_cfg, TranslationLanguage,
+};
+use crate::scripts::translation_overrides::apply_translation_overrides;
+
+/// Convertit un code de langue en nom de dossier de localisation (cf. `translation_languages`).
+pub fn get_language_folder(lang: &str) -> Option<&'static str> {
+    find_language(lang).map(|l| l.folder)
 }
 
 const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
 
+/// Contenu du `global.ini` installé pour `lang` (sans BOM), `None` s'il est absent.
+pub fn read_installed_global_ini(base_path: &Path, lang: &str) -> Option<String> {
+    let global_ini_path = base_path
+        .join("data")
+        .join("Localization")
+        .join(get_language_folder(lang)?)
+        .join("global.ini");
+    let bytes = fs::read(global_ini_path).ok()?;
+    let bytes = bytes.strip_prefix(UTF8_BOM).unwrap_or(&bytes);
+    Some(String::from_utf8_lossy(bytes).into_owned())
+}
+
 /// Vérifie si une version de Star Citizen a une traduction installée (travail bloquant).
 /// Public pour usage depuis le service de fond (déjà exécuté hors du thread principal).
 pub fn is_game_translated_sync(path: String, lang: String) -> bool {
@@ -34,7 +49,7 @@ pub fn is_game_translated_sync(path: String, lang: String) -> bool {
         None => return false,
     };
 
-    if !user_cfg_content.contains(&format!("g_language = {lang_folder_name}")) {
+    if language_from_user_cfg(&user_cfg_content).map(|l| l.folder) != Some(lang_folder_name) {
         return false;
     }
 
@@ -97,20 +112,67 @@ pub fn init_translation_files(
     let content = response
         .text()
         .map_err(|e| format!("Erreur lors de la lecture de la réponse: {e}"))?;
+    let content = apply_translation_overrides(&content, &lang)?;
     let mut file = File::create(&global_ini_path)
         .map_err(|e| format!("Erreur lors de la création de 'global.ini': {e}"))?;
     file.write_all(UTF8_BOM)
         .and_then(|_| file.write_all(content.as_bytes()))
         .map_err(|e| format!("Erreur lors de l'écriture de 'global.ini': {e}"))?;
 
+    set_user_cfg_language(base_path, Some(lang_folder_name))
+}
+
+/// Lignes de `user.cfg` sans les réglages de langue (les autres réglages sont conservés).
+fn user_cfg_without_language(content: &str) -> Vec<&str> {
+    content
+        .lines()
+        .filter(|line| {
+            let key = line.split('=').next().unwrap_or_default().trim();
+            !key.eq_ignore_ascii_case("g_language") && !key.eq_ignore_ascii_case("g_languageAudio")
+        })
+        .collect()
+}
+
+/// Écrit (ou retire, avec `None`) la langue dans `user.cfg` ; supprime le fichier s'il devient vide.
+fn set_user_cfg_language(base_path: &Path, lang_folder_name: Option<&str>) -> Result<(), String> {
     let user_cfg_path = base_path.join("user.cfg");
+    let existing = fs::read_to_string(&user_cfg_path).unwrap_or_default();
+    let mut lines: Vec<String> = user_cfg_without_language(&existing)
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    if let Some(folder) = lang_folder_name {
+        lines.push(format!("g_language = {folder}"));
+        lines.push("g_languageAudio = english".to_string());
+    }
+    lines.retain(|l| !l.trim().is_empty());
+    if lines.is_empty() {
+        if user_cfg_path.exists() {
+            fs::remove_file(&user_cfg_path)
+                .map_err(|e| format!("Erreur lors de la suppression de 'user.cfg': {e}"))?;
+        }
+        return Ok(());
+    }
     let mut file = File::create(&user_cfg_path)
         .map_err(|e| format!("Erreur lors de la création de 'user.cfg': {e}"))?;
-    let cfg_content = format!("g_language = {lang_folder_name}\ng_languageAudio = english\n");
-    file.write_all(cfg_content.as_bytes())
-        .map_err(|e| format!("Erreur lors de l'écriture dans 'user.cfg': {e}"))?;
+    file.write_all(format!("{}\n", lines.join("\n")).as_bytes())
+        .map_err(|e| format!("Erreur lors de l'écriture dans 'user.cfg': {e}"))
+}
 
-    Ok(())
+/// Langue activée dans le `user.cfg` d'une installation (travail bloquant).
+pub fn get_installed_translation_language_sync(path: &str) -> Option<TranslationLanguage> {
+    let content = fs::read_to_string(Path::new(path).join("user.cfg")).ok()?;
+    language_from_user_cfg(&content).copied()
+}
+
+/// Langue activée dans le `user.cfg` d'une installation (`None` : anglais par défaut du jeu).
+#[command]
+pub async fn get_installed_translation_language(
+    path: String,
+) -> Result<Option<TranslationLanguage>, String> {
+    tokio::task::spawn_blocking(move || get_installed_translation_language_sync(&path))
+        .await
+        .map_err(|e| e.to_string())
 }
 
 /// Vérifie si la traduction installée est à jour (travail bloquant, disque + réseau).
@@ -160,12 +222,13 @@ fn is_translation_up_to_date_sync(path: String, translation_link: String, lang:
         Err(_) => return false,
     };
 
-    // Normaliser les contenus
-    let local_normalized = local_ini_content.replace("\r\n", "\n").trim().to_string();
-    let remote_normalized = remote_ini_content.replace("\r\n", "\n").trim().to_string();
-
-    // Comparer les contenus
-    local_normalized == remote_normalized
+    // Comparer clé par clé au contenu surchargé (fins de ligne, commentaires et ordre ignorés)
+    // Surcharges illisibles : la mise à jour échouera en expliquant pourquoi.
+    let remote_ini_content = match apply_translation_overrides(&remote_ini_content, &lang) {
+        Ok(content) => content,
+        Err(_) => return false,
+    };
+    diff_global_ini(&local_ini_content, &remote_ini_content, 0).is_empty()
 }
 
 /// Vérifie si la traduction installée est à jour.
@@ -183,14 +246,17 @@ pub async fn is_translation_up_to_date(
     .map_err(|e| e.to_string())
 }
 
-/// Met à jour la traduction installée.
+/// Met à jour la traduction installée et consigne le changement clé par clé
+/// (cf. `translation_diff`).
 #[command]
 pub fn update_translation(
+    app: AppHandle,
     path: String,
     lang: String,
     translation_link: String,
 ) -> Result<(), String> {
     let base_path = Path::new(&path);
+    let previous = read_installed_global_ini(base_path, &lang).unwrap_or_default();
 
     // Obtenir le nom du dossier de langue
     let lang_folder_name =
@@ -218,6 +284,7 @@ pub fn update_translation(
     let content = response
         .text()
         .map_err(|e| format!("Erreur lors de la lecture de la réponse: {e}"))?;
+    let content = apply_translation_overrides(&content, &lang)?;
 
     let mut file = File::create(&global_ini_path)
         .map_err(|e| format!("Erreur lors de la création de 'global.ini': {e}"))?;
@@ -225,14 +292,42 @@ pub fn update_translation(
         .and_then(|_| file.write_all(content.as_bytes()))
         .map_err(|e| format!("Erreur lors de l'écriture de 'global.ini': {e}"))?;
 
+    if let Err(e) =
+        record_translation_update(&app, &path, &lang, &translation_link, &previous, &content)
+    {
+        eprintln!("[translation] journal des mises à jour: {e}");
+    }
     Ok(())
 }
 
 /// Désinstalle la traduction d'une version de Star Citizen.
+///
+/// Avec `lang`, seul le dossier de cette langue est retiré et `user.cfg` garde ses autres
+/// réglages ; sans, tout `data` et `user.cfg` sont supprimés.
 #[command]
-pub fn uninstall_translation(path: String) -> Result<(), String> {
+pub fn uninstall_translation(path: String, lang: Option<String>) -> Result<(), String> {
     let base_path = Path::new(&path);
 
+    if let Some(lang) = lang {
+        let lang_folder_name =
+            get_language_folder(&lang).ok_or_else(|| "Langue non prise en charge".to_string())?;
+        let lang_folder_path = base_path
+            .join("data")
+            .join("Localization")
+            .join(lang_folder_name);
+        if lang_folder_path.exists() {
+            fs::remove_dir_all(&lang_folder_path)
+                .map_err(|e| format!("Erreur lors de la suppression du dossier de langue: {e}"))?;
+        }
+        let active = fs::read_to_string(base_path.join("user.cfg"))
+            .ok()
+            .and_then(|content| language_from_user_cfg(&content).map(|l| l.folder));
+        if active == Some(lang_folder_name) {
+            set_user_cfg_language(base_path, None)?;
+        }
+        return Ok(());
+    }
+
     let data_path = base_path.join("data");
     if data_path.exists() {
         fs::remove_dir_all(&data_path)
@@ -300,20 +395,24 @@ pub async fn is_translation_up_to_date_async(
         Err(_) => return false,
     };
 
-    // Normaliser les contenus
-    let local_normalized = local_ini_content.replace("\r\n", "\n").trim().to_string();
-    let remote_normalized = remote_ini_content.replace("\r\n", "\n").trim().to_string();
-
-    local_normalized == remote_normalized
+    // Comparer clé par clé au contenu surchargé (fins de ligne, commentaires et ordre ignorés)
+    // Surcharges illisibles : la mise à jour échouera en expliquant pourquoi.
+    let remote_ini_content = match apply_translation_overrides(&remote_ini_content, &lang) {
+        Ok(content) => content,
+        Err(_) => return false,
+    };
+    diff_global_ini(&local_ini_content, &remote_ini_content, 0).is_empty()
 }
 
 /// Version asynchrone de `update_translation` pour le service de tâche de fond.
 pub async fn update_translation_async(
+    app: &AppHandle,
     path: String,
     lang: String,
     translation_link: String,
 ) -> Result<(), String> {
     let base_path = Path::new(&path);
+    let previous = read_installed_global_ini(base_path, &lang).unwrap_or_default();
 
     // Obtenir le nom du dossier de langue
     let lang_folder_name =
@@ -343,6 +442,7 @@ pub async fn update_translation_async(
         .text()
         .await
         .map_err(|e| format!("Erreur lors de la lecture de la réponse: {e}"))?;
+    let content = apply_translation_overrides(&content, &lang)?;
 
     let mut file = File::create(&global_ini_path)
         .map_err(|e| format!("Erreur lors de la création de 'global.ini': {e}"))?;
@@ -350,5 +450,10 @@ pub async fn update_translation_async(
         .and_then(|_| file.write_all(content.as_bytes()))
         .map_err(|e| format!("Erreur lors de l'écriture de 'global.ini': {e}"))?;
 
+    if let Err(e) =
+        record_translation_update(app, &path, &lang, &translation_link, &previous, &content)
+    {
+        eprintln!("[translation] journal des mises à jour: {e}");
+    }
     Ok(())
 }
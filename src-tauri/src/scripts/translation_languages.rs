@@ -0,0 +1,116 @@
+//! Langues de localisation prises en charge par Star Citizen (`data/Localization/<dossier>`).
+
+use serde::Serialize;
+use tauri::command;
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationLanguage {
+    /// Code utilisé par l'application et les préférences (`fr`, `de`, `es-419`, …).
+    pub code: &'static str,
+    /// Dossier de localisation et valeur de `g_language` dans `user.cfg`.
+    pub folder: &'static str,
+    pub display_name: &'static str,
+    pub native_name: &'static str,
+}
+
+const fn language(
+    code: &'static str,
+    folder: &'static str,
+    display_name: &'static str,
+    native_name: &'static str,
+) -> TranslationLanguage {
+    TranslationLanguage {
+        code,
+        folder,
+        display_name,
+        native_name,
+    }
+}
+
+pub const TRANSLATION_LANGUAGES: &[TranslationLanguage] = &[
+    language("en", "english", "Anglais", "English"),
+    language("fr", "french_(france)", "Français", "Français"),
+    language("de", "german_(germany)", "Allemand", "Deutsch"),
+    language(
+        "es",
+        "spanish_(spain)",
+        "Espagnol (Espagne)",
+        "Español (España)",
+    ),
+    language(
+        "es-419",
+        "spanish_(latin_america)",
+        "Espagnol (Amérique latine)",
+        "Español (Latinoamérica)",
+    ),
+    language("it", "italian_(italy)", "Italien", "Italiano"),
+    language(
+        "pt-br",
+        "portuguese_(brazil)",
+        "Portugais (Brésil)",
+        "Português (Brasil)",
+    ),
+    language("pl", "polish_(poland)", "Polonais", "Polski"),
+    language("ja", "japanese_(japan)", "Japonais", "日本語"),
+    language("ko", "korean_(south_korea)", "Coréen", "한국어"),
+    language(
+        "zh-cn",
+        "chinese_(simplified)",
+        "Chinois simplifié",
+        "简体中文",
+    ),
+    language(
+        "zh-tw",
+        "chinese_(traditional)",
+        "Chinois traditionnel",
+        "繁體中文",
+    ),
+];
+
+/// Langue désignée par son code (`fr`, `pt-BR`, `pt_br`) ou son dossier (`german_(germany)`).
+pub fn find_language(lang: &str) -> Option<&'static TranslationLanguage> {
+    let wanted = lang.trim().to_lowercase().replace('_', "-");
+    let folder = lang.trim().to_lowercase();
+    TRANSLATION_LANGUAGES
+        .iter()
+        .find(|l| l.code == wanted || l.folder == folder)
+}
+
+/// Langue configurée dans un `user.cfg` (`g_language = german_(germany)`).
+pub fn language_from_user_cfg(content: &str) -> Option<&'static TranslationLanguage> {
+    content.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        if !key.trim().eq_ignore_ascii_case("g_language") {
+            return None;
+        }
+        find_language(value.trim())
+    })
+}
+
+/// Liste des langues de localisation disponibles.
+#[command]
+pub fn list_translation_languages() -> Vec<TranslationLanguage> {
+    TRANSLATION_LANGUAGES.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_languages_by_code_or_folder() {
+        assert_eq!(find_language("fr").unwrap().folder, "french_(france)");
+        assert_eq!(find_language("DE").unwrap().folder, "german_(germany)");
+        assert_eq!(find_language("pt_BR").unwrap().code, "pt-br");
+        assert_eq!(
+            find_language("spanish_(latin_america)").unwrap().code,
+            "es-419"
+        );
+        assert!(find_language("klingon").is_none());
+
+        let cfg = "r_DisplayInfo = 0\ng_language = german_(germany)\ng_languageAudio = english\n";
+        assert_eq!(language_from_user_cfg(cfg).unwrap().code, "de");
+        assert!(language_from_user_cfg("g_languageAudio = english\n").is_none());
+    }
+}
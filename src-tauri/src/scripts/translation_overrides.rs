@@ -0,0 +1,364 @@
+//! Surcharges personnelles `clé=valeur` appliquées par-dessus les traductions téléchargées
+//! (garder un nom d'objet en anglais, corriger une coquille…). Stockées par langue dans
+//! `translation_overrides.json` et fusionnées à l'installation et à chaque mise à jour ; une
+//! surcharge modifiée rend la traduction installée « obsolète » jusqu'à la prochaine mise à jour.
+
+use crate::scripts::translation_diff::parse_global_ini;
+use crate::scripts::translation_languages::find_language;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::path::PathResolver;
+use tauri::{command, AppHandle, Manager, Runtime};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct TranslationOverridesConfig {
+    /// Code de langue → clé → valeur.
+    overrides: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationOverride {
+    pub lang: String,
+    pub key: String,
+    pub value: String,
+}
+
+/// État des surcharges en mémoire : la fusion est appelée depuis des fonctions sans `AppHandle`.
+#[derive(Debug, Clone)]
+enum OverridesState {
+    Loaded(TranslationOverridesConfig),
+    /// Fichier présent mais illisible : aucune écriture de `global.ini` tant qu'il l'est.
+    Unreadable {
+        path: PathBuf,
+        error: String,
+    },
+}
+
+static OVERRIDES: Mutex<Option<OverridesState>> = Mutex::new(None);
+
+fn config_path(resolver: &PathResolver<impl Runtime>) -> Result<PathBuf, String> {
+    let dir = resolver
+        .app_config_dir()
+        .map_err(|_| "Impossible d'obtenir le répertoire de configuration".to_string())?;
+    if !dir.exists() {
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir.join("translation_overrides.json"))
+}
+
+fn read_config_file(path: &Path) -> Result<TranslationOverridesConfig, String> {
+    if !path.exists() {
+        return Ok(TranslationOverridesConfig::default());
+    }
+    let json = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+fn load_config(app: &AppHandle) -> Result<TranslationOverridesConfig, String> {
+    read_config_file(&config_path(app.path())?)
+}
+
+fn save_config(app: &AppHandle, config: &TranslationOverridesConfig) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    fs::write(config_path(app.path())?, json).map_err(|e| e.to_string())?;
+    if let Ok(mut cached) = OVERRIDES.lock() {
+        *cached = Some(OverridesState::Loaded(config.clone()));
+    }
+    Ok(())
+}
+
+/// Charge les surcharges en mémoire (appelé au démarrage de l'application).
+pub fn init_translation_overrides(app: &AppHandle) {
+    let state = match config_path(app.path()) {
+        Ok(path) => match read_config_file(&path) {
+            Ok(config) => OverridesState::Loaded(config),
+            Err(error) => {
+                eprintln!("[translation] surcharges illisibles: {error}");
+                OverridesState::Unreadable { path, error }
+            }
+        },
+        Err(e) => {
+            eprintln!("[translation] surcharges illisibles: {e}");
+            return;
+        }
+    };
+    if let Ok(mut cached) = OVERRIDES.lock() {
+        *cached = Some(state);
+    }
+}
+
+fn lang_code(lang: &str) -> Result<&'static str, String> {
+    find_language(lang)
+        .map(|l| l.code)
+        .ok_or_else(|| "Langue non prise en charge".to_string())
+}
+
+fn validate_entry(key: &str, value: &str) -> Result<(), String> {
+    if key.is_empty() || key.contains(['=', '\n', '\r']) || key.starts_with([';', '#']) {
+        return Err(format!("Clé invalide : {key}"));
+    }
+    if value.contains(['\n', '\r']) {
+        return Err(format!("Valeur sur plusieurs lignes pour la clé {key}"));
+    }
+    Ok(())
+}
+
+/// Remplace la valeur des clés surchargées (casse ignorée) et ajoute celles absentes du fichier.
+fn merge_overrides(content: &str, overrides: &BTreeMap<String, String>) -> String {
+    if overrides.is_empty() {
+        return content.to_string();
+    }
+    let by_key: HashMap<String, &String> = overrides
+        .iter()
+        .map(|(k, v)| (k.to_ascii_lowercase(), v))
+        .collect();
+    let mut applied = HashSet::new();
+    let mut out = String::with_capacity(content.len() + overrides.len() * 64);
+    for line in content.lines() {
+        let line = line.trim_end_matches('\r');
+        let matched = line.split_once('=').and_then(|(raw_key, _)| {
+            let key = raw_key
+                .trim_start_matches('\u{feff}')
+                .trim()
+                .to_ascii_lowercase();
+            by_key.get(&key).map(|value| (raw_key, key, value))
+        });
+        match matched {
+            // La clé garde la graphie du fichier téléchargé.
+            Some((raw_key, key, value)) => {
+                out.push_str(&format!("{raw_key}={value}"));
+                applied.insert(key);
+            }
+            None => out.push_str(line),
+        }
+        out.push_str("\r\n");
+    }
+    for (key, value) in overrides {
+        if !applied.contains(&key.to_ascii_lowercase()) {
+            out.push_str(&format!("{key}={value}\r\n"));
+        }
+    }
+    out
+}
+
+/// Surcharges de `code` ; relit le fichier s'il était illisible et échoue s'il l'est encore,
+/// pour ne jamais écrire une traduction privée des surcharges de l'utilisateur.
+fn resolve_overrides(
+    state: &mut OverridesState,
+    code: &str,
+) -> Result<BTreeMap<String, String>, String> {
+    match state {
+        OverridesState::Loaded(config) => {
+            Ok(config.overrides.get(code).cloned().unwrap_or_default())
+        }
+        OverridesState::Unreadable { path, error } => match read_config_file(path) {
+            Ok(config) => {
+                let overrides = config.overrides.get(code).cloned().unwrap_or_default();
+                *state = OverridesState::Loaded(config);
+                Ok(overrides)
+            }
+            Err(e) => {
+                *error = e;
+                Err(format!(
+                    "Surcharges de traduction illisibles ({error}) : corrigez ou supprimez {}",
+                    path.display()
+                ))
+            }
+        },
+    }
+}
+
+/// Contenu téléchargé pour `lang` avec les surcharges personnelles appliquées ; échoue si
+/// `translation_overrides.json` n'a pas pu être lu.
+pub fn apply_translation_overrides(content: &str, lang: &str) -> Result<String, String> {
+    let Some(code) = find_language(lang).map(|l| l.code) else {
+        return Ok(content.to_string());
+    };
+    let overrides = match OVERRIDES.lock() {
+        Ok(mut cached) => match cached.as_mut() {
+            Some(state) => resolve_overrides(state, code)?,
+            None => BTreeMap::new(),
+        },
+        Err(_) => return Err("Surcharges de traduction indisponibles".to_string()),
+    };
+    Ok(merge_overrides(content, &overrides))
+}
+
+#[command]
+pub fn list_translation_overrides(
+    app: AppHandle,
+    lang: Option<String>,
+) -> Result<Vec<TranslationOverride>, String> {
+    let wanted = lang.as_deref().map(lang_code).transpose()?;
+    let config = load_config(&app)?;
+    Ok(config
+        .overrides
+        .into_iter()
+        .filter(|(code, _)| wanted.is_none_or(|w| w == code))
+        .flat_map(|(lang, entries)| {
+            entries
+                .into_iter()
+                .map(move |(key, value)| TranslationOverride {
+                    lang: lang.clone(),
+                    key,
+                    value,
+                })
+        })
+        .collect())
+}
+
+/// Ajoute ou remplace une surcharge.
+#[command]
+pub fn set_translation_override(
+    app: AppHandle,
+    lang: String,
+    key: String,
+    value: String,
+) -> Result<(), String> {
+    let code = lang_code(&lang)?;
+    let key = key.trim().to_string();
+    validate_entry(&key, &value)?;
+    let mut config = load_config(&app)?;
+    let entries = config.overrides.entry(code.to_string()).or_default();
+    // Une seule entrée par clé, quelle que soit la casse saisie.
+    entries.retain(|k, _| !k.eq_ignore_ascii_case(&key));
+    entries.insert(key, value);
+    save_config(&app, &config)
+}
+
+/// Retire une surcharge ; renvoie `false` si elle n'existait pas.
+#[command]
+pub fn remove_translation_override(
+    app: AppHandle,
+    lang: String,
+    key: String,
+) -> Result<bool, String> {
+    let code = lang_code(&lang)?;
+    let mut config = load_config(&app)?;
+    let Some(entries) = config.overrides.get_mut(code) else {
+        return Ok(false);
+    };
+    let before = entries.len();
+    entries.retain(|k, _| !k.eq_ignore_ascii_case(key.trim()));
+    let removed = entries.len() != before;
+    if entries.is_empty() {
+        config.overrides.remove(code);
+    }
+    if removed {
+        save_config(&app, &config)?;
+    }
+    Ok(removed)
+}
+
+/// Importe des surcharges depuis un fichier au format `global.ini` ; `replace` vide d'abord
+/// celles de la langue. Renvoie le nombre de clés importées.
+#[command]
+pub fn import_translation_overrides(
+    app: AppHandle,
+    lang: String,
+    file_path: String,
+    replace: Option<bool>,
+) -> Result<usize, String> {
+    let code = lang_code(&lang)?;
+    let raw = fs::read_to_string(file_path.trim())
+        .map_err(|e| format!("Lecture du fichier impossible : {e}"))?;
+    let imported = parse_global_ini(&raw);
+    for (key, value) in &imported {
+        validate_entry(key, value)?;
+    }
+    let mut config = load_config(&app)?;
+    let entries = config.overrides.entry(code.to_string()).or_default();
+    if replace.unwrap_or(false) {
+        entries.clear();
+    }
+    for (key, value) in &imported {
+        entries.retain(|k, _| !k.eq_ignore_ascii_case(key));
+        entries.insert(key.clone(), value.clone());
+    }
+    if entries.is_empty() {
+        config.overrides.remove(code);
+    }
+    save_config(&app, &config)?;
+    Ok(imported.len())
+}
+
+/// Exporte les surcharges d'une langue au format `global.ini`. Renvoie le nombre de clés.
+#[command]
+pub fn export_translation_overrides(
+    app: AppHandle,
+    lang: String,
+    dest_path: String,
+) -> Result<usize, String> {
+    let code = lang_code(&lang)?;
+    let config = load_config(&app)?;
+    let entries = config.overrides.get(code).cloned().unwrap_or_default();
+    let content: String = entries
+        .iter()
+        .map(|(key, value)| format!("{key}={value}\r\n"))
+        .collect();
+    let dest = PathBuf::from(dest_path.trim());
+    if let Some(parent) = dest.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+    }
+    fs::write(&dest, content).map_err(|e| e.to_string())?;
+    Ok(entries.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_replace_and_append_keys() {
+        let downloaded = "\u{feff}; commentaire\r\nitem_NameAmmo=Munitions\r\nui_ok=OK\r\n";
+        let overrides: BTreeMap<String, String> = [
+            ("item_nameammo".to_string(), "Ammo".to_string()),
+            ("ui_custom".to_string(), "Perso".to_string()),
+        ]
+        .into();
+
+        let merged = merge_overrides(downloaded, &overrides);
+        assert_eq!(
+            merged,
+            "\u{feff}; commentaire\r\nitem_NameAmmo=Ammo\r\nui_ok=OK\r\nui_custom=Perso\r\n"
+        );
+        // Fusion idempotente : le test « à jour » compare au résultat fusionné.
+        assert_eq!(merge_overrides(&merged, &overrides), merged);
+        assert_eq!(merge_overrides(downloaded, &BTreeMap::new()), downloaded);
+        assert!(validate_entry("a=b", "x").is_err());
+        assert!(validate_entry("ui_ok", "deux\nlignes").is_err());
+    }
+
+    #[test]
+    fn unreadable_overrides_block_the_merge_until_fixed() {
+        let dir = std::env::temp_dir().join(format!(
+            "multitool_overrides_unreadable_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("translation_overrides.json");
+        fs::write(&path, "{ pas du json").unwrap();
+
+        let error = read_config_file(&path).unwrap_err();
+        let mut state = OverridesState::Unreadable {
+            path: path.clone(),
+            error,
+        };
+        assert!(resolve_overrides(&mut state, "fr").is_err());
+
+        // Une fois le fichier corrigé, la fusion reprend sans redémarrage.
+        fs::write(&path, r#"{"overrides":{"fr":{"ui_ok":"Valider"}}}"#).unwrap();
+        let overrides = resolve_overrides(&mut state, "fr").unwrap();
+        assert_eq!(overrides.get("ui_ok").map(String::as_str), Some("Valider"));
+        assert!(matches!(state, OverridesState::Loaded(_)));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -31,6 +31,17 @@ impl TranslationsSelected {
     pub fn as_value(&self) -> &serde_json::Value {
         &self.0
     }
+
+    /// Langue choisie pour un canal (`"lang"` du réglage), sinon `default`.
+    pub fn channel_language(&self, channel: &str, default: &str) -> String {
+        self.0
+            .get(channel)
+            .and_then(|setting| setting.get("lang"))
+            .and_then(|v| v.as_str())
+            .filter(|lang| !lang.trim().is_empty())
+            .unwrap_or(default)
+            .to_string()
+    }
 }
 
 /// Sauvegarde les préférences de traduction dans un fichier JSON.
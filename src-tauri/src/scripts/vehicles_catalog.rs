@@ -803,6 +803,14 @@ async fn load_vehicles_catalog() -> Result<Vec<VehicleSummary>, String> {
     }
 }
 
+/// Retélécharge le catalogue sans tenir compte de l'âge du cache (après un patch).
+/// Renvoie le nombre de vaisseaux.
+pub(crate) async fn force_refresh_vehicles_catalog() -> Result<usize, String> {
+    let vehicles = fetch_all_vehicles().await?;
+    persist_cache(&vehicles);
+    Ok(vehicles.len())
+}
+
 /// Liste le catalogue des vaisseaux (cache local 7 jours).
 #[command]
 pub async fn vehicles_catalog_list() -> Result<Vec<VehicleSummary>, String> {
@@ -7,6 +7,20 @@ export interface BlueprintEntry {
   missionTrigger?: string | null;
   /** ID catalogue (`bp_craft_*`), rempli après matching — absent du Game.log. */
   catalogBlueprintId?: string | null;
+  /** Confiance (0–1) de la mission retenue, croisée avec les récompenses Wiki. */
+  correlationConfidence?: number | null;
+  /** Autres missions plausibles, meilleur score d'abord. */
+  correlationAlternatives?: CorrelationCandidate[];
+}
+
+export interface CorrelationCandidate {
+  missionGuid: string;
+  missionDebugName: string;
+  trigger: string;
+  deltaSec: number;
+  score: number;
+  /** `true` si le Wiki liste ce blueprint parmi les récompenses ; absent si mission inconnue. */
+  wikiMatch?: boolean | null;
 }
 
 export type BlueprintSortKey =
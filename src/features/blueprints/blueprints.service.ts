@@ -1,16 +1,61 @@
 import { invokeCommand } from "@/shared/api/tauriClient";
 import { TAURI_COMMANDS } from "@/shared/api/commands";
 import type { BlueprintEntry } from "@/features/blueprints/blueprints.lib";
+import type { GameStatsSnapshot } from "@/features/game-stats/gameStats.types";
 
 export interface BlueprintStoreFile {
   schemaVersion: number;
   blueprints: BlueprintEntry[];
 }
 
+export interface GamelogChannelInfo {
+  channel: string;
+  installPath: string | null;
+  detected: boolean;
+  enabled: boolean;
+}
+
+export interface ActiveMissionInfo {
+  missionGuid: string;
+  debugName: string;
+}
+
+export interface ChannelTailerHealth {
+  channel: string;
+  logPath: string;
+  offset: number;
+  linesProcessed: number;
+  lastLineTs: number | null;
+  rotations: number;
+  lastError: string | null;
+  owner: string | null;
+  activeMissions: ActiveMissionInfo[];
+  /** `false` si le tailer tourne en polling. */
+  notifications: boolean;
+}
+
+/** Événement typé du watcher (`kind` + champs propres au type). */
+export interface WatcherEvent {
+  channel: string;
+  ts: number;
+  /** Événement rejoué depuis un log enregistré (canal `REPLAY`). */
+  replay?: boolean;
+  /** Relu depuis le contenu déjà présent au démarrage du watcher. */
+  backfill?: boolean;
+  kind: string;
+  [field: string]: unknown;
+}
+
 export interface GamelogWatcherStatus {
   watching: boolean;
   logPath: string | null;
   blueprintCount: number;
+  /** Canaux suivis par le watcher en cours (vide s'il est arrêté). */
+  activeChannels: string[];
+  channels: GamelogChannelInfo[];
+  tailers: ChannelTailerHealth[];
+  /** Derniers événements reconnus, du plus ancien au plus récent. */
+  recentEvents: WatcherEvent[];
 }
 
 export interface BlueprintImportResult {
@@ -27,6 +72,83 @@ export interface BlueprintImportResult {
   removedWithoutOwner: number;
 }
 
+export interface BlueprintImportFileReport {
+  /** Chemin du fichier, ou `archive.zip!chemin/interne.log`. */
+  source: string;
+  owner: string | null;
+  matchesFound: number;
+  newEntries: number;
+  error?: string;
+}
+
+export interface BlueprintImportPreview {
+  sources: string[];
+  files: BlueprintImportFileReport[];
+  newEntries: BlueprintEntry[];
+  matchesFound: number;
+  alreadyKnown: number;
+  withoutOwner: number;
+  filesFailed: number;
+}
+
+export interface MissionDropProduct {
+  productName: string;
+  catalogBlueprintId?: string | null;
+  count: number;
+  averageConfidence: number;
+  lastTs: number;
+}
+
+export interface MissionBlueprintDrops {
+  missionDebugName: string;
+  products: MissionDropProduct[];
+}
+
+export interface GamelogReplayStatus {
+  running: boolean;
+  source: string | null;
+  /** 1 = horodatages d'origine, 10 = dix fois plus vite, 0 = sans pause. */
+  speed: number;
+  linesDone: number;
+  linesTotal: number;
+  eventsEmitted: number;
+  blueprintsFound: number;
+  currentTs: number | null;
+  finished: boolean;
+  error: string | null;
+}
+
+export interface GamelogReplayResults {
+  blueprints: BlueprintStoreFile;
+  stats: GameStatsSnapshot | null;
+}
+
+export interface GameProcessConfig {
+  enabled: boolean;
+  /** Noms d'exécutable surveillés (casse et `.exe` ignorés). */
+  processNames: string[];
+  startWatcherOnLaunch: boolean;
+  syncOnExit: boolean;
+}
+
+/** Payload de `game-started` / `game-exited` (fin renseignée à la fermeture). */
+export interface RunningGame {
+  pid: number;
+  processName: string;
+  exePath: string | null;
+  channel: string | null;
+  startedAt: number;
+  endedAt?: number | null;
+  durationSec?: number | null;
+}
+
+export interface GameProcessStatus {
+  monitoring: boolean;
+  config: GameProcessConfig;
+  running: RunningGame[];
+  recentSessions: RunningGame[];
+}
+
 export const blueprintsService = {
   loadStore: () =>
     invokeCommand<BlueprintStoreFile>(TAURI_COMMANDS.loadGamelogBlueprints),
@@ -38,12 +160,61 @@ export const blueprintsService = {
 
   stopWatcher: () => invokeCommand<void>(TAURI_COMMANDS.stopGamelogWatcher),
 
+  getGameProcessStatus: () =>
+    invokeCommand<GameProcessStatus>(TAURI_COMMANDS.getGameProcessStatus),
+
+  saveGameProcessConfig: (config: GameProcessConfig) =>
+    invokeCommand<void>(TAURI_COMMANDS.saveGameProcessConfig, { config }),
+
+  getChannels: () =>
+    invokeCommand<GamelogChannelInfo[]>(TAURI_COMMANDS.getGamelogChannels),
+
+  setChannelEnabled: (channel: string, enabled: boolean) =>
+    invokeCommand<GamelogChannelInfo[]>(
+      TAURI_COMMANDS.setGamelogChannelEnabled,
+      { channel, enabled },
+    ),
+
   importFromLogbackups: (includeCurrent = true) =>
     invokeCommand<BlueprintImportResult>(
       TAURI_COMMANDS.importBlueprintsFromLogbackups,
       { includeCurrent },
     ),
 
+  /** Sans `sources`, ouvre le dialogue (fichiers .log/.zip ou dossiers). `null` si annulé. */
+  previewImport: (sources?: string[], pickFolders = false) =>
+    invokeCommand<BlueprintImportPreview | null>(
+      TAURI_COMMANDS.previewBlueprintsImport,
+      { sources: sources ?? null, pickFolders },
+    ),
+
+  mergeImport: (entries: BlueprintEntry[]) =>
+    invokeCommand<number>(TAURI_COMMANDS.mergeBlueprintsImport, { entries }),
+
+  /** Sans `path`, ouvre le dialogue. `null` si annulé ; suivi via `gamelog-replay-progress`. */
+  startReplay: (path?: string, speed = 1, maxGapSec?: number) =>
+    invokeCommand<GamelogReplayStatus | null>(TAURI_COMMANDS.startGamelogReplay, {
+      path: path ?? null,
+      speed,
+      maxGapSec: maxGapSec ?? null,
+    }),
+
+  stopReplay: () => invokeCommand<void>(TAURI_COMMANDS.stopGamelogReplay),
+
+  getReplayStatus: () =>
+    invokeCommand<GamelogReplayStatus>(TAURI_COMMANDS.getGamelogReplayStatus),
+
+  /** Bac à sable de la dernière relecture (le store réel n'est pas modifié). */
+  loadReplayResults: () =>
+    invokeCommand<GamelogReplayResults>(TAURI_COMMANDS.loadGamelogReplayResults),
+
+  /** Produits réellement obtenus par mission (corrélations ≥ `minConfidence`, 0.5 par défaut). */
+  getMissionDrops: (minConfidence?: number) =>
+    invokeCommand<MissionBlueprintDrops[]>(
+      TAURI_COMMANDS.getMissionBlueprintDrops,
+      { minConfidence: minConfidence ?? null },
+    ),
+
   exportStore: () =>
     invokeCommand<string | null>(TAURI_COMMANDS.exportGamelogBlueprints),
 
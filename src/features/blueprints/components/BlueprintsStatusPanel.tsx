@@ -163,7 +163,7 @@ export function BlueprintsStatusPanel({
                 className="gap-1 px-2 py-0.5 text-[10px] font-medium"
               >
                 <Archive className="h-3 w-3 text-primary" />
-                Archive LIVE+HOTFIX
+                Archive locale
               </Badge>
             )}
             <span className="text-sm text-muted-foreground">
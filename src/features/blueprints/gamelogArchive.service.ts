@@ -3,16 +3,30 @@ import { TAURI_COMMANDS } from "@/shared/api/commands";
 
 export interface GamelogArchiveStatus {
   totalArchivedFiles: number;
+  /** Taille d'origine des logs archivés. */
   totalBytesArchived: number;
+  /** Taille occupée sur disque (copies compressées). */
+  totalBytesStored: number;
+  bytesSaved: number;
+  compressedCount: number;
+  /** Copies brutes en attente de compression. */
+  pendingCompressionCount: number;
   verifiedCount: number;
   pendingCount: number;
   deletedFromGameCount: number;
+  pinnedCount: number;
+  /** Copies supprimées par la rétention (contenu conservé dans les statistiques). */
+  prunedCount: number;
+  crashReportFiles: number;
+  launcherLogCount: number;
   channels: string[];
   archiveDirectory: string;
 }
 
 export interface GamelogArchiveSyncResult {
   archived: number;
+  crashFilesArchived: number;
+  launcherLogsArchived: number;
   deletedFromGame: number;
   pendingVerification: number;
   skippedAlreadyArchived: number;
@@ -20,12 +34,190 @@ export interface GamelogArchiveSyncResult {
   channelsScanned: string[];
 }
 
+export interface GamelogArchiveConfig {
+  deleteAfterVerified: boolean;
+  maxTotalBytes?: number | null;
+  maxAgeDays?: number | null;
+  keepLastSessionsPerChannel?: number | null;
+  /** Dossier personnalisé (lecture seule : modifié par `relocate`). */
+  archiveDirectory?: string | null;
+}
+
+export type ArchiveEntryKind = "gameLog" | "crashReport" | "launcherLog";
+
+export interface GamelogArchiveEntry {
+  id: string;
+  kind: ArchiveEntryKind;
+  crashId?: string;
+  channel: string;
+  sourcePath: string;
+  originalName: string;
+  archiveName: string;
+  size: number;
+  compressedSize?: number;
+  sourceMtimeMs: number;
+  archivedAtMs: number;
+  pinned: boolean;
+}
+
+export type RetentionReason = "maxAge" | "sessionCount" | "totalSize";
+
+export interface RetentionCandidate {
+  archiveName: string;
+  channel: string;
+  originalName: string;
+  sourceMtimeMs: number;
+  size: number;
+  storedBytes: number;
+  kind: ArchiveEntryKind;
+  reason: RetentionReason;
+}
+
+export interface RetentionPreview {
+  candidates: RetentionCandidate[];
+  bytesToFree: number;
+  bytesRemaining: number;
+}
+
+export interface RetentionResult {
+  pruned: number;
+  failed: number;
+  bytesFreed: number;
+  blueprintsAdded: number;
+}
+
+export const GAMELOG_ARCHIVE_VERIFY_PROGRESS_EVENT = "gamelog-archive-verify-progress";
+
+export interface GamelogArchiveVerifyProgress {
+  phase: "verifying" | "repairing" | "done";
+  filesDone: number;
+  filesTotal: number;
+  currentFile?: string;
+  percent: number;
+}
+
+export type ArchiveIssueKind = "missing" | "corrupted" | "orphaned";
+
+export type ArchiveRepairAction = "recopyFromSource" | "dropEntry";
+
+export interface ArchiveIssue {
+  kind: ArchiveIssueKind;
+  archiveName: string;
+  channel?: string;
+  originalName?: string;
+  detail: string;
+  repair?: ArchiveRepairAction;
+}
+
+export interface GamelogArchiveVerifyReport {
+  checked: number;
+  ok: number;
+  missing: number;
+  corrupted: number;
+  orphaned: number;
+  issues: ArchiveIssue[];
+}
+
+export interface GamelogArchiveRepairResult {
+  recopied: number;
+  dropped: number;
+  failed: number;
+  unrepairable: number;
+}
+
+export interface ArchiveRelocationResult {
+  archiveDirectory: string;
+  filesMoved: number;
+  bytesMoved: number;
+}
+
+export interface ArchiveBundleExportResult {
+  filesPacked: number;
+  bytesPacked: number;
+  skipped: string[];
+}
+
+export interface ArchiveBundleImportResult {
+  imported: number;
+  skippedDuplicates: number;
+  failed: number;
+  blueprintsAdded: number;
+}
+
+export interface CrashReportSummary {
+  crashId: string;
+  channel: string;
+  /** AAAA-MM-JJ (UTC). */
+  date: string;
+  occurredAtMs: number;
+  build?: string;
+  signature?: string;
+  archiveNames: string[];
+  totalBytes: number;
+}
+
+export interface CrashIndexGroup {
+  date: string;
+  build?: string;
+  signature?: string;
+  count: number;
+  crashIds: string[];
+  lastOccurredAtMs: number;
+}
+
+export interface CrashIndex {
+  reports: CrashReportSummary[];
+  groups: CrashIndexGroup[];
+}
+
 export const gamelogArchiveService = {
   getStatus: () =>
     invokeCommand<GamelogArchiveStatus>(TAURI_COMMANDS.getGamelogArchiveStatus),
 
   sync: () =>
     invokeCommand<GamelogArchiveSyncResult>(TAURI_COMMANDS.syncGamelogArchive),
+
+  loadConfig: () =>
+    invokeCommand<GamelogArchiveConfig>(TAURI_COMMANDS.loadGamelogArchiveConfig),
+
+  saveConfig: (config: GamelogArchiveConfig) =>
+    invokeCommand<void>(TAURI_COMMANDS.saveGamelogArchiveConfig, { config }),
+
+  listEntries: () =>
+    invokeCommand<GamelogArchiveEntry[]>(TAURI_COMMANDS.listGamelogArchiveEntries),
+
+  setPinned: (archiveName: string, pinned: boolean) =>
+    invokeCommand<void>(TAURI_COMMANDS.setGamelogArchivePinned, { archiveName, pinned }),
+
+  previewRetention: () =>
+    invokeCommand<RetentionPreview>(TAURI_COMMANDS.previewGamelogArchiveRetention),
+
+  applyRetention: () =>
+    invokeCommand<RetentionResult>(TAURI_COMMANDS.applyGamelogArchiveRetention),
+
+  verify: () =>
+    invokeCommand<GamelogArchiveVerifyReport>(TAURI_COMMANDS.verifyGamelogArchive),
+
+  repair: () =>
+    invokeCommand<GamelogArchiveRepairResult>(TAURI_COMMANDS.repairGamelogArchive),
+
+  /** `targetDir` absent : retour à l'emplacement par défaut. */
+  relocate: (targetDir?: string) =>
+    invokeCommand<ArchiveRelocationResult>(TAURI_COMMANDS.relocateGamelogArchive, {
+      targetDir: targetDir ?? null,
+    }),
+
+  getCrashIndex: () => invokeCommand<CrashIndex>(TAURI_COMMANDS.getGamelogCrashIndex),
+
+  exportBundle: (destPath: string) =>
+    invokeCommand<ArchiveBundleExportResult>(TAURI_COMMANDS.exportGamelogArchiveBundle, {
+      destPath,
+    }),
+
+  importBundle: (bundlePath: string) =>
+    invokeCommand<ArchiveBundleImportResult>(TAURI_COMMANDS.importGamelogArchiveBundle, {
+      bundlePath,
+    }),
 };
 
 export function formatArchiveBytes(bytes: number): string {
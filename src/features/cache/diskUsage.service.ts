@@ -0,0 +1,53 @@
+import { invokeCommand } from "@/shared/api/tauriClient";
+import { TAURI_COMMANDS } from "@/shared/api/commands";
+
+export type DiskUsageKind =
+  | "gameData"
+  | "userFolder"
+  | "logBackups"
+  | "crashes"
+  | "shaderCache"
+  | "wikiCache"
+  | "gamelogArchive"
+  | "statsCache"
+  | "appData";
+
+export type CleanupRisk = "low" | "medium" | "high";
+
+export interface CleanupSuggestion {
+  label: string;
+  detail: string;
+  estimatedSavings: number;
+  risk: CleanupRisk;
+  /** Chemins à supprimer (via `cacheService.deleteFolder`) ; vide : action ailleurs dans l'app. */
+  paths: string[];
+}
+
+export interface DiskUsageEntry {
+  kind: DiskUsageKind;
+  label: string;
+  path: string;
+  bytes: number;
+  fileCount: number;
+  suggestions: CleanupSuggestion[];
+}
+
+export interface ChannelDiskUsage {
+  channel: string;
+  installPath: string;
+  totalBytes: number;
+  entries: DiskUsageEntry[];
+}
+
+export interface DiskUsageReport {
+  channels: ChannelDiskUsage[];
+  shaderCaches: DiskUsageEntry[];
+  multitool: DiskUsageEntry[];
+  totalBytes: number;
+  /** Somme des suggestions sans risque. */
+  totalSavings: number;
+}
+
+export const diskUsageService = {
+  getReport: () => invokeCommand<DiskUsageReport>(TAURI_COMMANDS.getDiskUsageReport),
+};
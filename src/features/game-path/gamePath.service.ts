@@ -4,4 +4,10 @@ import type { GamePaths } from "@/types/translation";
 
 export const gamePathService = {
   getVersions: () => invokeCommand<GamePaths>(TAURI_COMMANDS.getStarCitizenVersions),
+  listManualPaths: () => invokeCommand<string[]>(TAURI_COMMANDS.listManualInstallPaths),
+  /** Dossier d'un canal ou bibliothèque contenant des canaux ; refusé s'il n'y a aucune installation valide. */
+  addManualPath: (path: string) =>
+    invokeCommand<string[]>(TAURI_COMMANDS.addManualInstallPath, { path }),
+  removeManualPath: (path: string) =>
+    invokeCommand<string[]>(TAURI_COMMANDS.removeManualInstallPath, { path }),
 };
@@ -0,0 +1,62 @@
+import { invokeCommand } from "@/shared/api/tauriClient";
+import { TAURI_COMMANDS } from "@/shared/api/commands";
+
+export type LauncherOperation = "download" | "verify" | "install";
+
+interface LauncherEventBase {
+  tsMs?: number;
+  level?: string;
+}
+
+export type LauncherEvent = LauncherEventBase &
+  (
+    | { kind: "login" }
+    | { kind: "channelSelected"; channel: string }
+    | { kind: "operationStarted"; operation: LauncherOperation; channel?: string | null }
+    | {
+        kind: "operationProgress";
+        operation: LauncherOperation;
+        channel?: string | null;
+        percent: number;
+      }
+    | {
+        kind: "operationFinished";
+        operation: LauncherOperation;
+        channel?: string | null;
+        success: boolean;
+      }
+    | { kind: "patchInstalled"; channel: string; version: string }
+    | { kind: "gameLaunched"; channel: string }
+    | { kind: "error"; message: string }
+  );
+
+export type LauncherEventKind = LauncherEvent["kind"];
+
+/** État d'un canal déduit du log (ex. « LIVE : téléchargement 62 % »). */
+export interface LauncherChannelStatus {
+  channel: string;
+  activeOperation?: LauncherOperation;
+  percent?: number;
+  installedVersion?: string;
+  lastError?: string;
+  updatedAtMs?: number;
+}
+
+export interface LauncherEventQuery {
+  kinds?: LauncherEventKind[];
+  channel?: string;
+  sinceMs?: number;
+  limit?: number;
+}
+
+export interface LauncherLogReport {
+  /** Du plus récent au plus ancien. */
+  events: LauncherEvent[];
+  totalMatched: number;
+  channels: LauncherChannelStatus[];
+}
+
+export const launcherLogService = {
+  query: (query?: LauncherEventQuery) =>
+    invokeCommand<LauncherLogReport>(TAURI_COMMANDS.queryLauncherEvents, { query }),
+};
@@ -0,0 +1,32 @@
+import { invokeCommand } from "@/shared/api/tauriClient";
+import { TAURI_COMMANDS } from "@/shared/api/commands";
+
+/** Entrée de `Data.p4k` (chemin stocké avec des `\`). */
+export interface P4kEntry {
+  name: string;
+  compressedSize: number;
+  uncompressedSize: number;
+  method: number;
+  crc32: number;
+  modifiedMs?: number;
+  /** Entrée chiffrée : listée mais pas extractible. */
+  encrypted: boolean;
+}
+
+export interface P4kListing {
+  totalEntries: number;
+  matched: number;
+  entries: P4kEntry[];
+  truncated: boolean;
+}
+
+/** Lecture seule de l'archive du jeu ; `installPath` est le dossier du canal (ex. `…\StarCitizen\LIVE`). */
+export const p4kService = {
+  list: (installPath: string, prefix?: string, limit?: number) =>
+    invokeCommand<P4kListing>(TAURI_COMMANDS.listP4kEntries, { installPath, prefix, limit }),
+  getEntry: (installPath: string, name: string) =>
+    invokeCommand<P4kEntry | null>(TAURI_COMMANDS.getP4kEntry, { installPath, name }),
+  /** Extrait un fichier vers `destPath` ; renvoie le nombre d'octets écrits. */
+  extract: (installPath: string, name: string, destPath: string) =>
+    invokeCommand<number>(TAURI_COMMANDS.extractP4kFile, { installPath, name, destPath }),
+};
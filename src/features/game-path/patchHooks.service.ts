@@ -0,0 +1,55 @@
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
+import { invokeCommand } from "@/shared/api/tauriClient";
+import { TAURI_COMMANDS } from "@/shared/api/commands";
+
+export const PATCH_DETECTED_EVENT = "patch-detected";
+
+/** Actions lancées quand le build d'un canal change. */
+export interface PatchHooksConfig {
+  reinstallTranslation: boolean;
+  refreshWikiCaches: boolean;
+  backupConfig: boolean;
+  notify: boolean;
+}
+
+export interface SeenBuild {
+  buildNumber?: string;
+  gameVersion?: string;
+}
+
+export type PatchHookAction =
+  | "reinstallTranslation"
+  | "refreshWikiCaches"
+  | "backupConfig"
+  | "notify";
+
+export interface PatchHookOutcome {
+  action: PatchHookAction;
+  ok: boolean;
+  detail?: string;
+}
+
+export interface PatchHistoryEntry {
+  channel: string;
+  detectedAtMs: number;
+  previous: SeenBuild;
+  current: SeenBuild;
+  outcomes: PatchHookOutcome[];
+}
+
+export const patchHooksService = {
+  getConfig: () => invokeCommand<PatchHooksConfig>(TAURI_COMMANDS.getPatchHooksConfig),
+  saveConfig: (config: PatchHooksConfig) =>
+    invokeCommand<void>(TAURI_COMMANDS.savePatchHooksConfig, { config }),
+  /** Du plus récent au plus ancien ; tous les canaux si `channel` est omis. */
+  getHistory: (channel?: string) =>
+    invokeCommand<PatchHistoryEntry[]>(TAURI_COMMANDS.getPatchHistory, { channel }),
+};
+
+export async function subscribePatchDetected(
+  onPatch: (entry: PatchHistoryEntry) => void,
+): Promise<UnlistenFn> {
+  return listen<PatchHistoryEntry>(PATCH_DETECTED_EVENT, (event) => {
+    onPatch(event.payload);
+  });
+}
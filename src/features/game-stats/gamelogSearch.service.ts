@@ -0,0 +1,57 @@
+import { invokeCommand } from "@/shared/api/tauriClient";
+import { TAURI_COMMANDS } from "@/shared/api/commands";
+
+export interface GamelogSearchQuery {
+  /** Termes séparés par des espaces : une ligne doit tous les contenir (casse ignorée). */
+  query: string;
+  /** Bornes en secondes epoch. */
+  fromTs?: number;
+  toTs?: number;
+  channel?: string;
+  owner?: string;
+  /** Reprise juste après ce résultat (`nextCursor` de la page précédente). */
+  cursor?: GamelogSearchCursor;
+  offset?: number;
+  limit?: number;
+  contextLines?: number;
+}
+
+export interface GamelogSearchCursor {
+  filePath: string;
+  lineNumber: number;
+}
+
+export interface GamelogSearchHit {
+  filePath: string;
+  fileName: string;
+  channel: string;
+  lineNumber: number;
+  timestamp?: number;
+  line: string;
+  before: string[];
+  after: string[];
+}
+
+export interface GamelogSearchResponse {
+  hits: GamelogSearchHit[];
+  offset: number;
+  hasMore: boolean;
+  nextCursor?: GamelogSearchCursor;
+  filesTotal: number;
+  filesScanned: number;
+}
+
+export interface GamelogSearchIndexStatus {
+  filesIndexed: number;
+  filesReindexed: number;
+  filesRemoved: number;
+  indexDirectory: string;
+}
+
+export const gamelogSearchService = {
+  search: (query: GamelogSearchQuery) =>
+    invokeCommand<GamelogSearchResponse>(TAURI_COMMANDS.searchGamelogs, { query }),
+
+  rebuildIndex: () =>
+    invokeCommand<GamelogSearchIndexStatus>(TAURI_COMMANDS.rebuildGamelogSearchIndex),
+};
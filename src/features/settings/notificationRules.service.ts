@@ -0,0 +1,35 @@
+import { invokeCommand } from "@/shared/api/tauriClient";
+import { TAURI_COMMANDS } from "@/shared/api/commands";
+
+export type NotificationTrigger =
+  | { kind: "wishlistBlueprint" }
+  | { kind: "anyBlueprint" }
+  | { kind: "missionFailed" }
+  | { kind: "purchaseAbove"; minPrice: number };
+
+/** Plage `HH:MM` locale, peut passer minuit (ex. 23:00 → 08:00). */
+export interface QuietHours {
+  start: string;
+  end: string;
+}
+
+export interface NotificationRule {
+  id: string;
+  name: string;
+  enabled: boolean;
+  trigger: NotificationTrigger;
+  quietHours?: QuietHours | null;
+}
+
+export interface NotificationRulesConfig {
+  enabled: boolean;
+  /** Ordre significatif : seule la première règle qui correspond notifie. */
+  rules: NotificationRule[];
+}
+
+export const notificationRulesService = {
+  get: () => invokeCommand<NotificationRulesConfig>(TAURI_COMMANDS.getNotificationRules),
+
+  save: (config: NotificationRulesConfig) =>
+    invokeCommand<void>(TAURI_COMMANDS.saveNotificationRules, { config }),
+};
@@ -4,9 +4,18 @@ import { PAGE_CENTER } from "@/shared/components/pageStyles";
 import { useTranslation } from "@/features/translation/useTranslation";
 import { TranslationVersionCard } from "@/features/translation/components/TranslationVersionCard";
 import { PageWaveLoader } from "@/shared/components/PageWaveLoader";
+import {
+  type PendingTranslationUpdate,
+  TranslationUpdateDiffDialog,
+} from "@/features/translation/components/TranslationUpdateDiffDialog";
+import {
+  type TranslationChangelogTarget,
+  TranslationChangelogDialog,
+} from "@/features/translation/components/TranslationChangelogDialog";
 import {
   getTranslationCardsLayoutClass,
   TRANSLATION_LOAD_DELAY_MS,
+  translationLangFor,
 } from "@/features/translation/translation.lib";
 
 function TranslationEmptyState() {
@@ -29,6 +38,11 @@ function TranslationEmptyState() {
 export default function TranslationPage() {
   const vm = useTranslation();
   const [loadDelayElapsed, setLoadDelayElapsed] = useState(false);
+  const [pendingUpdate, setPendingUpdate] = useState<PendingTranslationUpdate | null>(
+    null,
+  );
+  const [changelogTarget, setChangelogTarget] =
+    useState<TranslationChangelogTarget | null>(null);
 
   useEffect(() => {
     const timer = window.setTimeout(
@@ -66,8 +80,18 @@ export default function TranslationPage() {
                   loadingButtonId={vm.loadingButtonId}
                   onSettingsToggle={vm.handleSettingsToggle}
                   onInstall={vm.handleInstallTranslation}
-                  onUpdate={vm.handleUpdateTranslation}
+                  onUpdate={(path, link, version) =>
+                    setPendingUpdate({
+                      path,
+                      link,
+                      version,
+                      lang: translationLangFor(vm.translationsSelected?.[version]),
+                    })
+                  }
                   onUninstall={vm.handleUninstallTranslation}
+                  onShowChangelog={(path, version) =>
+                    setChangelogTarget({ path, version })
+                  }
                   setLoadingButtonId={vm.setLoadingButtonId}
                 />
               );
@@ -81,6 +105,17 @@ export default function TranslationPage() {
           <PageWaveLoader message="Chargement des traductions…" />
         </div>
       )}
+      <TranslationUpdateDiffDialog
+        pending={pendingUpdate}
+        onClose={() => setPendingUpdate(null)}
+        onConfirm={(update) =>
+          vm.handleUpdateTranslation(update.path, update.link, update.version)
+        }
+      />
+      <TranslationChangelogDialog
+        target={changelogTarget}
+        onClose={() => setChangelogTarget(null)}
+      />
     </PageMotion>
   );
 }
@@ -0,0 +1,136 @@
+import { useEffect, useState } from "react";
+import { Loader2 } from "lucide-react";
+import {
+  Dialog,
+  DialogContent,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import { Button } from "@/components/ui/button";
+import type { TranslationChangelogEntry } from "@/types/translation";
+import { translationService } from "@/features/translation/translation.service";
+import { DiffSample } from "@/features/translation/components/TranslationUpdateDiffDialog";
+import { formatChangelogDate } from "@/features/translation/translation.lib";
+
+export interface TranslationChangelogTarget {
+  path: string;
+  version: string;
+}
+
+interface TranslationChangelogDialogProps {
+  target: TranslationChangelogTarget | null;
+  onClose: () => void;
+}
+
+function ChangelogEntry({ entry }: { entry: TranslationChangelogEntry }) {
+  const [expanded, setExpanded] = useState(false);
+  const { diff } = entry;
+  const hasSamples = diff.changed.length + diff.added.length + diff.removed.length > 0;
+
+  return (
+    <li className="flex flex-col gap-2 rounded-md border border-primary/12 bg-primary/5 px-2.5 py-2">
+      <div className="flex items-center justify-between gap-2">
+        <div className="min-w-0">
+          <p className="text-xs font-semibold">
+            {formatChangelogDate(entry.appliedAtMs)} · {entry.lang.toUpperCase()}
+          </p>
+          <p className="text-[11px] text-muted-foreground">
+            {diff.changedCount} modifiées · {diff.addedCount} ajoutées ·{" "}
+            {diff.removedCount} supprimées
+          </p>
+        </div>
+        {hasSamples && (
+          <Button
+            variant="ghost"
+            size="sm"
+            className="h-7 shrink-0 px-2 text-xs"
+            onClick={() => setExpanded((value) => !value)}
+            data-no-window-drag
+          >
+            {expanded ? "Masquer" : "Détails"}
+          </Button>
+        )}
+      </div>
+      {expanded && (
+        <div className="flex flex-col gap-3">
+          <DiffSample title="Textes modifiés" items={diff.changed} />
+          <DiffSample title="Clés ajoutées" items={diff.added} />
+          <DiffSample title="Clés supprimées" items={diff.removed} />
+        </div>
+      )}
+    </li>
+  );
+}
+
+/** Journal des mises à jour de traduction appliquées à une installation. */
+export function TranslationChangelogDialog({
+  target,
+  onClose,
+}: TranslationChangelogDialogProps) {
+  const [entries, setEntries] = useState<TranslationChangelogEntry[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    setEntries(null);
+    setError(null);
+    if (!target) return;
+    let cancelled = false;
+    translationService
+      .getChangelog(target.path)
+      .then((result) => {
+        if (!cancelled) setEntries(result);
+      })
+      .catch((e) => {
+        if (!cancelled) setError(String(e));
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [target]);
+
+  const handleOpenChange = (isOpen: boolean) => {
+    if (!isOpen) onClose();
+  };
+
+  return (
+    <Dialog open={target !== null} onOpenChange={handleOpenChange}>
+      <DialogContent
+        className="settings-section max-h-[80vh] overflow-y-auto border-primary/12 bg-background/40 backdrop-blur-xl"
+        data-no-window-drag
+      >
+        <DialogHeader>
+          <DialogTitle className="text-sm font-semibold">
+            Historique de la traduction · {target?.version}
+          </DialogTitle>
+        </DialogHeader>
+        {error ? (
+          <p className="text-sm text-destructive">{error}</p>
+        ) : !entries ? (
+          <div className="flex items-center gap-2 py-4 text-sm text-muted-foreground">
+            <Loader2 className="h-4 w-4 animate-spin" />
+            Chargement de l&apos;historique…
+          </div>
+        ) : entries.length === 0 ? (
+          <p className="py-2 text-sm text-muted-foreground">
+            Aucune mise à jour enregistrée pour cette installation.
+          </p>
+        ) : (
+          <ul className="flex flex-col gap-2 py-1">
+            {entries.map((entry) => (
+              <ChangelogEntry
+                key={`${entry.appliedAtMs}-${entry.lang}`}
+                entry={entry}
+              />
+            ))}
+          </ul>
+        )}
+        <DialogFooter>
+          <Button variant="outline" onClick={onClose} data-no-window-drag>
+            Fermer
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,126 @@
+import { useEffect, useState } from "react";
+import { Loader2 } from "lucide-react";
+import {
+  Dialog,
+  DialogContent,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import { Button } from "@/components/ui/button";
+import type { ChangedString, GlobalIniDiff } from "@/types/translation";
+import { translationService } from "@/features/translation/translation.service";
+
+export interface PendingTranslationUpdate {
+  path: string;
+  link: string;
+  version: string;
+  lang: string;
+}
+
+interface TranslationUpdateDiffDialogProps {
+  pending: PendingTranslationUpdate | null;
+  onClose: () => void;
+  onConfirm: (pending: PendingTranslationUpdate) => void;
+}
+
+export function DiffSample({ title, items }: { title: string; items: ChangedString[] }) {
+  if (items.length === 0) return null;
+  return (
+    <div className="flex flex-col gap-1">
+      <p className="text-xs font-semibold text-muted-foreground">{title}</p>
+      <ul className="flex flex-col gap-1">
+        {items.map((item) => (
+          <li
+            key={item.key}
+            className="rounded-md border border-primary/12 bg-primary/5 px-2 py-1.5 text-xs"
+          >
+            <p className="font-mono text-[11px] text-muted-foreground">{item.key}</p>
+            {item.oldValue && (
+              <p className="break-words text-destructive line-through">{item.oldValue}</p>
+            )}
+            {item.newValue && <p className="break-words">{item.newValue}</p>}
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+}
+
+/** Aperçu clé par clé d'une mise à jour de traduction, à valider avant application. */
+export function TranslationUpdateDiffDialog({
+  pending,
+  onClose,
+  onConfirm,
+}: TranslationUpdateDiffDialogProps) {
+  const [diff, setDiff] = useState<GlobalIniDiff | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    setDiff(null);
+    setError(null);
+    if (!pending) return;
+    let cancelled = false;
+    translationService
+      .previewUpdate(pending.path, pending.link, pending.lang)
+      .then((result) => {
+        if (!cancelled) setDiff(result);
+      })
+      .catch((e) => {
+        if (!cancelled) setError(String(e));
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [pending]);
+
+  const handleOpenChange = (isOpen: boolean) => {
+    if (!isOpen) onClose();
+  };
+
+  const handleConfirm = () => {
+    if (pending) onConfirm(pending);
+    onClose();
+  };
+
+  return (
+    <Dialog open={pending !== null} onOpenChange={handleOpenChange}>
+      <DialogContent
+        className="settings-section max-h-[80vh] overflow-y-auto border-primary/12 bg-background/40 backdrop-blur-xl"
+        data-no-window-drag
+      >
+        <DialogHeader>
+          <DialogTitle className="text-sm font-semibold">
+            Mise à jour de la traduction · {pending?.version}
+          </DialogTitle>
+        </DialogHeader>
+        {error ? (
+          <p className="text-sm text-destructive">{error}</p>
+        ) : !diff ? (
+          <div className="flex items-center gap-2 py-4 text-sm text-muted-foreground">
+            <Loader2 className="h-4 w-4 animate-spin" />
+            Comparaison avec la traduction distante…
+          </div>
+        ) : (
+          <div className="flex flex-col gap-3 py-1">
+            <p className="text-sm">
+              {diff.changedCount} modifiées · {diff.addedCount} ajoutées ·{" "}
+              {diff.removedCount} supprimées · {diff.unchangedCount} inchangées
+            </p>
+            <DiffSample title="Textes modifiés" items={diff.changed} />
+            <DiffSample title="Clés ajoutées" items={diff.added} />
+            <DiffSample title="Clés supprimées" items={diff.removed} />
+          </div>
+        )}
+        <DialogFooter className="gap-2 sm:gap-0">
+          <Button variant="outline" onClick={onClose} data-no-window-drag>
+            Annuler
+          </Button>
+          <Button onClick={handleConfirm} disabled={!diff} data-no-window-drag>
+            Appliquer la mise à jour
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
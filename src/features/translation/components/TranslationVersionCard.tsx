@@ -32,6 +32,7 @@ interface TranslationVersionCardProps {
   onInstall: (path: string, version: string) => void;
   onUpdate: (path: string, link: string, version: string) => void;
   onUninstall: (path: string) => void;
+  onShowChangelog: (path: string, version: string) => void;
   setLoadingButtonId: (id: string | null) => void;
 }
 
@@ -107,6 +108,7 @@ export function TranslationVersionCard({
   onInstall,
   onUpdate,
   onUninstall,
+  onShowChangelog,
   setLoadingButtonId,
 }: TranslationVersionCardProps) {
   const settings = translationsSelected[versionKey as keyof TranslationsChoosen];
@@ -200,6 +202,17 @@ export function TranslationVersionCard({
             </Button>
           )}
 
+          {version.translated && (
+            <Button
+              variant="outline"
+              size="sm"
+              className="w-full"
+              onClick={() => onShowChangelog(version.path, versionKey)}
+            >
+              Historique des mises à jour
+            </Button>
+          )}
+
           {version.translated && (
             <Button
               variant="destructive"
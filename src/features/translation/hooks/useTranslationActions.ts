@@ -5,8 +5,8 @@ import { translationService } from "@/features/translation/translation.service";
 import { blueprintsCatalogService } from "@/features/blueprints/blueprints.catalog.service";
 import {
   createTranslationTimestamp,
-  DEFAULT_TRANSLATION_LANG,
   extractTranslationLink,
+  translationLangFor,
 } from "@/features/translation/translation.lib";
 import { toFriendlyFsError } from "@/utils/fs-permissions";
 import { detectDistribution } from "@/utils/buildInfo";
@@ -53,11 +53,16 @@ export function useTranslationActions(data: TranslationData) {
             link,
             settingsEN: false,
             lastUpdatedAt: createTranslationTimestamp(),
+            lang: versionSettings?.lang,
           },
         };
         setTranslationsSelected(updatedTranslations);
         await saveSelectedTranslations(updatedTranslations);
-        await translationService.initFiles(versionPath, link, DEFAULT_TRANSLATION_LANG);
+        await translationService.initFiles(
+          versionPath,
+          link,
+          translationLangFor(versionSettings),
+        );
         await blueprintsCatalogService.refreshLocalization().catch(() => undefined);
         toastSuccess(toast, `Traduction installée · ${version}`);
         await refreshVersionStates();
@@ -90,7 +95,7 @@ export function useTranslationActions(data: TranslationData) {
           await translationService.initFiles(
             versionPath,
             versionSettings.link,
-            DEFAULT_TRANSLATION_LANG,
+            translationLangFor(versionSettings),
           );
           const updatedTranslations = {
             ...translationsSelected,
@@ -131,7 +136,9 @@ export function useTranslationActions(data: TranslationData) {
         await translationService.update(
           versionPath,
           translationLink,
-          DEFAULT_TRANSLATION_LANG,
+          translationLangFor(
+            translationsSelected?.[buttonId as keyof TranslationsChoosen],
+          ),
         );
         await blueprintsCatalogService.refreshLocalization().catch(() => undefined);
         if (translationsSelected) {
@@ -1,14 +1,28 @@
-import type { GamePaths, TranslationsChoosen } from "@/types/translation";
+import type {
+  GamePaths,
+  TranslationSetting,
+  TranslationsChoosen,
+} from "@/types/translation";
 import { cn } from "@/lib/utils";
 import { translationService } from "@/features/translation/translation.service";
 
 export const DEFAULT_TRANSLATION_LANG = "fr";
 
+/** Langue de traduction d'un canal : la sienne si choisie, sinon la langue par défaut. */
+export function translationLangFor(setting?: TranslationSetting | null): string {
+  return setting?.lang || DEFAULT_TRANSLATION_LANG;
+}
+
 const lastUpdatedFormatter = new Intl.DateTimeFormat("fr-FR", {
   dateStyle: "long",
   timeStyle: "short",
 });
 
+/** Date d'une mise à jour du journal (`appliedAtMs`). */
+export function formatChangelogDate(appliedAtMs: number): string {
+  return lastUpdatedFormatter.format(new Date(appliedAtMs));
+}
+
 export function buildDefaultTranslationsState(
   paths: GamePaths | null,
 ): TranslationsChoosen {
@@ -167,16 +181,14 @@ export async function resolveTranslationVersionStates(
         const value = gamePaths.versions[key];
         const versionSettings = translationsSelected[key as keyof TranslationsChoosen];
 
-        const translated = await translationService.isGameTranslated(
-          value.path,
-          DEFAULT_TRANSLATION_LANG,
-        );
+        const lang = translationLangFor(versionSettings);
+        const translated = await translationService.isGameTranslated(value.path, lang);
 
         const upToDate = versionSettings?.link
           ? await translationService.isUpToDate(
               value.path,
               versionSettings.link,
-              DEFAULT_TRANSLATION_LANG,
+              lang,
             )
           : value.up_to_date;
 
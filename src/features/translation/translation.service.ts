@@ -1,6 +1,12 @@
 import { invokeCommand } from "@/shared/api/tauriClient";
 import { TAURI_COMMANDS } from "@/shared/api/commands";
-import type { TranslationsChoosen } from "@/types/translation";
+import type {
+  GlobalIniDiff,
+  TranslationChangelogEntry,
+  TranslationLanguage,
+  TranslationOverride,
+  TranslationsChoosen,
+} from "@/types/translation";
 
 export const translationService = {
   getTranslations: () => invokeCommand<unknown>(TAURI_COMMANDS.getTranslations),
@@ -21,6 +27,26 @@ export const translationService = {
       lang,
     }),
 
+  /** Différence clé par clé avec la traduction distante, sans rien écrire. */
+  previewUpdate: (
+    path: string,
+    translationLink: string,
+    lang: string,
+    sampleLimit?: number,
+  ) =>
+    invokeCommand<GlobalIniDiff>(TAURI_COMMANDS.previewTranslationUpdate, {
+      path,
+      translationLink,
+      lang,
+      sampleLimit,
+    }),
+
+  /** Mises à jour appliquées, de la plus récente à la plus ancienne. */
+  getChangelog: (path?: string) =>
+    invokeCommand<TranslationChangelogEntry[]>(TAURI_COMMANDS.getTranslationChangelog, {
+      path,
+    }),
+
   getBySetting: (settingType: string) =>
     invokeCommand<unknown>(TAURI_COMMANDS.getTranslationBySetting, {
       settingType,
@@ -40,6 +66,43 @@ export const translationService = {
       lang,
     }),
 
-  uninstall: (path: string) =>
-    invokeCommand<void>(TAURI_COMMANDS.uninstallTranslation, { path }),
+  /** Sans `lang`, retire tout `data` et `user.cfg` ; avec, seulement cette langue. */
+  uninstall: (path: string, lang?: string) =>
+    invokeCommand<void>(TAURI_COMMANDS.uninstallTranslation, { path, lang }),
+
+  listLanguages: () =>
+    invokeCommand<TranslationLanguage[]>(TAURI_COMMANDS.listTranslationLanguages),
+
+  /** Langue activée dans `user.cfg` (`null` : anglais par défaut du jeu). */
+  getInstalledLanguage: (path: string) =>
+    invokeCommand<TranslationLanguage | null>(
+      TAURI_COMMANDS.getInstalledTranslationLanguage,
+      { path },
+    ),
+
+  /** Surcharges personnelles `clé=valeur` (toutes langues sans `lang`). */
+  listOverrides: (lang?: string) =>
+    invokeCommand<TranslationOverride[]>(TAURI_COMMANDS.listTranslationOverrides, {
+      lang,
+    }),
+
+  setOverride: (lang: string, key: string, value: string) =>
+    invokeCommand<void>(TAURI_COMMANDS.setTranslationOverride, { lang, key, value }),
+
+  removeOverride: (lang: string, key: string) =>
+    invokeCommand<boolean>(TAURI_COMMANDS.removeTranslationOverride, { lang, key }),
+
+  /** Importe un fichier au format `global.ini` ; renvoie le nombre de clés. */
+  importOverrides: (lang: string, filePath: string, replace?: boolean) =>
+    invokeCommand<number>(TAURI_COMMANDS.importTranslationOverrides, {
+      lang,
+      filePath,
+      replace,
+    }),
+
+  exportOverrides: (lang: string, destPath: string) =>
+    invokeCommand<number>(TAURI_COMMANDS.exportTranslationOverrides, {
+      lang,
+      destPath,
+    }),
 };
@@ -3,9 +3,9 @@ import { useToast } from "@/hooks/use-toast";
 import type { GamePaths, TranslationsChoosen } from "@/types/translation";
 import { translationService } from "@/features/translation/translation.service";
 import {
-  DEFAULT_TRANSLATION_LANG,
   extractTranslationLink,
   hydrateGamePaths,
+  translationLangFor,
 } from "@/features/translation/translation.lib";
 import { toFriendlyFsError } from "@/utils/fs-permissions";
 import { detectDistribution } from "@/utils/buildInfo";
@@ -73,7 +73,9 @@ export function useTranslation() {
             const isUpToDate = await translationService.isUpToDate(
               versionPath,
               link,
-              DEFAULT_TRANSLATION_LANG,
+              translationLangFor(
+                translationsSelected[version as keyof TranslationsChoosen],
+              ),
             );
             if (paths && versionOrder.length > 0) {
               const updatedPaths = hydrateGamePaths(
@@ -83,6 +83,53 @@ export const TAURI_COMMANDS = {
   manualOwnedBlueprintsGet: "manual_owned_blueprints_get",
   manualOwnedBlueprintToggle: "manual_owned_blueprint_toggle",
   blueprintsUnlockIndexStatus: "blueprints_unlock_index_status",
+  getLocationCatalog: "get_location_catalog",
+  refreshLocationCatalog: "refresh_location_catalog",
+  getGamelogChannels: "get_gamelog_channels",
+  setGamelogChannelEnabled: "set_gamelog_channel_enabled",
+  previewBlueprintsImport: "preview_blueprints_import",
+  mergeBlueprintsImport: "merge_blueprints_import",
+  getMissionBlueprintDrops: "get_mission_blueprint_drops",
+  startGamelogReplay: "start_gamelog_replay",
+  stopGamelogReplay: "stop_gamelog_replay",
+  getGamelogReplayStatus: "get_gamelog_replay_status",
+  loadGamelogReplayResults: "load_gamelog_replay_results",
+  getGameProcessStatus: "get_game_process_status",
+  saveGameProcessConfig: "save_game_process_config",
+  getNotificationRules: "get_notification_rules",
+  saveNotificationRules: "save_notification_rules",
+  listGamelogArchiveEntries: "list_gamelog_archive_entries",
+  setGamelogArchivePinned: "set_gamelog_archive_pinned",
+  previewGamelogArchiveRetention: "preview_gamelog_archive_retention",
+  applyGamelogArchiveRetention: "apply_gamelog_archive_retention",
+  verifyGamelogArchive: "verify_gamelog_archive",
+  repairGamelogArchive: "repair_gamelog_archive",
+  relocateGamelogArchive: "relocate_gamelog_archive",
+  exportGamelogArchiveBundle: "export_gamelog_archive_bundle",
+  importGamelogArchiveBundle: "import_gamelog_archive_bundle",
+  searchGamelogs: "search_gamelogs",
+  rebuildGamelogSearchIndex: "rebuild_gamelog_search_index",
+  getGamelogCrashIndex: "get_gamelog_crash_index",
+  listManualInstallPaths: "list_manual_install_paths",
+  addManualInstallPath: "add_manual_install_path",
+  removeManualInstallPath: "remove_manual_install_path",
+  listP4kEntries: "list_p4k_entries",
+  getP4kEntry: "get_p4k_entry",
+  extractP4kFile: "extract_p4k_file",
+  queryLauncherEvents: "query_launcher_events",
+  getPatchHooksConfig: "get_patch_hooks_config",
+  savePatchHooksConfig: "save_patch_hooks_config",
+  getPatchHistory: "get_patch_history",
+  getDiskUsageReport: "get_disk_usage_report",
+  listTranslationLanguages: "list_translation_languages",
+  getInstalledTranslationLanguage: "get_installed_translation_language",
+  previewTranslationUpdate: "preview_translation_update",
+  getTranslationChangelog: "get_translation_changelog",
+  listTranslationOverrides: "list_translation_overrides",
+  setTranslationOverride: "set_translation_override",
+  removeTranslationOverride: "remove_translation_override",
+  importTranslationOverrides: "import_translation_overrides",
+  exportTranslationOverrides: "export_translation_overrides",
 } as const;
 
 export type TauriCommand = (typeof TAURI_COMMANDS)[keyof typeof TAURI_COMMANDS];
@@ -1,9 +1,18 @@
+export type InstallSource =
+  | "manual"
+  | "launcherLog"
+  | "launcherSettings"
+  | "winePrefix"
+  | "commonRoot";
+
 export interface GamePaths {
   versions: {
     [key: string]: {
       path: string;
       translated: boolean;
       up_to_date: boolean;
+      /** Sources ayant trouvé l'installation (la première est la plus fiable). */
+      detectedBy?: InstallSource[];
     };
   };
 }
@@ -25,12 +34,53 @@ export interface TranslationSetting {
   link: string | null;
   settingsEN: boolean;
   lastUpdatedAt?: string;
+  /** Code de langue du canal (`fr`, `de`, `es`…) ; défaut : `DEFAULT_TRANSLATION_LANG`. */
+  lang?: string;
+}
+
+/** Langue de localisation prise en charge par le jeu (cf. `list_translation_languages`). */
+export interface TranslationLanguage {
+  code: string;
+  folder: string;
+  displayName: string;
+  nativeName: string;
 }
 
 export interface TranslationsChoosen {
   [key: string]: TranslationSetting | null;
 }
 
+export interface ChangedString {
+  key: string;
+  oldValue: string;
+  newValue: string;
+}
+
+/** Différence clé par clé entre deux `global.ini` (échantillons limités, compteurs complets). */
+export interface GlobalIniDiff {
+  addedCount: number;
+  removedCount: number;
+  changedCount: number;
+  unchangedCount: number;
+  added: ChangedString[];
+  removed: ChangedString[];
+  changed: ChangedString[];
+}
+
+export interface TranslationChangelogEntry {
+  path: string;
+  lang: string;
+  link: string;
+  appliedAtMs: number;
+  diff: GlobalIniDiff;
+}
+
+export interface TranslationOverride {
+  lang: string;
+  key: string;
+  value: string;
+}
+
 export const isGamePaths = (value: any): value is GamePaths => {
   return (
     value &&